@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS bracket_links (
+    id                UUID NOT NULL UNIQUE PRIMARY KEY,
+    source_debate_id  UUID NOT NULL UNIQUE REFERENCES debates(id) ON DELETE CASCADE,
+    target_debate_id  UUID NOT NULL REFERENCES debates(id) ON DELETE CASCADE,
+    is_proposition    BOOLEAN NOT NULL,
+    UNIQUE (target_debate_id, is_proposition)
+);
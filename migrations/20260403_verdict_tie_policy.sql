@@ -0,0 +1,2 @@
+ALTER TABLE tournaments
+    ADD COLUMN IF NOT EXISTS verdict_tie_policy TEXT NOT NULL DEFAULT 'Tied';
@@ -0,0 +1,3 @@
+ALTER TABLE tournaments
+    ADD COLUMN IF NOT EXISTS tab_tie_breaks TEXT[] NOT NULL
+        DEFAULT ARRAY['Wins', 'Ballots', 'HeadToHead', 'Random'];
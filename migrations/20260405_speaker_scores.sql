@@ -0,0 +1,13 @@
+ALTER TABLE tournaments
+    ADD COLUMN IF NOT EXISTS speaker_score_min     DOUBLE PRECISION NOT NULL DEFAULT 60,
+    ADD COLUMN IF NOT EXISTS speaker_score_max     DOUBLE PRECISION NOT NULL DEFAULT 80,
+    ADD COLUMN IF NOT EXISTS speaker_score_step    DOUBLE PRECISION NOT NULL DEFAULT 0.5,
+    ADD COLUMN IF NOT EXISTS allow_low_point_wins  BOOLEAN NOT NULL DEFAULT false;
+
+CREATE TABLE IF NOT EXISTS speaker_scores (
+    id           UUID NOT NULL UNIQUE PRIMARY KEY,
+    verdict_id   UUID NOT NULL REFERENCES verdicts(id) ON DELETE CASCADE,
+    attendee_id  UUID NOT NULL REFERENCES attendees(id),
+    score        DOUBLE PRECISION NOT NULL,
+    UNIQUE (verdict_id, attendee_id)
+);
@@ -0,0 +1,55 @@
+-- Motions used to be shared by every tournament in the system.
+-- Each motion now belongs to a single tournament: motions used by several
+-- tournaments are copied into each of them, while motions not used
+-- by any round or debate cannot be attributed to a tournament.
+-- These are kept in unattributed_motions, so that none of them is lost.
+ALTER TABLE motions ADD COLUMN IF NOT EXISTS tournament_id UUID REFERENCES tournaments(id);
+ALTER TABLE motions DROP CONSTRAINT IF EXISTS motions_motion_key;
+
+CREATE TEMPORARY TABLE motion_usages AS
+    SELECT motion_id, tournament_id FROM debates
+    WHERE motion_id IS NOT NULL
+    UNION
+    SELECT rounds.motion_id, phases.tournament_id FROM rounds
+    JOIN phases ON phases.id = rounds.phase_id
+    WHERE rounds.motion_id IS NOT NULL;
+
+UPDATE motions SET tournament_id = (
+    SELECT tournament_id FROM motion_usages
+    WHERE motion_usages.motion_id = motions.id
+    ORDER BY tournament_id
+    LIMIT 1
+)
+WHERE tournament_id IS NULL;
+
+CREATE TEMPORARY TABLE motion_copies AS
+    SELECT motion_usages.motion_id, motion_usages.tournament_id, gen_random_uuid() AS copy_id
+    FROM motion_usages
+    JOIN motions ON motions.id = motion_usages.motion_id
+    WHERE motions.tournament_id <> motion_usages.tournament_id;
+
+INSERT INTO motions(id, motion, adinfo, tournament_id)
+    SELECT motion_copies.copy_id, motions.motion, motions.adinfo, motion_copies.tournament_id
+    FROM motion_copies
+    JOIN motions ON motions.id = motion_copies.motion_id;
+
+UPDATE debates SET motion_id = motion_copies.copy_id
+FROM motion_copies
+WHERE debates.motion_id = motion_copies.motion_id
+    AND debates.tournament_id = motion_copies.tournament_id;
+
+UPDATE rounds SET motion_id = motion_copies.copy_id
+FROM motion_copies, phases
+WHERE phases.id = rounds.phase_id
+    AND rounds.motion_id = motion_copies.motion_id
+    AND phases.tournament_id = motion_copies.tournament_id;
+
+CREATE TABLE IF NOT EXISTS unattributed_motions AS
+    SELECT id, motion, adinfo FROM motions WHERE tournament_id IS NULL;
+DELETE FROM motions WHERE tournament_id IS NULL;
+
+DROP TABLE motion_usages, motion_copies;
+
+ALTER TABLE motions
+    ALTER COLUMN tournament_id SET NOT NULL,
+    ADD CONSTRAINT motions_tournament_id_motion_key UNIQUE (tournament_id, motion);
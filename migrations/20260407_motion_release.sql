@@ -0,0 +1,5 @@
+ALTER TABLE rounds ADD COLUMN IF NOT EXISTS motion_release_time TIMESTAMPTZ;
+
+-- Motions of rounds that have already started are considered announced
+UPDATE rounds SET motion_release_time = COALESCE(planned_start_time, NOW())
+WHERE motion_id IS NOT NULL AND status <> 'Planned';
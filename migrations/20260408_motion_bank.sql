@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS bank_motions (
+    id                    UUID NOT NULL UNIQUE PRIMARY KEY,
+    motion                TEXT NOT NULL UNIQUE,
+    adinfo                TEXT,
+    tags                  TEXT[] NOT NULL DEFAULT '{}',
+    language              TEXT,
+    difficulty            TEXT CHECK (difficulty IN ('Easy', 'Medium', 'Hard')),
+    source_tournament_id  UUID REFERENCES tournaments(id) ON DELETE SET NULL,
+    search_vector         TSVECTOR GENERATED ALWAYS AS (
+        to_tsvector('simple', motion || ' ' || COALESCE(adinfo, ''))
+    ) STORED
+);
+
+CREATE INDEX IF NOT EXISTS bank_motions_search_vector_idx
+    ON bank_motions USING GIN (search_vector);
+CREATE INDEX IF NOT EXISTS bank_motions_tags_idx
+    ON bank_motions USING GIN (tags);
+
+-- Motions that could not be attributed to any tournament
+-- are copied into the bank from unattributed_motions,
+-- which 20260406_tournament_motions.sql keeps. They were unique
+-- across the system, so every one of them finds its place in the bank.
+INSERT INTO bank_motions(id, motion, adinfo)
+    SELECT id, motion, adinfo FROM unattributed_motions;
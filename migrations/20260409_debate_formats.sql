@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS debate_formats (
+    id                 UUID NOT NULL UNIQUE PRIMARY KEY,
+    tournament_id      UUID NOT NULL REFERENCES tournaments(id),
+    name               TEXT NOT NULL,
+    speakers_per_team  INTEGER NOT NULL CHECK (speakers_per_team > 0),
+    ad_vocem_time      INTEGER NOT NULL DEFAULT 0 CHECK (ad_vocem_time >= 0),
+    UNIQUE (tournament_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS debate_format_speeches (
+    debate_format_id      UUID NOT NULL REFERENCES debate_formats(id) ON DELETE CASCADE,
+    speech_order          INTEGER NOT NULL,
+    side                  TEXT NOT NULL CHECK (side IN ('Proposition', 'Opposition')),
+    speaker_position      INTEGER NOT NULL,
+    duration              INTEGER NOT NULL,
+    start_protected_time  INTEGER NOT NULL DEFAULT 0,
+    end_protected_time    INTEGER NOT NULL DEFAULT 0,
+    is_reply              BOOLEAN NOT NULL DEFAULT FALSE,
+    allows_ad_vocem       BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (debate_format_id, speech_order)
+);
+
+ALTER TABLE tournaments ADD COLUMN IF NOT EXISTS debate_format_id
+    UUID REFERENCES debate_formats(id) ON DELETE SET NULL;
+ALTER TABLE debates ADD COLUMN IF NOT EXISTS debate_format_id
+    UUID REFERENCES debate_formats(id);
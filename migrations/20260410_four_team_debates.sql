@@ -0,0 +1,35 @@
+ALTER TABLE debate_format_speeches
+    DROP CONSTRAINT IF EXISTS debate_format_speeches_side_check;
+ALTER TABLE debate_format_speeches
+    ADD CONSTRAINT debate_format_speeches_side_check CHECK (side IN (
+        'Proposition', 'Opposition',
+        'OpeningGovernment', 'OpeningOpposition', 'ClosingGovernment', 'ClosingOpposition'
+    ));
+
+ALTER TABLE debate_teams_assignments ADD COLUMN IF NOT EXISTS side TEXT DEFAULT NULL
+    CHECK (side IN (
+        'Proposition', 'Opposition',
+        'OpeningGovernment', 'OpeningOpposition', 'ClosingGovernment', 'ClosingOpposition'
+    ));
+UPDATE debate_teams_assignments
+    SET side = CASE WHEN is_proposition THEN 'Proposition' ELSE 'Opposition' END
+    WHERE side IS NULL AND is_proposition IS NOT NULL;
+-- The side is the only stored fact; is_proposition follows from it
+ALTER TABLE debate_teams_assignments DROP COLUMN IF EXISTS is_proposition;
+ALTER TABLE debate_teams_assignments ADD COLUMN is_proposition BOOLEAN
+    GENERATED ALWAYS AS (CASE side
+        WHEN 'Proposition' THEN true
+        WHEN 'Opposition' THEN false
+    END) STORED;
+
+ALTER TABLE verdicts ALTER COLUMN proposition_won DROP NOT NULL;
+
+CREATE TABLE IF NOT EXISTS verdict_rankings (
+    verdict_id  UUID NOT NULL REFERENCES verdicts(id) ON DELETE CASCADE,
+    side        TEXT NOT NULL CHECK (side IN (
+        'OpeningGovernment', 'OpeningOpposition', 'ClosingGovernment', 'ClosingOpposition'
+    )),
+    rank        INTEGER NOT NULL CHECK (rank > 0),
+    PRIMARY KEY (verdict_id, side),
+    UNIQUE (verdict_id, rank)
+);
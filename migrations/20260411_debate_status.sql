@@ -0,0 +1,12 @@
+ALTER TABLE debates ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'Scheduled'
+    CHECK (status IN (
+        'Scheduled', 'SidesDrawn', 'Preparation', 'InProgress',
+        'AwaitingVerdicts', 'Concluded', 'Cancelled'
+    ));
+ALTER TABLE debates ADD COLUMN IF NOT EXISTS preparation_end_time TIMESTAMPTZ;
+
+-- Debates ruled by their whole panel are considered concluded
+UPDATE debates d SET status = 'Concluded'
+WHERE EXISTS (SELECT 1 FROM debate_judge_assignments WHERE debate_id = d.id)
+    AND (SELECT COUNT(*) FROM verdicts WHERE debate_id = d.id)
+        >= (SELECT COUNT(*) FROM debate_judge_assignments WHERE debate_id = d.id);
@@ -0,0 +1,11 @@
+ALTER TABLE tournaments ADD COLUMN IF NOT EXISTS side_draw_seed BIGINT;
+
+CREATE TABLE IF NOT EXISTS side_draws (
+    id                UUID NOT NULL UNIQUE PRIMARY KEY,
+    debate_id         UUID NOT NULL REFERENCES debates(id) ON DELETE CASCADE,
+    seed              BIGINT NOT NULL,
+    drawn_at          TIMESTAMPTZ NOT NULL,
+    drawn_by_user_id  UUID NOT NULL REFERENCES users(id),
+    team_ids          UUID[] NOT NULL,
+    sides             TEXT[] NOT NULL
+);
@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS rule_overrides (
+    id                        UUID NOT NULL UNIQUE PRIMARY KEY,
+    phase_id                  UUID UNIQUE REFERENCES phases(id) ON DELETE CASCADE,
+    round_id                  UUID UNIQUE REFERENCES rounds(id) ON DELETE CASCADE,
+    debate_id                 UUID UNIQUE REFERENCES debates(id) ON DELETE CASCADE,
+    speech_time               INTEGER,
+    end_protected_time        INTEGER,
+    start_protected_time      INTEGER,
+    ad_vocem_time             INTEGER,
+    debate_time_slot          INTEGER,
+    debate_preparation_time   INTEGER,
+    beep_on_speech_end        BOOLEAN,
+    beep_on_protected_time    BOOLEAN,
+    visualize_protected_time  BOOLEAN,
+    CHECK (num_nonnulls(phase_id, round_id, debate_id) = 1)
+);
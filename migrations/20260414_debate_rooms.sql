@@ -0,0 +1,26 @@
+ALTER TABLE debates ADD COLUMN IF NOT EXISTS room_id
+    UUID REFERENCES rooms(id) ON DELETE SET NULL;
+
+-- Time slot of every debate, starting with its round. The length follows
+-- rule overrides in order: tournament -> phase -> round -> debate.
+-- Every tournament has a time slot, set to its default on creation.
+CREATE OR REPLACE VIEW room_bookings AS
+SELECT
+    d.id AS debate_id,
+    d.tournament_id,
+    d.round_id,
+    d.room_id,
+    d.status,
+    r.planned_start_time AS start_time,
+    r.planned_start_time + make_interval(mins => COALESCE(
+        debate_overrides.debate_time_slot,
+        round_overrides.debate_time_slot,
+        phase_overrides.debate_time_slot,
+        t.debate_time_slot
+    )) AS end_time
+FROM debates d
+JOIN rounds r ON r.id = d.round_id
+JOIN tournaments t ON t.id = d.tournament_id
+LEFT JOIN rule_overrides debate_overrides ON debate_overrides.debate_id = d.id
+LEFT JOIN rule_overrides round_overrides ON round_overrides.round_id = r.id
+LEFT JOIN rule_overrides phase_overrides ON phase_overrides.phase_id = r.phase_id;
@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS room_closures (
+    id          UUID NOT NULL UNIQUE PRIMARY KEY,
+    room_id     UUID NOT NULL REFERENCES rooms(id) ON DELETE CASCADE,
+    reason      TEXT NOT NULL,
+    start_time  TIMESTAMPTZ NOT NULL,
+    end_time    TIMESTAMPTZ NOT NULL,
+    CHECK (start_time < end_time)
+);
+
+ALTER TABLE rooms DROP COLUMN IF EXISTS is_occupied;
+
+-- A room is occupied while a debate held in it is being prepared, debated
+-- or ruled on, or while it is closed.
+CREATE OR REPLACE FUNCTION room_is_occupied(room UUID) RETURNS BOOLEAN
+LANGUAGE SQL STABLE AS $$
+    SELECT EXISTS(
+        SELECT 1 FROM debates
+        WHERE room_id = room
+        AND status IN ('Preparation', 'InProgress', 'AwaitingVerdicts')
+    ) OR EXISTS(
+        SELECT 1 FROM room_closures
+        WHERE room_id = room AND start_time <= now() AND now() < end_time
+    )
+$$;
@@ -0,0 +1,72 @@
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS capacity INTEGER CHECK (capacity > 0);
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS building TEXT;
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS floor INTEGER;
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS has_projector BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS is_step_free BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE rooms ADD COLUMN IF NOT EXISTS is_online_capable BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- The fields above replace free-text remarks. The remarks are archived
+-- word for word first, since the heuristics below cannot read every one,
+-- and whatever they recognise is carried over before the column is dropped.
+CREATE TABLE IF NOT EXISTS room_remarks_archive (
+    room_id     UUID NOT NULL PRIMARY KEY REFERENCES rooms(id) ON DELETE CASCADE,
+    remarks     TEXT NOT NULL
+);
+INSERT INTO room_remarks_archive (room_id, remarks)
+SELECT id, remarks FROM rooms WHERE remarks IS NOT NULL;
+UPDATE rooms SET
+    capacity = COALESCE(capacity, NULLIF(substring(
+        remarks FROM '(?i)(\d{1,4})\s*(?:seats|people|persons|places)'
+    )::INTEGER, 0)),
+    floor = COALESCE(
+        floor,
+        substring(remarks FROM '(?i)floor\s*(-?\d{1,3})')::INTEGER,
+        substring(remarks FROM '(?i)(-?\d{1,3})(?:st|nd|rd|th)?\s*floor')::INTEGER
+    ),
+    building = COALESCE(building, substring(
+        remarks FROM '(?i)building:?\s*([^,;.]+[^,;.\s])'
+    )),
+    has_projector = has_projector OR (remarks ~* 'projector|beamer'
+        AND remarks !~* '(no|without|broken)\s+(projector|beamer)'),
+    is_step_free = is_step_free OR (remarks ~* 'step[- ]free|wheelchair'
+        AND remarks !~* '(not|no)\s+(step[- ]free|wheelchair)'),
+    is_online_capable = is_online_capable OR (remarks ~* 'online|remote'
+        AND remarks !~* '(not|no)\s+(online|remote)')
+WHERE remarks IS NOT NULL;
+ALTER TABLE rooms DROP COLUMN IF EXISTS remarks;
+
+CREATE TABLE IF NOT EXISTS accessibility_requirements (
+    id                      UUID NOT NULL UNIQUE PRIMARY KEY,
+    tournament_id           UUID NOT NULL REFERENCES tournaments(id) ON DELETE CASCADE,
+    team_id                 UUID UNIQUE REFERENCES teams(id) ON DELETE CASCADE,
+    judge_user_id           UUID REFERENCES users(id) ON DELETE CASCADE,
+    needs_step_free_access  BOOLEAN NOT NULL DEFAULT FALSE,
+    needs_projector         BOOLEAN NOT NULL DEFAULT FALSE,
+    needs_online_room       BOOLEAN NOT NULL DEFAULT FALSE,
+    UNIQUE (tournament_id, judge_user_id),
+    CHECK (num_nonnulls(team_id, judge_user_id) = 1)
+);
+
+-- What a room must offer to hold a debate: space for the speakers
+-- of its teams and its panel, and whatever the teams and Judges need.
+CREATE OR REPLACE VIEW debate_room_needs AS
+SELECT
+    d.id AS debate_id,
+    (
+        SELECT COUNT(*) FROM attendees
+        JOIN debate_teams_assignments t ON t.team_id = attendees.team_id
+        WHERE t.debate_id = d.id
+    ) + (
+        SELECT COUNT(*) FROM debate_judge_assignments j WHERE j.debate_id = d.id
+    ) AS participants,
+    COALESCE(bool_or(r.needs_step_free_access), FALSE) AS needs_step_free_access,
+    COALESCE(bool_or(r.needs_projector), FALSE) AS needs_projector,
+    COALESCE(bool_or(r.needs_online_room), FALSE) AS needs_online_room
+FROM debates d
+LEFT JOIN accessibility_requirements r ON r.tournament_id = d.tournament_id AND (
+    r.team_id IN (SELECT team_id FROM debate_teams_assignments WHERE debate_id = d.id)
+    OR r.judge_user_id IN (
+        SELECT judge_user_id FROM debate_judge_assignments WHERE debate_id = d.id
+    )
+)
+GROUP BY d.id;
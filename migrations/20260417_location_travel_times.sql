@@ -0,0 +1,16 @@
+-- Travel times are the same both ways, so every pair of locations
+-- is stored once, with the lower ID first.
+CREATE TABLE IF NOT EXISTS location_travel_times (
+    id                UUID NOT NULL UNIQUE PRIMARY KEY,
+    from_location_id  UUID NOT NULL REFERENCES locations(id) ON DELETE CASCADE,
+    to_location_id    UUID NOT NULL REFERENCES locations(id) ON DELETE CASCADE,
+    minutes           INTEGER NOT NULL CHECK (minutes >= 0),
+    UNIQUE (from_location_id, to_location_id),
+    CHECK (from_location_id < to_location_id)
+);
+
+-- Teams and Judges taking part in every debate
+CREATE OR REPLACE VIEW debate_participants AS
+SELECT debate_id, team_id AS participant_id FROM debate_teams_assignments
+UNION ALL
+SELECT debate_id, judge_user_id AS participant_id FROM debate_judge_assignments;
@@ -0,0 +1,12 @@
+-- Every Judge rules on a debate once. Should a Judge have filed
+-- several verdicts on the same debate, only the latest one is kept.
+DELETE FROM verdicts v
+WHERE EXISTS (
+    SELECT 1 FROM verdicts newer
+    WHERE newer.judge_user_id = v.judge_user_id
+        AND newer.debate_id = v.debate_id
+        AND newer.id > v.id
+);
+
+ALTER TABLE verdicts
+    ADD CONSTRAINT verdicts_judge_user_id_debate_id_key UNIQUE (judge_user_id, debate_id);
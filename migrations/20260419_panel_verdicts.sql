@@ -0,0 +1,8 @@
+-- Verdicts that count towards the result of a debate:
+-- one from every Judge who still sits on the panel
+CREATE OR REPLACE VIEW panel_verdicts AS
+SELECT DISTINCT ON (v.debate_id, v.judge_user_id) v.*
+FROM verdicts v
+JOIN debate_judge_assignments j
+    ON j.debate_id = v.debate_id AND j.judge_user_id = v.judge_user_id
+ORDER BY v.debate_id, v.judge_user_id, v.id DESC;
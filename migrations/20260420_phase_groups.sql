@@ -0,0 +1,7 @@
+-- Groups the teams were split into by the round-robin draw of a group phase
+CREATE TABLE IF NOT EXISTS phase_groups (
+    phase_id     UUID NOT NULL REFERENCES phases(id) ON DELETE CASCADE,
+    team_id      UUID NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    group_index  INTEGER NOT NULL CHECK (group_index >= 0),
+    PRIMARY KEY (phase_id, team_id)
+);
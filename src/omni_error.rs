@@ -16,6 +16,15 @@ const ROLES_PARSING_MESSAGE: &str = "Failed to parse user roles";
 const NOT_A_JUDGE_MESSAGE: &str =
     "This user is not a Judge and therefore cannot have affiliations";
 const PHASE_STATUS_PARSING_MESSAGE: &str = "Failed to parse phase status";
+const NOT_A_JUDGE_WITHIN_TOURNAMENT_MESSAGE: &str =
+    "This user is not a Judge within this tournament";
+const AFFILIATED_JUDGE_MESSAGE: &str =
+    "This Judge is affiliated with one of the teams attending the debate";
+const VERDICT_ON_BEHALF_MESSAGE: &str =
+    "Only users permitted to submit verdicts can rule on behalf of other Judges";
+const JUDGE_NOT_ON_PANEL_MESSAGE: &str = "This Judge is not on the panel of the debate";
+const LOW_POINT_WIN_MESSAGE: &str =
+    "Every team must have a higher total of speaker scores than the teams placed below it";
 
 #[derive(thiserror::Error, Debug)]
 pub enum OmniError {
@@ -61,6 +70,16 @@ pub enum OmniError {
     NotAJudgeAffiliationError,
     #[error("{PHASE_STATUS_PARSING_MESSAGE}")]
     PhaseStatusParsingError,
+    #[error("{NOT_A_JUDGE_WITHIN_TOURNAMENT_MESSAGE}")]
+    NotAJudgeError,
+    #[error("{AFFILIATED_JUDGE_MESSAGE}")]
+    AffiliatedJudgeError,
+    #[error("{VERDICT_ON_BEHALF_MESSAGE}")]
+    VerdictOnBehalfError,
+    #[error("{JUDGE_NOT_ON_PANEL_MESSAGE}")]
+    JudgeNotOnPanelError,
+    #[error("{LOW_POINT_WIN_MESSAGE}")]
+    LowPointWinError,
 }
 
 impl IntoResponse for OmniError {
@@ -155,6 +174,17 @@ impl OmniError {
             E::PhaseStatusParsingError => {
                 (StatusCode::BAD_REQUEST, self.clerr()).into_response()
             }
+            E::NotAJudgeError => (StatusCode::CONFLICT, self.clerr()).into_response(),
+            E::AffiliatedJudgeError => {
+                (StatusCode::CONFLICT, self.clerr()).into_response()
+            }
+            E::VerdictOnBehalfError => {
+                (StatusCode::UNAUTHORIZED, self.clerr()).into_response()
+            }
+            E::JudgeNotOnPanelError => {
+                (StatusCode::CONFLICT, self.clerr()).into_response()
+            }
+            E::LowPointWinError => (StatusCode::CONFLICT, self.clerr()).into_response(),
         }
     }
 
@@ -180,6 +210,11 @@ impl OmniError {
             E::RolesParsingError => ROLES_PARSING_MESSAGE,
             E::NotAJudgeAffiliationError => NOT_A_JUDGE_MESSAGE,
             E::PhaseStatusParsingError => PHASE_STATUS_PARSING_MESSAGE,
+            E::NotAJudgeError => NOT_A_JUDGE_WITHIN_TOURNAMENT_MESSAGE,
+            E::AffiliatedJudgeError => AFFILIATED_JUDGE_MESSAGE,
+            E::VerdictOnBehalfError => VERDICT_ON_BEHALF_MESSAGE,
+            E::JudgeNotOnPanelError => JUDGE_NOT_ON_PANEL_MESSAGE,
+            E::LowPointWinError => LOW_POINT_WIN_MESSAGE,
         }
         .to_string()
     }
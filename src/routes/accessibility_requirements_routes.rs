@@ -0,0 +1,252 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        accessibility_requirements::{AccessibilityRequirements, RequirementHolder},
+        roles::Role,
+        teams::Team,
+    },
+    users::{permissions::Permission, TournamentUser, User},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/teams/{id}/accessibility_requirements",
+            get(get_team_accessibility_requirements)
+                .put(put_team_accessibility_requirements),
+        )
+        .route(
+            "/tournaments/{tournament_id}/judges/{id}/accessibility_requirements",
+            get(get_judge_accessibility_requirements)
+                .put(put_judge_accessibility_requirements),
+        )
+}
+
+/// Get accessibility requirements of a team
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get,
+    path = "/tournaments/{tournament_id}/teams/{id}/accessibility_requirements",
+    responses(
+        (status=200, description = "Ok", body=AccessibilityRequirements),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read teams within this tournament",
+        ),
+        (status=404, description = "Tournament or team not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="teams"
+)]
+async fn get_team_accessibility_requirements(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadTeams) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let holder = get_team_holder(tournament_id, id, pool).await?;
+    get_requirements(holder, tournament_id, pool).await
+}
+
+/// Set accessibility requirements of a team
+///
+/// Replaces the previous requirements of the team.
+/// Rooms are only allocated to debates of the team if they meet the requirements.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put,
+    path = "/tournaments/{tournament_id}/teams/{id}/accessibility_requirements",
+    request_body=AccessibilityRequirements,
+    responses(
+        (
+            status=200, description = "Requirements set successfully",
+            body=AccessibilityRequirements
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify teams within this tournament",
+        ),
+        (status=404, description = "Tournament or team not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="teams"
+)]
+async fn put_team_accessibility_requirements(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    Json(json): Json<AccessibilityRequirements>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteTeams) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let holder = get_team_holder(tournament_id, id, pool).await?;
+    put_requirements(json, holder, tournament_id, pool).await
+}
+
+/// Get accessibility requirements of a Judge
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get,
+    path = "/tournaments/{tournament_id}/judges/{id}/accessibility_requirements",
+    responses(
+        (status=200, description = "Ok", body=AccessibilityRequirements),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read attendees within this tournament",
+        ),
+        (status=404, description = "Tournament or Judge not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="users"
+)]
+async fn get_judge_accessibility_requirements(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadAttendees) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let holder = get_judge_holder(tournament_id, id, pool).await?;
+    get_requirements(holder, tournament_id, pool).await
+}
+
+/// Set accessibility requirements of a Judge
+///
+/// Replaces the previous requirements of the Judge within this tournament.
+/// Rooms are only allocated to debates judged by the Judge
+/// if they meet the requirements.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put,
+    path = "/tournaments/{tournament_id}/judges/{id}/accessibility_requirements",
+    request_body=AccessibilityRequirements,
+    responses(
+        (
+            status=200, description = "Requirements set successfully",
+            body=AccessibilityRequirements
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify attendees within this tournament",
+        ),
+        (status=404, description = "Tournament or Judge not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="users"
+)]
+async fn put_judge_accessibility_requirements(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    Json(json): Json<AccessibilityRequirements>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteAttendees) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let holder = get_judge_holder(tournament_id, id, pool).await?;
+    put_requirements(json, holder, tournament_id, pool).await
+}
+
+async fn get_requirements(
+    holder: RequirementHolder,
+    tournament_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<Response, OmniError> {
+    match AccessibilityRequirements::get(holder, tournament_id, pool).await {
+        Ok(requirements) => Ok(Json(requirements).into_response()),
+        Err(e) => {
+            error!("Error getting accessibility requirements: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn put_requirements(
+    requirements: AccessibilityRequirements,
+    holder: RequirementHolder,
+    tournament_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<Response, OmniError> {
+    match requirements.put(holder, tournament_id, pool).await {
+        Ok(requirements) => Ok(Json(requirements).into_response()),
+        Err(e) => {
+            error!("Error setting accessibility requirements: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn get_team_holder(
+    tournament_id: Uuid,
+    team_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<RequirementHolder, OmniError> {
+    let team = Team::get_by_id(team_id, pool).await?;
+    match team.tournament_id == tournament_id {
+        true => Ok(RequirementHolder::Team(team.id)),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
+
+async fn get_judge_holder(
+    tournament_id: Uuid,
+    user_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<RequirementHolder, OmniError> {
+    let user = User::get_by_id(user_id, pool).await?;
+    match user.has_role(Role::Judge, tournament_id, pool).await? {
+        true => Ok(RequirementHolder::Judge(user.id)),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
@@ -13,12 +13,14 @@ use uuid::Uuid;
 use crate::{
     omni_error::OmniError,
     setup::AppState,
-    tournaments::attendees::{Attendee, AttendeePatch},
+    tournaments::{
+        attendees::{Attendee, AttendeePatch},
+        debate_formats::DebateFormat,
+        Tournament,
+    },
     users::{permissions::Permission, TournamentUser},
 };
 
-const POSITION_OUT_OF_RANGE_MESSAGE: &str = "Attendee position must be in range of 1-4.";
-
 pub fn route() -> Router<AppState> {
     Router::new()
         .route(
@@ -35,6 +37,8 @@ pub fn route() -> Router<AppState> {
 
 /// Create an attendee
 ///
+/// The position of a speaker must not exceed the number of speakers per team
+/// of the tournament's debate format.
 /// Requires the WritesAttendee permission.
 #[utoipa::path(
     post,
@@ -56,6 +60,9 @@ pub fn route() -> Router<AppState> {
         (
             status=409, description = "Attendee position is duplicated",
         ),
+        (
+            status=422, description = "Attendee position out of range of the debate format",
+        ),
         (
             status=500, description = "Internal server error",
         ),
@@ -79,13 +86,8 @@ async fn create_attendee(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    if let Some(position) = attendee.position {
-        if !attendee_position_is_valid(position) {
-            return Err(OmniError::ExplicitError {
-                status: StatusCode::UNPROCESSABLE_ENTITY,
-                message: POSITION_OUT_OF_RANGE_MESSAGE.to_owned(),
-            });
-        }
+    if attendee.position.is_some() {
+        validate_attendee_position(attendee.position, tournament_id, pool).await?;
         match attendee_position_is_duplicated(&attendee, pool).await {
             Ok(position_duplicated) => {
                 if position_duplicated {
@@ -210,13 +212,15 @@ async fn get_attendee_by_id(
         ),
         (status=404, description = "Tournament or attendee not found"),
         (status=409, description = "Attendee position is duplicated"),
-        (status=422, description = "Attendee position out of range [1-4]"),
+        (
+            status=422, description = "Attendee position out of range of the debate format",
+        ),
         (status=500, description = "Internal server error"),
     ),
     tag="attendees"
 )]
 async fn patch_attendee_by_id(
-    Path((id, tournament_id)): Path<(Uuid, Uuid)>,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
     State(state): State<AppState>,
     headers: HeaderMap,
     cookies: Cookies,
@@ -231,14 +235,7 @@ async fn patch_attendee_by_id(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    if new_attendee.position.is_some()
-        && !attendee_position_is_valid(new_attendee.position.unwrap())
-    {
-        return Err(OmniError::ExplicitError {
-            status: StatusCode::UNPROCESSABLE_ENTITY,
-            message: POSITION_OUT_OF_RANGE_MESSAGE.to_owned(),
-        });
-    }
+    validate_attendee_position(new_attendee.position, tournament_id, pool).await?;
     let attendee = Attendee::get_by_id(id, pool).await?;
     let position_is_unique = attendee_position_is_duplicated(&attendee, pool).await?;
     if !position_is_unique {
@@ -292,8 +289,28 @@ async fn delete_attendee_by_id(
     }
 }
 
-fn attendee_position_is_valid(position: i32) -> bool {
-    (1..=4).contains(&position)
+/// Speaker positions follow the number of speakers per team
+/// of the tournament's debate format
+async fn validate_attendee_position(
+    position: Option<i32>,
+    tournament_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<(), OmniError> {
+    let Some(position) = position else {
+        return Ok(());
+    };
+    let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let format = DebateFormat::get_for_tournament(&tournament, pool).await?;
+    match format.is_valid_speaker_position(position) {
+        true => Ok(()),
+        false => Err(OmniError::ExplicitError {
+            status: StatusCode::UNPROCESSABLE_ENTITY,
+            message: format!(
+                "Attendee position must be in range of 1-{}.",
+                format.speakers_per_team
+            ),
+        }),
+    }
 }
 
 async fn attendee_position_is_duplicated(
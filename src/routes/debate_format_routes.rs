@@ -0,0 +1,456 @@
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        debate_formats::{DebateFormat, DebateFormatPatch, DebateFormatPreset},
+        Tournament,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+const DUPLICATE_FORMAT_ERROR: &str =
+    "Debate format with such name already exists within this tournament";
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/debate_formats",
+            get(get_debate_formats).post(create_debate_format),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debate_formats/{id}",
+            get(get_debate_format_by_id)
+                .delete(delete_debate_format_by_id)
+                .patch(patch_debate_format_by_id),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debate_format_presets",
+            get(get_debate_format_presets),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debate_format_presets/{preset}",
+            post(create_debate_format_from_preset),
+        )
+}
+
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debate_formats",
+    responses(
+        (
+            status=200, description = "Ok",
+            body=Vec<DebateFormat>,
+            example=json!(get_debate_formats_list_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+    ),
+    tag="debate_formats"
+)]
+/// Get a list of all debate formats within a tournament
+///
+/// The user must be given a role within this tournament to use this endpoint.
+async fn get_debate_formats(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match DebateFormat::get_all(tournament_id, pool).await {
+        Ok(formats) => Ok(Json(formats).into_response()),
+        Err(e) => {
+            error!("Error getting a list of debate formats: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Create a new debate format
+///
+/// Speaker positions of the speeches must not exceed speakers_per_team.
+/// Available only to the tournament Organizers.
+#[utoipa::path(
+    post,
+    request_body=DebateFormat,
+    path = "/tournaments/{tournament_id}/debate_formats",
+    responses(
+        (
+            status=200, description = "Debate format created successfully",
+            body=DebateFormat,
+            example=json!(get_debate_format_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+        (status=409, description = DUPLICATE_FORMAT_ERROR)
+    ),
+    tag="debate_formats"
+)]
+async fn create_debate_format(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+    Json(json): Json<DebateFormat>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let _tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    match DebateFormat::post(tournament_id, json, pool).await {
+        Ok(format) => Ok(Json(format).into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Get details of an existing debate format
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debate_formats/{id}",
+    responses(
+        (
+            status=200, description = "Ok", body=DebateFormat,
+            example=json!(get_debate_format_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read this tournament"
+        ),
+        (status=404, description = "Tournament or debate format not found"),
+    ),
+    tag="debate_formats"
+)]
+async fn get_debate_format_by_id(
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match get_tournament_format(tournament_id, id, pool).await {
+        Ok(format) => Ok(Json(format).into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Patch an existing debate format
+///
+/// Changes apply to debate timers that have not been started yet.
+/// Available only to the tournament Organizers.
+#[utoipa::path(patch, path = "/tournaments/{tournament_id}/debate_formats/{id}",
+    request_body=DebateFormatPatch,
+    responses(
+        (
+            status=200, description = "Debate format patched successfully",
+            body=DebateFormat,
+            example=json!(get_debate_format_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify this tournament"
+        ),
+        (status=404, description = "Tournament or debate format not found"),
+        (status=409, description = DUPLICATE_FORMAT_ERROR)
+    ),
+    tag="debate_formats"
+)]
+async fn patch_debate_format_by_id(
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Json(patch): Json<DebateFormatPatch>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let format = get_tournament_format(tournament_id, id, pool).await?;
+    match format.patch(patch, pool).await {
+        Ok(format) => Ok(Json(format).into_response()),
+        Err(e) => {
+            error!("Failed to patch debate format {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Delete an existing debate format
+///
+/// If the tournament uses the format, it goes back to the Oxford format.
+/// Formats used by particular debates cannot be deleted.
+/// Available only to the tournament Organizers.
+#[utoipa::path(delete, path = "/tournaments/{tournament_id}/debate_formats/{id}",
+    responses(
+        (status=204, description = "Debate format deleted successfully"),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify this tournament"
+        ),
+        (status=404, description = "Tournament or debate format not found"),
+        (status=409, description = "Debates use this format. They must be changed first")
+    ),
+    tag="debate_formats"
+)]
+async fn delete_debate_format_by_id(
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let format = get_tournament_format(tournament_id, id, pool).await?;
+    match format.delete(pool).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(e) => {
+            if e.is_sqlx_foreign_key_violation() {
+                Err(OmniError::DependentResourcesError)
+            } else {
+                Err(e)?
+            }
+        }
+    }
+}
+
+/// Get preset debate formats
+///
+/// Returns the formats as they would be added to the tournament, without adding them.
+/// The Oxford preset follows speech rules of the tournament.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debate_format_presets",
+    responses(
+        (status=200, description = "Ok", body=Vec<DebateFormat>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+    ),
+    tag="debate_formats"
+)]
+async fn get_debate_format_presets(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let presets: Vec<DebateFormat> = [
+        DebateFormatPreset::Oxford,
+        DebateFormatPreset::Wsdc,
+        DebateFormatPreset::Australs,
+        DebateFormatPreset::BritishParliamentary,
+    ]
+    .into_iter()
+    .map(|preset| tournament.get_preset_debate_format(preset))
+    .collect();
+    Ok(Json(presets).into_response())
+}
+
+/// Add a preset debate format to a tournament
+///
+/// The added format can then be adapted like any other format of the tournament.
+/// Available only to the tournament Organizers.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/debate_format_presets/{preset}",
+    params(
+        ("tournament_id" = Uuid, Path),
+        ("preset" = DebateFormatPreset, Path),
+    ),
+    responses(
+        (
+            status=200, description = "Debate format created successfully",
+            body=DebateFormat,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+        (status=409, description = DUPLICATE_FORMAT_ERROR)
+    ),
+    tag="debate_formats"
+)]
+async fn create_debate_format_from_preset(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, preset)): Path<(Uuid, DebateFormatPreset)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteTournament) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let format = tournament.get_preset_debate_format(preset);
+    match DebateFormat::post(tournament_id, format, pool).await {
+        Ok(format) => Ok(Json(format).into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+async fn get_tournament_format(
+    tournament_id: Uuid,
+    id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<DebateFormat, OmniError> {
+    let format = DebateFormat::get_by_id(id, pool).await?;
+    match format.tournament_id == tournament_id {
+        true => Ok(format),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
+
+fn get_debate_format_example() -> String {
+    r#"
+    {
+    "id": "01962d2f-7b1e-7c3a-9d5e-2f4b6a8c0e13",
+    "tournament_id": "01941267-0109-7405-b30e-7883d309c603",
+    "name": "WSDC",
+    "speakers_per_team": 3,
+    "ad_vocem_time": 0,
+    "speeches": [
+        {
+        "side": "Proposition",
+        "speaker_position": 1,
+        "duration": 480,
+        "start_protected_time": 60,
+        "end_protected_time": 60,
+        "is_reply": false,
+        "allows_ad_vocem": false
+        },
+        {
+        "side": "Opposition",
+        "speaker_position": 1,
+        "duration": 480,
+        "start_protected_time": 60,
+        "end_protected_time": 60,
+        "is_reply": false,
+        "allows_ad_vocem": false
+        },
+        {
+        "side": "Opposition",
+        "speaker_position": 1,
+        "duration": 240,
+        "start_protected_time": 240,
+        "end_protected_time": 0,
+        "is_reply": true,
+        "allows_ad_vocem": false
+        }
+    ]
+    }
+    "#
+    .to_owned()
+}
+
+fn get_debate_formats_list_example() -> String {
+    r#"
+    [
+        {
+        "id": "01962d2f-7b1e-7c3a-9d5e-2f4b6a8c0e13",
+        "tournament_id": "01941267-0109-7405-b30e-7883d309c603",
+        "name": "Short Oxford",
+        "speakers_per_team": 2,
+        "ad_vocem_time": 60,
+        "speeches": [
+            {
+            "side": "Proposition",
+            "speaker_position": 1,
+            "duration": 240,
+            "start_protected_time": 0,
+            "end_protected_time": 30,
+            "is_reply": false,
+            "allows_ad_vocem": true
+            },
+            {
+            "side": "Opposition",
+            "speaker_position": 1,
+            "duration": 240,
+            "start_protected_time": 0,
+            "end_protected_time": 30,
+            "is_reply": false,
+            "allows_ad_vocem": true
+            }
+        ]
+        }
+    ]
+    "#
+    .to_owned()
+}
@@ -0,0 +1,239 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{debate_judges::DebateJudgeAssignment, debates::Debate},
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/debates/{debate_id}/judges",
+            get(get_debate_judges).post(create_debate_judge),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{debate_id}/judges/{id}",
+            get(get_debate_judge_by_id).delete(delete_debate_judge_by_id),
+        )
+}
+
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{debate_id}/judges",
+    responses(
+        (
+            status=200, description = "Ok",
+            body=Vec<DebateJudgeAssignment>,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+/// Get the panel of Judges assigned to a debate
+///
+/// The user must be given a role within this tournament to use this endpoint.
+async fn get_debate_judges(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    match DebateJudgeAssignment::get_by_debate_id(debate.id, pool).await {
+        Ok(assignments) => Ok(Json(assignments).into_response()),
+        Err(e) => {
+            error!("Error getting the panel of a debate with id {debate_id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Assign a Judge to the panel of a debate
+///
+/// The user must be a Judge within this tournament,
+/// must not be affiliated with any of the debating teams
+/// and must not sit on another panel in the same round.
+/// Available only to Organizers and Admins.
+#[utoipa::path(post, request_body=DebateJudgeAssignment,
+    path = "/tournaments/{tournament_id}/debates/{debate_id}/judges",
+    responses(
+        (
+            status=200,
+            description = "Judge assigned successfully",
+            body=DebateJudgeAssignment,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament, debate or user not found"),
+        (
+            status=409,
+            description = "The user is not an eligible Judge for this debate",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn create_debate_judge(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+    Json(assignment): Json<DebateJudgeAssignment>,
+) -> Result<Response, OmniError> {
+    if assignment.debate_id != debate_id {
+        return Err(OmniError::BadRequestError);
+    }
+
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    match DebateJudgeAssignment::post(assignment, &debate, pool).await {
+        Ok(assignment) => Ok(Json(assignment).into_response()),
+        Err(e) => {
+            error!("Error assigning a Judge to a debate with id {debate_id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Get details of an existing Judge assignment
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{debate_id}/judges/{id}",
+    responses(
+        (
+            status=200,
+            description = "Ok",
+            body=DebateJudgeAssignment,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament, debate or assignment not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_debate_judge_by_id(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match get_assignment_of_debate(tournament_id, debate_id, id, pool).await {
+        Ok(assignment) => Ok(Json(assignment).into_response()),
+        Err(e) => match e {
+            OmniError::ResourceNotFoundError => Err(e),
+            _ => {
+                error!("Error getting a Judge assignment with id {id}: {e}");
+                Err(OmniError::InternalServerError)
+            }
+        },
+    }
+}
+
+/// Remove a Judge from the panel of a debate
+///
+/// Available only to Organizers and Admins.
+#[utoipa::path(delete, path = "/tournaments/{tournament_id}/debates/{debate_id}/judges/{id}",
+    responses
+    (
+        (status=204, description = "Judge removed from the panel successfully"),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament"
+        ),
+        (status=404, description = "Tournament, debate or assignment not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn delete_debate_judge_by_id(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let assignment = get_assignment_of_debate(tournament_id, debate_id, id, pool).await?;
+    match assignment.delete(pool).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(e) => {
+            error!("Error deleting a Judge assignment with id {id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+async fn get_assignment_of_debate(
+    tournament_id: Uuid,
+    debate_id: Uuid,
+    id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<DebateJudgeAssignment, OmniError> {
+    let _debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    let assignment = DebateJudgeAssignment::get_by_id(id, pool).await?;
+    if assignment.debate_id != debate_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    Ok(assignment)
+}
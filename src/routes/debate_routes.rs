@@ -1,8 +1,17 @@
+use std::slice;
+
 use crate::{
     omni_error::OmniError,
     setup::AppState,
     tournaments::{
-        debates::{Debate, DebatePatch},
+        debate_formats::DebateFormat,
+        debate_judges::DebateJudgeAssignment,
+        debate_results::DebateResult,
+        debate_teams::DebateTeamAssignment,
+        debates::{Debate, DebateDetails, DebatePatch, DebateStatus, DebateStatusChange},
+        motions::Motion,
+        rooms::Room,
+        rounds::Round,
         Tournament,
     },
     users::{permissions::Permission, TournamentUser},
@@ -11,7 +20,7 @@ use axum::{
     extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use tower_cookies::Cookies;
@@ -30,13 +39,21 @@ pub fn route() -> Router<AppState> {
                 .delete(delete_debate_by_id)
                 .patch(patch_debate_by_id),
         )
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/result",
+            get(get_debate_result),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/status",
+            post(change_debate_status),
+        )
 }
 
 #[utoipa::path(get, path = "/tournaments/{tournament_id}/debates",
     responses(
         (
             status=200, description = "Ok",
-            body=Vec<Debate>,
+            body=Vec<DebateDetails>,
         ),
         (status=400, description = "Bad request"),
         (status=401, description = "Authentication error"),
@@ -49,8 +66,10 @@ pub fn route() -> Router<AppState> {
     ),
     tag="debates"
 )]
-/// Get a list of all debates
+/// Get a list of all debates along with the teams and Judges assigned to them
 ///
+/// Motions are hidden until their rounds release them,
+/// unless the user is permitted to modify motions.
 /// The user must be given a role within this tournament to use this endpoint.
 async fn get_debates(
     State(state): State<AppState>,
@@ -67,12 +86,17 @@ async fn get_debates(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    match Tournament::get_by_id(tournament_id, pool)
+    let mut debates = Tournament::get_by_id(tournament_id, pool)
         .await?
         .get_debates(pool)
-        .await
-    {
-        Ok(debates) => Ok(Json(debates).into_response()),
+        .await?;
+    let rounds = Round::get_all(tournament_id, pool).await?;
+    Debate::hide_unreleased_motions(&mut debates, &rounds, &tournament_user);
+    let teams = DebateTeamAssignment::get_all(tournament_id, pool).await?;
+    match DebateJudgeAssignment::get_all(tournament_id, pool).await {
+        Ok(judges) => {
+            Ok(Json(DebateDetails::collect(debates, teams, judges)).into_response())
+        }
         Err(e) => {
             error!("Error getting a list of debates: {e}");
             Err(e)?
@@ -82,6 +106,7 @@ async fn get_debates(
 
 /// Create a new debate
 ///
+/// A room cannot be booked for two debates at the same time.
 /// Available only to Organizers and Admins.
 #[utoipa::path(post, request_body=Debate, path = "/tournaments/{tournament_id}/debates",
     responses(
@@ -97,6 +122,10 @@ async fn get_debates(
             description = "The user is not permitted to modify debates within this tournament",
         ),
         (status=404, description = "Tournament or attendee not found"),
+        (
+            status=409,
+            description = "The motion, the debate format or the room is not from this tournament, or the room is already booked"
+        ),
         (status=500, description = "Internal server error"),
     ),
     tag="debates"
@@ -117,6 +146,9 @@ async fn create_debate(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
+    Motion::validate_assignment(json.motion_id, tournament_id, pool).await?;
+    DebateFormat::validate_assignment(json.debate_format_id, tournament_id, pool).await?;
+    Room::validate_assignment(json.room_id, tournament_id, pool).await?;
     match Debate::post(tournament_id, json, &state.connection_pool).await {
         Ok(debate) => Ok(Json(debate).into_response()),
         Err(e) => {
@@ -126,15 +158,17 @@ async fn create_debate(
     }
 }
 
-/// Get details of an existing debate
+/// Get details of an existing debate, including its teams and panel of Judges
 ///
+/// The motion is hidden until the round releases it,
+/// unless the user is permitted to modify motions.
 /// The user must be given a role within this tournament to use this endpoint.
 #[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}",
     responses(
         (
             status=200,
             description = "Ok",
-            body=Debate,
+            body=DebateDetails,
         ),
         (status=400, description = "Bad request"),
         (status=401, description = "Authentication error"),
@@ -162,7 +196,14 @@ async fn get_debate_by_id(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    match Debate::get_by_id(debate_id, &state.connection_pool).await {
+    let mut debate = Debate::get_by_id(debate_id, pool).await?;
+    let round = Round::get_by_id(debate.round_id, pool).await?;
+    Debate::hide_unreleased_motions(
+        slice::from_mut(&mut debate),
+        &[round],
+        &tournament_user,
+    );
+    match debate.with_details(pool).await {
         Ok(debate) => Ok(Json(debate).into_response()),
         Err(e) => match e {
             OmniError::ResourceNotFoundError => Err(e),
@@ -174,8 +215,61 @@ async fn get_debate_by_id(
     }
 }
 
+/// Get the result of a debate, computed from verdicts of its panel
+///
+/// A debate is decided once every Judge on its panel has submitted a verdict.
+/// The side favoured by the majority of verdicts wins. If the verdicts are
+/// split evenly, the outcome depends on the tournament's verdict_tie_policy.
+/// Teams of four-team debates are placed by the sum of ranks given by the Judges
+/// and score 3, 2, 1 and 0 points.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/result",
+    responses(
+        (
+            status=200,
+            description = "Ok",
+            body=DebateResult,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_debate_result(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    let mut connection = pool.acquire().await?;
+    match DebateResult::get_by_debate_id(debate.id, &mut connection).await {
+        Ok(result) => Ok(Json(result).into_response()),
+        Err(e) => {
+            error!("Error getting the result of a debate with id {debate_id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
 /// Patch an existing debate
 ///
+/// A room cannot be booked for two debates at the same time.
 /// Available only to the tournament Organizers.
 #[utoipa::path(patch, path = "/tournaments/{tournament_id}/debates/{id}",
     request_body=DebatePatch,
@@ -191,6 +285,10 @@ async fn get_debate_by_id(
             description = "The user is not permitted to modify debates within this tournament"
         ),
         (status=404, description = "Tournament or debate not found"),
+        (
+            status=409,
+            description = "The motion, the debate format or the room is not from this tournament, or the room is already booked"
+        ),
         (status=500, description = "Internal server error"),
     ),
     tag="debates"
@@ -199,7 +297,7 @@ async fn patch_debate_by_id(
     State(state): State<AppState>,
     headers: HeaderMap,
     cookies: Cookies,
-    Path((id, tournament_id)): Path<(Uuid, Uuid)>,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
     Json(new_debate): Json<DebatePatch>,
 ) -> Result<Response, OmniError> {
     let pool = &state.connection_pool;
@@ -211,7 +309,12 @@ async fn patch_debate_by_id(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    let existing_debate = Debate::get_by_id(id, &state.connection_pool).await?;
+    let existing_debate =
+        Debate::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    Motion::validate_assignment(new_debate.motion_id, tournament_id, pool).await?;
+    DebateFormat::validate_assignment(new_debate.debate_format_id, tournament_id, pool)
+        .await?;
+    Room::validate_assignment(new_debate.room_id, tournament_id, pool).await?;
     match existing_debate
         .patch(new_debate, &state.connection_pool)
         .await
@@ -224,6 +327,71 @@ async fn patch_debate_by_id(
     }
 }
 
+/// Move a debate to another stage
+///
+/// Debates go from Scheduled through SidesDrawn, Preparation, InProgress
+/// and AwaitingVerdicts, one stage at a time. Sides are drawn once every position
+/// is taken by a team. Preparation requires a motion and lasts for the tournament's
+/// debate_preparation_time; the debate cannot start until it runs out.
+/// A debate awaiting verdicts is Concluded automatically once every Judge
+/// on the panel has submitted a verdict.
+/// Available to the Marshal conducting the debate and the tournament Organizers.
+/// Only Organizers can cancel debates.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/debates/{id}/status",
+    request_body=DebateStatusChange,
+    responses(
+        (
+            status=200, description = "Debate status changed successfully",
+            body=Debate,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to conduct this debate"
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (
+            status=409,
+            description = "The debate cannot move to this stage yet",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn change_debate_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    Json(change): Json<DebateStatusChange>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    let debate = Debate::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    let conducts_debate = debate.marshal_user_id == Some(tournament_user.user.id)
+        && tournament_user.has_permission(Permission::OperateDebateTimers)
+        && change.status != DebateStatus::Cancelled;
+    if !conducts_debate && !tournament_user.has_permission(Permission::WriteDebates) {
+        return Err(OmniError::InsufficientPermissionsError);
+    }
+
+    match debate.change_status(change.status, pool).await {
+        Ok(debate) => {
+            if debate.status.is_over() {
+                state.debate_timers.remove(debate.id);
+            }
+            Ok(Json(debate).into_response())
+        }
+        Err(e) => {
+            error!("Error changing the status of a debate with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
 /// Delete an existing debate
 ///
 /// Available only to the tournament Organizers.
@@ -259,7 +427,10 @@ async fn delete_debate_by_id(
 
     match Debate::get_by_id(debate_id, &state.connection_pool).await {
         Ok(debate) => match debate.delete(&state.connection_pool).await {
-            Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+            Ok(_) => {
+                state.debate_timers.remove(debate_id);
+                Ok(StatusCode::NO_CONTENT.into_response())
+            }
             Err(e) => match e {
                 OmniError::ResourceAlreadyExistsError => Err(e),
                 _ => Err(e),
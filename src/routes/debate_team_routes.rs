@@ -0,0 +1,295 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        debate_teams::{DebateTeamAssignment, DebateTeamAssignmentPatch},
+        debates::Debate,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/debates/{debate_id}/teams",
+            get(get_debate_teams).post(create_debate_team),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{debate_id}/teams/{id}",
+            get(get_debate_team_by_id)
+                .patch(patch_debate_team_by_id)
+                .delete(delete_debate_team_by_id),
+        )
+}
+
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{debate_id}/teams",
+    responses(
+        (
+            status=200, description = "Ok",
+            body=Vec<DebateTeamAssignment>,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+/// Get a list of teams assigned to a debate
+///
+/// The user must be given a role within this tournament to use this endpoint.
+async fn get_debate_teams(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    match DebateTeamAssignment::get_by_debate_id(debate.id, pool).await {
+        Ok(assignments) => Ok(Json(assignments).into_response()),
+        Err(e) => {
+            error!("Error getting teams of a debate with id {debate_id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Assign a team to a debate
+///
+/// A debate can be attended by one team per position of the debate format.
+/// A team can debate only once per round.
+/// Available only to Organizers and Admins.
+#[utoipa::path(post, request_body=DebateTeamAssignment,
+    path = "/tournaments/{tournament_id}/debates/{debate_id}/teams",
+    responses(
+        (
+            status=200,
+            description = "Team assigned successfully",
+            body=DebateTeamAssignment,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament, debate or team not found"),
+        (
+            status=409,
+            description = "The assignment conflicts with the debate or the round",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn create_debate_team(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+    Json(assignment): Json<DebateTeamAssignment>,
+) -> Result<Response, OmniError> {
+    if assignment.debate_id != debate_id {
+        return Err(OmniError::BadRequestError);
+    }
+
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    match DebateTeamAssignment::post(assignment, &debate, pool).await {
+        Ok(assignment) => Ok(Json(assignment).into_response()),
+        Err(e) => {
+            error!("Error assigning a team to a debate with id {debate_id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Get details of an existing team assignment
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{debate_id}/teams/{id}",
+    responses(
+        (
+            status=200,
+            description = "Ok",
+            body=DebateTeamAssignment,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament, debate or assignment not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_debate_team_by_id(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match get_assignment_of_debate(tournament_id, debate_id, id, pool).await {
+        Ok(assignment) => Ok(Json(assignment).into_response()),
+        Err(e) => match e {
+            OmniError::ResourceNotFoundError => Err(e),
+            _ => {
+                error!("Error getting a team assignment with id {id}: {e}");
+                Err(OmniError::InternalServerError)
+            }
+        },
+    }
+}
+
+/// Patch an existing team assignment
+///
+/// Can be used to swap the team or to set its side.
+/// Available only to Organizers and Admins.
+#[utoipa::path(patch, path = "/tournaments/{tournament_id}/debates/{debate_id}/teams/{id}",
+    request_body=DebateTeamAssignmentPatch,
+    responses(
+        (
+            status=200, description = "Team assignment patched successfully",
+            body=DebateTeamAssignment,
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament"
+        ),
+        (status=404, description = "Tournament, debate or assignment not found"),
+        (
+            status=409,
+            description = "The assignment conflicts with the debate or the round",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn patch_debate_team_by_id(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(patch): Json<DebateTeamAssignmentPatch>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let assignment = get_assignment_of_debate(tournament_id, debate_id, id, pool).await?;
+    match assignment.patch(patch, pool).await {
+        Ok(assignment) => Ok(Json(assignment).into_response()),
+        Err(e) => {
+            error!("Error patching a team assignment with id {id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Remove a team from a debate
+///
+/// Available only to Organizers and Admins.
+#[utoipa::path(delete, path = "/tournaments/{tournament_id}/debates/{debate_id}/teams/{id}",
+    responses
+    (
+        (status=204, description = "Team removed from the debate successfully"),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament"
+        ),
+        (status=404, description = "Tournament, debate or assignment not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn delete_debate_team_by_id(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let assignment = get_assignment_of_debate(tournament_id, debate_id, id, pool).await?;
+    match assignment.delete(pool).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(e) => {
+            error!("Error deleting a team assignment with id {id}: {e}");
+            Err(e)?
+        }
+    }
+}
+
+async fn get_assignment_of_debate(
+    tournament_id: Uuid,
+    debate_id: Uuid,
+    id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<DebateTeamAssignment, OmniError> {
+    let _debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    let assignment = DebateTeamAssignment::get_by_id(id, pool).await?;
+    if assignment.debate_id != debate_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    Ok(assignment)
+}
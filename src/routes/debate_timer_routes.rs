@@ -0,0 +1,271 @@
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, State,
+    },
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        debate_formats::DebateFormat,
+        debate_timers::{
+            DebateTimerEvent, DebateTimerState, SpeechRules, TimerAction, TimerCommand,
+        },
+        debates::{Debate, DebateStatus},
+        rule_overrides::RuleOverrides,
+        Tournament,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/timer",
+            get(get_debate_timer).post(operate_debate_timer),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/timer/live",
+            get(watch_debate_timer),
+        )
+}
+
+/// Get the current state of a debate timer
+///
+/// The timer follows the order of speeches of the debate format,
+/// along with beep settings of the tournament. While it runs, clients can count down on their own
+/// using running_since and server_time; /timer/live pushes every change as it happens.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/timer",
+    responses(
+        (status=200, description = "Ok", body=DebateTimerState),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_debate_timer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    let rules = get_speech_rules(&debate, pool).await?;
+    let timer = state.debate_timers.get_state(debate_id, rules);
+    Ok(Json(timer).into_response())
+}
+
+/// Operate a debate timer
+///
+/// Starts, pauses or resumes the current speech, or moves on to the next one.
+/// An ad vocem reply can be given to the other side instead of moving on.
+/// Speeches can only be started while the debate is in progress.
+/// Everyone watching the timer through /timer/live is notified.
+/// Available to the Marshal conducting the debate and the tournament Organizers.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/debates/{id}/timer",
+    request_body=TimerCommand,
+    responses(
+        (status=200, description = "Timer operated successfully", body=DebateTimerState),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to operate the timer of this debate",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (
+            status=409,
+            description = "The action cannot be performed in the current state of the timer, \
+            or in the current stage of the debate",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn operate_debate_timer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+    Json(command): Json<TimerCommand>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::OperateDebateTimers) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    if debate.marshal_user_id != Some(tournament_user.user.id)
+        && !tournament_user.has_permission(Permission::WriteDebates)
+    {
+        return Err(OmniError::InsufficientPermissionsError);
+    }
+
+    reject_if_over(&debate)?;
+    if starts_a_speech(command.action) && debate.status != DebateStatus::InProgress {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "Speeches can only be started while the debate is in progress"
+                .to_owned(),
+        });
+    }
+    let rules = get_speech_rules(&debate, pool).await?;
+    match state.debate_timers.apply(debate_id, rules, command.action) {
+        Ok(timer) => Ok(Json(timer).into_response()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Watch a debate timer live
+///
+/// Upgrades the connection to a WebSocket. The current state of the timer is sent
+/// right away, followed by a message every time the timer is operated
+/// and every time a beep is due. Each message is a JSON object with
+/// an "event" ("State" or "Beep") and its "data".
+/// The connection is closed once the debate is concluded, cancelled or deleted.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/timer/live",
+    responses(
+        (status=101, description = "Switching to the WebSocket protocol", body=DebateTimerEvent),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=409, description = "The debate is over"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn watch_debate_timer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+    websocket: WebSocketUpgrade,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    reject_if_over(&debate)?;
+    let rules = get_speech_rules(&debate, pool).await?;
+    let (timer, events) = state.debate_timers.subscribe(debate_id, rules);
+    Ok(websocket.on_upgrade(move |socket| push_timer_events(socket, timer, events)))
+}
+
+/// Timers of concluded or cancelled debates are not kept
+fn reject_if_over(debate: &Debate) -> Result<(), OmniError> {
+    match debate.status.is_over() {
+        true => Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: format!("The debate is {}", debate.status),
+        }),
+        false => Ok(()),
+    }
+}
+
+fn starts_a_speech(action: TimerAction) -> bool {
+    matches!(
+        action,
+        TimerAction::Start | TimerAction::NextSpeech | TimerAction::AdVocem
+    )
+}
+
+async fn get_speech_rules(
+    debate: &Debate,
+    pool: &Pool<Postgres>,
+) -> Result<SpeechRules, OmniError> {
+    let mut tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+    let overrides =
+        RuleOverrides::apply_for_debate(&mut tournament, debate, pool).await?;
+    let mut format = DebateFormat::get_for_debate(debate, &tournament, pool).await?;
+    overrides.apply_to_format(&mut format);
+    Ok(tournament.get_speech_rules(format))
+}
+
+/// Forwards timer events until the client disconnects.
+/// Clients falling behind skip the events they missed;
+/// the next state brings them up to date.
+async fn push_timer_events(
+    mut socket: WebSocket,
+    timer: DebateTimerState,
+    mut events: broadcast::Receiver<DebateTimerEvent>,
+) {
+    if send_timer_event(&mut socket, &DebateTimerEvent::State(timer))
+        .await
+        .is_err()
+    {
+        return;
+    }
+    loop {
+        tokio::select! {
+            event = events.recv() => match event {
+                Ok(event) => {
+                    if send_timer_event(&mut socket, &event).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => return,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => continue,
+            },
+        }
+    }
+}
+
+async fn send_timer_event(
+    socket: &mut WebSocket,
+    event: &DebateTimerEvent,
+) -> Result<(), axum::Error> {
+    let message = match serde_json::to_string(event) {
+        Ok(message) => message,
+        Err(e) => {
+            error!("Error serializing a debate timer event: {e}");
+            return Ok(());
+        }
+    };
+    socket.send(Message::Text(message.into())).await
+}
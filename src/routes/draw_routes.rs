@@ -0,0 +1,222 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        draws::{
+            ensure_two_team_format,
+            knockout::{self, Seeding, SeedingOptions},
+            round_robin,
+            swiss::{self, SwissDraw, SwissDrawOptions},
+            Draw, DrawOptions,
+        },
+        phases::Phase,
+        rounds::Round,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/draw",
+            post(draw_round_robin),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{round_id}/draw",
+            post(draw_swiss),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/seed",
+            post(seed_bracket),
+        )
+}
+
+/// Draw all rounds of a group phase as round robins
+///
+/// Teams of the tournament are split into groups of at most group_size teams.
+/// Within each group every pair of teams meets at most once
+/// and sides are alternated as evenly as possible.
+/// Existing debates of the rounds are reused and their team assignments replaced.
+/// With dry_run set, the proposed draw is returned without being saved.
+/// Available only to Organizers and Admins.
+#[utoipa::path(post, request_body=DrawOptions,
+    path = "/tournaments/{tournament_id}/phases/{phase_id}/draw",
+    responses(
+        (status=200, description = "Draw generated successfully", body=Draw),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (
+            status=409,
+            description = "The phase cannot be drawn as a round robin",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="draws"
+)]
+async fn draw_round_robin(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id)): Path<(Uuid, Uuid)>,
+    Json(options): Json<DrawOptions>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    if phase.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    ensure_two_team_format(tournament_id, pool).await?;
+
+    match round_robin::draw_phase(&phase, &options, pool).await {
+        Ok(draw) => Ok(Json(draw).into_response()),
+        Err(e) => {
+            error!("Error drawing a phase with id {phase_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Draw a single round using Swiss power pairing
+///
+/// Teams are paired with teams of equal or nearby win counts,
+/// computed from verdicts of the preceding rounds. Byes count as wins.
+/// Rematches are avoided and sides are balanced whenever possible;
+/// every constraint that had to be broken is reported along with pull-ups.
+/// Odd team counts give a bye to the lowest ranked team that hasn't had one yet.
+/// The same seed always yields the same draw.
+/// With dry_run set, the proposed draw is returned without being saved.
+/// Available only to Organizers and Admins.
+#[utoipa::path(post, request_body=SwissDrawOptions,
+    path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{round_id}/draw",
+    responses(
+        (status=200, description = "Draw generated successfully", body=SwissDraw),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament, phase or round not found"),
+        (
+            status=409,
+            description = "The round cannot be drawn",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="draws"
+)]
+async fn draw_swiss(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id, round_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(options): Json<SwissDrawOptions>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    let round = Round::get_by_id(round_id, pool).await?;
+    if phase.tournament_id != tournament_id || round.phase_id != phase_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    ensure_two_team_format(tournament_id, pool).await?;
+
+    match swiss::draw_round(&round, &options, pool).await {
+        Ok(draw) => Ok(Json(draw).into_response()),
+        Err(e) => {
+            error!("Error drawing a round with id {round_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Seed the first round of a finals phase from group standings
+///
+/// Teams are ranked by debates won in the group phase, then by ballots,
+/// and the top advancing_teams are seeded as a standard bracket (1 v N, 2 v N-1...).
+/// The group phase must be finished. If advancing_teams is not given,
+/// it is taken from the tournament plan.
+/// With keep_groups_apart set, teams from the same group of a round-robin
+/// group phase are kept apart in the first round whenever possible.
+/// With dry_run set, the proposed bracket is returned without being saved.
+/// Available only to Organizers and Admins.
+#[utoipa::path(post, request_body=SeedingOptions,
+    path = "/tournaments/{tournament_id}/phases/{phase_id}/seed",
+    responses(
+        (status=200, description = "Bracket seeded successfully", body=Seeding),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (
+            status=409,
+            description = "The phase cannot be seeded",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="draws"
+)]
+async fn seed_bracket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id)): Path<(Uuid, Uuid)>,
+    Json(options): Json<SeedingOptions>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    if phase.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    ensure_two_team_format(tournament_id, pool).await?;
+
+    match knockout::seed_phase(&phase, &options, pool).await {
+        Ok(seeding) => Ok(Json(seeding).into_response()),
+        Err(e) => {
+            error!("Error seeding a phase with id {phase_id}: {e}");
+            Err(e)
+        }
+    }
+}
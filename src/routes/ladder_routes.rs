@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use axum::{
     extract::{Path, State},
     http::HeaderMap,
@@ -14,7 +16,16 @@ use uuid::Uuid;
 use crate::{
     omni_error::OmniError,
     setup::AppState,
-    tournaments::{debates::Debate, phases::Phase, rounds::Round, Tournament},
+    tournaments::{
+        bracket_links::BracketLink,
+        debate_judges::DebateJudgeAssignment,
+        debate_results::DebateResult,
+        debate_teams::DebateTeamAssignment,
+        debates::{Debate, DebateDetails},
+        phases::Phase,
+        rounds::Round,
+        Tournament,
+    },
     users::{permissions::Permission, TournamentUser},
 };
 
@@ -22,7 +33,17 @@ use crate::{
 struct TournamentLadderResponse {
     phases: Vec<Phase>,
     rounds: Vec<Round>,
-    debates: Vec<Debate>,
+    debates: Vec<LadderDebate>,
+    bracket_links: Vec<BracketLink>,
+}
+
+#[derive(Serialize, ToSchema)]
+/// A debate of the ladder along with its result
+struct LadderDebate {
+    #[serde(flatten)]
+    details: DebateDetails,
+    /// Result computed from verdicts of the debate's panel
+    result: Option<DebateResult>,
 }
 
 pub fn route() -> Router<AppState> {
@@ -30,7 +51,7 @@ pub fn route() -> Router<AppState> {
 }
 
 #[utoipa::path(get, path = "/tournaments/{tournament_id}/ladder",
-    description = "Returns the tournament ladder including all phases, rounds, and debates for the specified tournament. Requires authentication and tournament permissions ReadPhases, ReadRounds, and ReadDebates.",
+    description = "Returns the tournament ladder including all phases, rounds, debates (with their teams, panels and results) and links between finals debates for the specified tournament. Motions are hidden until released, unless the user is permitted to modify motions. Requires authentication and tournament permissions ReadPhases, ReadRounds, and ReadDebates.",
     responses(
         (status=200, description = "Tournament ladder returned successfully", body=TournamentLadderResponse),
         (status=400, description = "Bad request"),
@@ -66,14 +87,35 @@ async fn get_ladder(
         .await?;
 
     let phases = Phase::get_all(tournament_id, &mut *transaction).await?;
-    let rounds = Round::get_all(tournament_id, &mut *transaction).await?;
-    let debates = Debate::get_all(tournament_id, &mut *transaction).await?;
+    let mut rounds = Round::get_all(tournament_id, &mut *transaction).await?;
+    let mut debates = Debate::get_all(tournament_id, &mut *transaction).await?;
+    let teams = DebateTeamAssignment::get_all(tournament_id, &mut *transaction).await?;
+    let judges = DebateJudgeAssignment::get_all(tournament_id, &mut *transaction).await?;
+    let bracket_links = BracketLink::get_all(tournament_id, &mut *transaction).await?;
+    let mut results: HashMap<Uuid, DebateResult> =
+        DebateResult::get_all(tournament_id, &mut transaction)
+            .await?
+            .into_iter()
+            .map(|result| (result.debate_id, result))
+            .collect();
     transaction.commit().await?;
+    Debate::hide_unreleased_motions(&mut debates, &rounds, &tournament_user);
+    for round in &mut rounds {
+        round.hide_unreleased_motion(&tournament_user);
+    }
+    let debates = DebateDetails::collect(debates, teams, judges)
+        .into_iter()
+        .map(|details| LadderDebate {
+            result: results.remove(&details.debate.id),
+            details,
+        })
+        .collect();
 
     Ok(Json(TournamentLadderResponse {
         phases,
         rounds,
         debates,
+        bracket_links,
     })
     .into_response())
 }
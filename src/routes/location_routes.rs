@@ -2,7 +2,7 @@ use axum::{
     extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, put},
     Json, Router,
 };
 use sqlx::query_as;
@@ -14,6 +14,7 @@ use crate::{
     omni_error::OmniError,
     setup::AppState,
     tournaments::{
+        location_travel_times::TravelTime,
         locations::{Location, LocationPatch},
         Tournament,
     },
@@ -34,6 +35,14 @@ pub fn route() -> Router<AppState> {
                 .patch(patch_location_by_id)
                 .delete(delete_location_by_id),
         )
+        .route(
+            "/tournaments/{tournament_id}/locations/{id}/travel_times",
+            get(get_travel_times),
+        )
+        .route(
+            "/tournaments/{tournament_id}/locations/{id}/travel_times/{other_id}",
+            put(put_travel_time),
+        )
 }
 
 /// Create a new location
@@ -294,6 +303,121 @@ async fn delete_location_by_id(
     }
 }
 
+/// Get travel times from a location
+///
+/// Lists travel times to other locations of the tournament.
+/// Locations left out of the list can be reached instantly.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/locations/{id}/travel_times",
+    responses(
+        (
+            status=200, description = "Ok", body=Vec<TravelTime>,
+            example=json!(get_travel_times_list_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read locations within this tournament"
+        ),
+        (status=404, description = "Tournament or location not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="locations"
+)]
+async fn get_travel_times(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadLocations) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let location = Location::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    match TravelTime::get_all_from(location.id, pool).await {
+        Ok(travel_times) => Ok(Json(travel_times).into_response()),
+        Err(e) => {
+            error!("Error getting travel times from a location with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Set the travel time between two locations
+///
+/// The travel time applies in both directions.
+/// When rounds run back to back, the room allocator does not send teams and Judges
+/// to a location they cannot reach from their previous debate in time.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put,
+    path = "/tournaments/{tournament_id}/locations/{id}/travel_times/{other_id}",
+    request_body=TravelTime,
+    responses(
+        (
+            status=200, description = "Travel time set successfully", body=TravelTime,
+            example=json!(get_travel_time_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify locations within this tournament"
+        ),
+        (status=404, description = "Tournament or location not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="locations"
+)]
+async fn put_travel_time(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id, other_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(mut json): Json<TravelTime>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteLocations) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let location = Location::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    let other_location =
+        Location::get_by_id_in_tournament(other_id, tournament_id, pool).await?;
+    if location.id == other_location.id {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: "Travel time can only be set between two different locations"
+                .to_owned(),
+        });
+    }
+    if json.minutes < 0 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: "Travel time cannot be negative".to_owned(),
+        });
+    }
+
+    json.location_id = other_location.id;
+    match json.put(location.id, pool).await {
+        Ok(travel_time) => Ok(Json(travel_time).into_response()),
+        Err(e) => {
+            error!("Error setting a travel time from a location with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
 fn get_location_example() -> String {
     r#"
     {
@@ -325,3 +449,29 @@ fn get_locations_list_example() -> String {
     "#
     .to_owned()
 }
+
+fn get_travel_time_example() -> String {
+    r#"
+    {
+        "location_id": "01968b3c-1f2e-7d4a-9b1c-2e3f4a5b6c7d",
+        "minutes": 25
+    }
+    "#
+    .to_owned()
+}
+
+fn get_travel_times_list_example() -> String {
+    r#"
+    [
+        {
+            "location_id": "01968b3c-1f2e-7d4a-9b1c-2e3f4a5b6c7d",
+            "minutes": 25
+        },
+        {
+            "location_id": "01968b3c-4a5b-7c6d-8e9f-0a1b2c3d4e5f",
+            "minutes": 40
+        }
+    ]
+    "#
+    .to_owned()
+}
@@ -2,14 +2,21 @@
 
 use crate::setup::AppState;
 
+mod accessibility_requirements_routes;
 mod affiliation_routes;
 mod attendee_routes;
 mod auth;
+mod debate_format_routes;
+mod debate_judge_routes;
 mod debate_routes;
+mod debate_team_routes;
+mod debate_timer_routes;
+mod draw_routes;
 mod health_check;
 mod infradmin_routes;
 mod ladder_routes;
 mod location_routes;
+mod motion_bank_routes;
 mod motion_routes;
 mod permissions_routes;
 mod phase_routes;
@@ -17,7 +24,11 @@ mod plans_routes;
 mod roles_routes;
 mod room_routes;
 mod round_routes;
+mod rule_overrides_routes;
+mod schedule_routes;
+mod side_draw_routes;
 mod swagger;
+mod tab_routes;
 mod team_routes;
 mod teapot;
 mod tournament_routes;
@@ -36,9 +47,18 @@ pub fn routes() -> Router<AppState> {
         .merge(auth::route())
         .merge(tournament_routes::route())
         .merge(team_routes::route())
+        .merge(accessibility_requirements_routes::route())
         .merge(attendee_routes::route())
         .merge(motion_routes::route())
+        .merge(motion_bank_routes::route())
         .merge(debate_routes::route())
+        .merge(debate_team_routes::route())
+        .merge(debate_format_routes::route())
+        .merge(debate_timer_routes::route())
+        .merge(side_draw_routes::route())
+        .merge(rule_overrides_routes::route())
+        .merge(debate_judge_routes::route())
+        .merge(draw_routes::route())
         .merge(location_routes::route())
         .merge(room_routes::route())
         .merge(user_routes::route())
@@ -46,7 +66,9 @@ pub fn routes() -> Router<AppState> {
         .merge(affiliation_routes::route())
         .merge(phase_routes::route())
         .merge(round_routes::route())
+        .merge(schedule_routes::route())
         .merge(plans_routes::route())
         .merge(permissions_routes::route())
         .merge(verdicts_routes::route())
+        .merge(tab_routes::route())
 }
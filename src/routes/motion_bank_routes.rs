@@ -0,0 +1,354 @@
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        motion_bank::{BankMotion, BankMotionPatch, MotionBankQuery},
+        motions::Motion,
+        roles::Role,
+        Tournament,
+    },
+    users::{permissions::Permission, TournamentUser, User},
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+const DUPLICATE_BANK_MOTION_ERROR: &str =
+    "Motion with such content already exists in the motion bank";
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/motion_bank",
+            get(get_bank_motions).post(create_bank_motion),
+        )
+        .route(
+            "/motion_bank/{id}",
+            get(get_bank_motion_by_id)
+                .delete(delete_bank_motion_by_id)
+                .patch(patch_bank_motion_by_id),
+        )
+        .route(
+            "/tournaments/{tournament_id}/motions/from_bank/{id}",
+            post(copy_bank_motion_to_tournament),
+        )
+}
+
+/// The motion bank is shared by all tournaments,
+/// so it is available to the infrastructure admin and Organizers of any tournament.
+async fn authenticate_bank_user(
+    headers: &HeaderMap,
+    cookies: Cookies,
+    pool: &Pool<Postgres>,
+) -> Result<User, OmniError> {
+    let user = User::authenticate(headers, cookies, pool).await?;
+    match user.is_infrastructure_admin()
+        || user.is_organizer_of_any_tournament(pool).await?
+    {
+        true => Ok(user),
+        false => Err(OmniError::InsufficientPermissionsError),
+    }
+}
+
+/// Bank motions are curated by the tournaments they were set at,
+/// so only the infrastructure admin and Organizers of the source tournament
+/// can attribute motions to it, change or remove them.
+/// Motions with no source tournament are left to the infrastructure admin.
+async fn authorize_bank_motion_curator(
+    user: &User,
+    source_tournament_id: Option<Uuid>,
+    pool: &Pool<Postgres>,
+) -> Result<(), OmniError> {
+    if user.is_infrastructure_admin() {
+        return Ok(());
+    }
+    match source_tournament_id {
+        Some(tournament_id)
+            if user.has_role(Role::Organizer, tournament_id, pool).await? =>
+        {
+            Ok(())
+        }
+        _ => Err(OmniError::InsufficientPermissionsError),
+    }
+}
+
+fn duplicate_bank_motion_error() -> OmniError {
+    OmniError::ExplicitError {
+        status: StatusCode::CONFLICT,
+        message: DUPLICATE_BANK_MOTION_ERROR.to_owned(),
+    }
+}
+
+#[utoipa::path(get, path = "/motion_bank",
+    params(MotionBankQuery),
+    responses(
+        (
+            status=200, description = "Ok",
+            body=Vec<BankMotion>,
+            example=json!(get_bank_motions_list_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "The user is not permitted to access the motion bank"),
+    ),
+    tag="motion_bank"
+)]
+/// Search the motion bank
+///
+/// Returns bank motions matching all of the provided filters.
+/// When a search query is given, the most relevant motions come first.
+/// Available to the infrastructure admin and tournament Organizers.
+async fn get_bank_motions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Query(filters): Query<MotionBankQuery>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    authenticate_bank_user(&headers, cookies, pool).await?;
+
+    match BankMotion::search(filters, pool).await {
+        Ok(motions) => Ok(Json(motions).into_response()),
+        Err(e) => {
+            error!("Error searching the motion bank: {e}");
+            Err(e)?
+        }
+    }
+}
+
+/// Add a motion to the motion bank
+///
+/// Available to the infrastructure admin and tournament Organizers.
+/// Only Organizers of the source tournament can attribute the motion to it.
+#[utoipa::path(
+    post,
+    request_body=BankMotion,
+    path = "/motion_bank",
+    responses(
+        (
+            status=200, description = "Motion added successfully",
+            body=BankMotion,
+            example=json!(get_bank_motion_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "The user is not permitted to access the motion bank"),
+        (status=404, description = "Source tournament not found"),
+        (status=409, description = DUPLICATE_BANK_MOTION_ERROR)
+    ),
+    tag="motion_bank"
+)]
+async fn create_bank_motion(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Json(json): Json<BankMotion>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let user = authenticate_bank_user(&headers, cookies, pool).await?;
+    if json.source_tournament_id.is_some() {
+        authorize_bank_motion_curator(&user, json.source_tournament_id, pool).await?;
+    }
+
+    match BankMotion::post(json, pool).await {
+        Ok(motion) => Ok(Json(motion).into_response()),
+        Err(e) if e.is_sqlx_unique_violation() => Err(duplicate_bank_motion_error()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Get details of a bank motion
+///
+/// Available to the infrastructure admin and tournament Organizers.
+#[utoipa::path(get, path = "/motion_bank/{id}",
+    responses(
+        (
+            status=200, description = "Ok", body=BankMotion,
+            example=json!(get_bank_motion_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "The user is not permitted to access the motion bank"),
+        (status=404, description = "Motion not found")
+    ),
+    tag="motion_bank"
+)]
+async fn get_bank_motion_by_id(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    authenticate_bank_user(&headers, cookies, pool).await?;
+
+    match BankMotion::get_by_id(id, pool).await {
+        Ok(motion) => Ok(Json(motion).into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Patch a bank motion
+///
+/// Available to the infrastructure admin and Organizers of the source tournament
+/// of the motion.
+#[utoipa::path(patch, path = "/motion_bank/{id}",
+    request_body=BankMotionPatch,
+    responses(
+        (
+            status=200, description = "Motion patched successfully",
+            body=BankMotion,
+            example=json!(get_bank_motion_example())
+        ),
+        (status=400, description = "Bad request"),
+        (status=401, description = "The user is not permitted to change this motion"),
+        (status=404, description = "Motion or source tournament not found"),
+        (status=409, description = DUPLICATE_BANK_MOTION_ERROR)
+    ),
+    tag="motion_bank"
+)]
+async fn patch_bank_motion_by_id(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Json(patch): Json<BankMotionPatch>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let user = authenticate_bank_user(&headers, cookies, pool).await?;
+
+    let existing_motion = BankMotion::get_by_id(id, pool).await?;
+    authorize_bank_motion_curator(&user, existing_motion.source_tournament_id, pool)
+        .await?;
+    if patch.source_tournament_id.is_some() {
+        authorize_bank_motion_curator(&user, patch.source_tournament_id, pool).await?;
+    }
+    match existing_motion.patch(patch, pool).await {
+        Ok(motion) => Ok(Json(motion).into_response()),
+        Err(e) if e.is_sqlx_unique_violation() => Err(duplicate_bank_motion_error()),
+        Err(e) => {
+            error!("Failed to patch bank motion {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Delete a bank motion
+///
+/// Motions previously copied into tournaments are not affected.
+/// Available to the infrastructure admin and Organizers of the source tournament
+/// of the motion.
+#[utoipa::path(delete, path = "/motion_bank/{id}",
+    responses(
+        (status=204, description = "Motion deleted successfully"),
+        (status=400, description = "Bad request"),
+        (status=401, description = "The user is not permitted to change this motion"),
+        (status=404, description = "Motion not found")
+    ),
+    tag="motion_bank"
+)]
+async fn delete_bank_motion_by_id(
+    Path(id): Path<Uuid>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let user = authenticate_bank_user(&headers, cookies, pool).await?;
+
+    let motion = BankMotion::get_by_id(id, pool).await?;
+    authorize_bank_motion_curator(&user, motion.source_tournament_id, pool).await?;
+    match motion.delete(pool).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+/// Copy a bank motion into a tournament
+///
+/// Creates a new tournament motion with the content and infoslide of the bank motion.
+/// The copy can then be adapted like any other motion of the tournament.
+/// Available only to the tournament Organizers.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/motions/from_bank/{id}",
+    responses(
+        (
+            status=200, description = "Motion copied successfully",
+            body=Motion,
+        ),
+        (status=400, description = "Bad request"),
+        (
+            status=401,
+            description = "The user is not permitted to modify motions within this tournament"
+        ),
+        (status=404, description = "Tournament or bank motion not found"),
+        (
+            status=409,
+            description = "Motion with such content already exists within this tournament"
+        )
+    ),
+    tag="motion_bank"
+)]
+async fn copy_bank_motion_to_tournament(
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteMotions) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let _tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let bank_motion = BankMotion::get_by_id(id, pool).await?;
+    match bank_motion.copy_to_tournament(tournament_id, pool).await {
+        Ok(motion) => Ok(Json(motion).into_response()),
+        Err(e) => Err(e)?,
+    }
+}
+
+fn get_bank_motion_example() -> String {
+    r#"
+    {
+    "id": "01941266-8dda-7e88-82ab-38180d9d8e27",
+    "motion": "This House Would abolish the UN Security Council.",
+    "tags": ["international relations"],
+    "language": "en",
+    "difficulty": "Medium",
+    "source_tournament_id": "01941267-0109-7405-b30e-7883d309c603"
+    }
+    "#
+    .to_owned()
+}
+
+fn get_bank_motions_list_example() -> String {
+    r#"
+    [
+        {
+        "id": "01941266-8dda-7e88-82ab-38180d9d8e27",
+        "motion": "This House Would abolish the UN Security Council.",
+        "tags": ["international relations"],
+        "language": "en",
+        "difficulty": "Medium",
+        "source_tournament_id": "01941267-0109-7405-b30e-7883d309c603"
+        },
+        {
+        "id": "01941266-725b-7d8d-be4e-4f71bb0d0e1c",
+        "motion": "This House would introduce a universal basic income.",
+        "tags": ["economics", "welfare"],
+        "language": "en",
+        "difficulty": "Easy"
+        }
+    ]
+    "#
+    .to_owned()
+}
@@ -1,7 +1,11 @@
 use crate::{
     omni_error::OmniError,
     setup::AppState,
-    tournaments::motions::{Motion, MotionPatch},
+    tournaments::{
+        motion_balance::MotionBalanceReport,
+        motions::{Motion, MotionPatch},
+        Tournament,
+    },
     users::{permissions::Permission, TournamentUser},
 };
 use axum::{
@@ -15,7 +19,8 @@ use tower_cookies::Cookies;
 use tracing::error;
 use uuid::Uuid;
 
-const DUPLICATE_MOTION_ERROR: &str = "Motion with such content already exists";
+const DUPLICATE_MOTION_ERROR: &str =
+    "Motion with such content already exists within this tournament";
 
 pub fn route() -> Router<AppState> {
     Router::new()
@@ -29,6 +34,10 @@ pub fn route() -> Router<AppState> {
                 .delete(delete_motion_by_id)
                 .patch(patch_motion_by_id),
         )
+        .route(
+            "/tournaments/{tournament_id}/motion_balance",
+            get(get_motion_balance),
+        )
 }
 
 #[utoipa::path(get, path = "/tournaments/{tournament_id}/motions",
@@ -39,8 +48,9 @@ pub fn route() -> Router<AppState> {
     )),
     tag="motions"
 )]
-/// Get a list of all motions
+/// Get a list of all motions within a tournament
 ///
+/// Users not permitted to modify motions only see motions already released in a round.
 /// The user must be given a role within this tournament to use this endpoint.
 async fn get_motions(
     State(state): State<AppState>,
@@ -57,7 +67,11 @@ async fn get_motions(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    match Motion::get_all(pool).await {
+    let motions = match tournament_user.has_permission(Permission::WriteMotions) {
+        true => Motion::get_all(tournament_id, pool).await,
+        false => Motion::get_released(tournament_id, pool).await,
+    };
+    match motions {
         Ok(motions) => Ok(Json(motions).into_response()),
         Err(e) => {
             error!("Error getting a list of motions: {e}");
@@ -106,7 +120,8 @@ async fn create_motion(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    match Motion::post(json, &state.connection_pool).await {
+    let _tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    match Motion::post(tournament_id, json, &state.connection_pool).await {
         Ok(motion) => Ok(Json(motion).into_response()),
         Err(e) => Err(e)?,
     }
@@ -114,6 +129,7 @@ async fn create_motion(
 
 /// Get details of an existing motion
 ///
+/// Users not permitted to modify motions can only see motions already released in a round.
 /// The user must be given a role within this tournament to use this endpoint.
 #[utoipa::path(get, path = "/tournaments/{tournament_id}/motions/{id}",
     responses((status=200, description = "Ok", body=Motion,
@@ -136,10 +152,16 @@ async fn get_motion_by_id(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
-    match Motion::get_by_id(id, &state.connection_pool).await {
-        Ok(motion) => Ok(Json(motion).into_response()),
-        Err(e) => Err(e)?,
+    let motion = Motion::get_by_id(id, &state.connection_pool).await?;
+    if motion.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    if !tournament_user.has_permission(Permission::WriteMotions)
+        && !motion.is_released(pool).await?
+    {
+        return Err(OmniError::ResourceNotFoundError);
     }
+    Ok(Json(motion).into_response())
 }
 
 /// Patch an existing motion
@@ -159,7 +181,8 @@ async fn get_motion_by_id(
             status=403,
             description = "The user is not permitted to modify motions within this tournament"
         ),
-        (status=404, description = "Tournament or motion not found")
+        (status=404, description = "Tournament or motion not found"),
+        (status=409, description = DUPLICATE_MOTION_ERROR)
     ),
     tag="motions"
 )]
@@ -180,6 +203,9 @@ async fn patch_motion_by_id(
     }
 
     let existing_motion = Motion::get_by_id(id, pool).await?;
+    if existing_motion.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
     match existing_motion
         .patch(new_motion, &state.connection_pool)
         .await
@@ -226,17 +252,68 @@ async fn delete_motion_by_id(
     }
 
     let motion = Motion::get_by_id(id, pool).await?;
+    if motion.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
     match motion.delete(pool).await {
         Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
         Err(e) => Err(e)?,
     }
 }
 
+/// Get side balance statistics of motions used within a tournament
+///
+/// For every motion used in a debate, directly or through the debate's round,
+/// reports how often each side won: overall, in every phase, and separately
+/// for unanimous and split decisions. The side bias summarizes all debates of the tournament.
+/// Debates whose panels haven't submitted all verdicts yet are not counted.
+/// Users not permitted to modify motions only see motions already released in a round.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/motion_balance",
+    responses(
+        (status=200, description = "Ok", body=MotionBalanceReport),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read motions within this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="motions"
+)]
+async fn get_motion_balance(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadMotions) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let include_unreleased = tournament_user.has_permission(Permission::WriteMotions);
+    match MotionBalanceReport::get(tournament_id, include_unreleased, pool).await {
+        Ok(report) => Ok(Json(report).into_response()),
+        Err(e) => {
+            error!("Error getting motion balance of tournament {tournament_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
 fn get_motion_example() -> String {
     r#"
     {
     "id": "01941266-8dda-7e88-82ab-38180d9d8e27",
-    "motion": "This House Would abolish the UN Security Council."
+    "motion": "This House Would abolish the UN Security Council.",
+    "tournament_id": "01941267-0109-7405-b30e-7883d309c603"
     }
     "#
     .to_owned()
@@ -247,12 +324,14 @@ fn get_motions_list_example() -> String {
     [
         {
         "id": "01941266-8dda-7e88-82ab-38180d9d8e27",
-        "motion": "This House Would abolish the UN Security Council."
+        "motion": "This House Would abolish the UN Security Council.",
+        "tournament_id": "01941267-0109-7405-b30e-7883d309c603"
         },
         {
         "id": "01941266-725b-7d8d-be4e-4f71bb0d0e1c",
         "motion": "As a society of a newly established state, we would opt for a representative democracy system.",
-        "adinfo": "In the middle of the Baltic Sea, an island with a population has appeared. The new state of 'Balticstan' is seeking the best political system to govern itself. The country has guaranteed independence and is sovereign over regional powers at the time of the debate. Balticstan represents the maximum average of all countries bordering the Baltic Sea (nine countries in total) regarding population, economy, problems and opportunities."
+        "adinfo": "In the middle of the Baltic Sea, an island with a population has appeared. The new state of 'Balticstan' is seeking the best political system to govern itself. The country has guaranteed independence and is sovereign over regional powers at the time of the debate. Balticstan represents the maximum average of all countries bordering the Baltic Sea (nine countries in total) regarding population, economy, problems and opportunities.",
+        "tournament_id": "01941267-0109-7405-b30e-7883d309c603"
         }
     ]
     "#.to_owned()
@@ -37,7 +37,7 @@ pub fn route() -> Router<AppState> {
     params(
         ("id" = Uuid, Path, description = "User ID"),
         ("tournament_id" = Uuid, Path, description = "Tournament ID"),
-        ("permission_name" = String, Query, description = "The permission to check. Must be exactly one of: ReadAttendees, WriteAttendees, ReadDebates, WriteDebates, ReadTeams, WriteTeams, ReadMotions, WriteMotions, ReadTournament, WriteTournament, CreateUsersManually, CreateUsersWithLink, DeleteUsers, ModifyUserRoles, SubmitOwnVerdictVote, SubmitVerdict, WriteRoles, ReadLocations, WriteLocations, ReadRooms, ModifyAllRoomDetails, ChangeRoomOccupationStatus, ReadAffiliations, WriteAffiliations, ReadPhases, WritePhases, ReadRounds, WriteRounds"),
+        ("permission_name" = String, Query, description = "The permission to check. Must be exactly one of: ReadAttendees, WriteAttendees, ReadDebates, WriteDebates, ReadTeams, WriteTeams, ReadMotions, WriteMotions, ReadTournament, WriteTournament, CreateUsersManually, CreateUsersWithLink, DeleteUsers, ModifyUserRoles, SubmitOwnVerdictVote, SubmitVerdict, OperateDebateTimers, WriteRoles, ReadLocations, WriteLocations, ReadRooms, ModifyAllRoomDetails, ChangeRoomOccupationStatus, ReadAffiliations, WriteAffiliations, ReadPhases, WritePhases, ReadRounds, WriteRounds"),
     ),
     responses(
         (status=200, description = "Permission check result", body=bool, example=json!(true)),
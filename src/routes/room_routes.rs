@@ -2,9 +2,10 @@ use axum::{
     extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{delete, get},
     Json, Router,
 };
+use sqlx::{Pool, Postgres};
 use tower_cookies::Cookies;
 use tracing::error;
 use uuid::Uuid;
@@ -14,6 +15,8 @@ use crate::{
     setup::AppState,
     tournaments::{
         locations::Location,
+        room_bookings::{RoomBooking, RoomOccupancy},
+        room_closures::RoomClosure,
         rooms::{Room, RoomPatch},
         Tournament,
     },
@@ -34,6 +37,18 @@ pub fn route() -> Router<AppState> {
                 .patch(patch_room_by_id)
                 .delete(delete_room_by_id),
         )
+        .route(
+            "/tournaments/{tournament_id}/locations/{location_id}/rooms/{id}/closures",
+            get(get_room_closures).post(create_room_closure),
+        )
+        .route(
+            "/tournaments/{tournament_id}/locations/{location_id}/rooms/{room_id}/closures/{id}",
+            delete(delete_room_closure),
+        )
+        .route(
+            "/tournaments/{tournament_id}/locations/{location_id}/occupancy",
+            get(get_room_occupancy),
+        )
 }
 
 /// Create a new room
@@ -293,13 +308,221 @@ async fn delete_room_by_id(
     }
 }
 
+/// Get a list of rooms within a location along with their current and next debate
+///
+/// A room is occupied while a debate held in it is being prepared,
+/// debated or ruled on, or while the room is closed.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/locations/{location_id}/occupancy",
+    responses
+    (
+        (status=200, description = "Ok", body=Vec<RoomOccupancy>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read rooms within this tournament"
+        ),
+        (status=404, description = "Tournament or location not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rooms"
+)]
+async fn get_room_occupancy(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, location_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadRooms) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let location = Location::get_by_id(location_id, pool).await?;
+    if location.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    match RoomBooking::get_occupancy(&location, pool).await {
+        Ok(occupancy) => Ok(Json(occupancy).into_response()),
+        Err(e) => {
+            error!("Error getting occupancy of location {location_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Get a list of closures of a room
+///
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/locations/{location_id}/rooms/{id}/closures",
+    responses
+    (
+        (status=200, description = "Ok", body=Vec<RoomClosure>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read rooms within this tournament"
+        ),
+        (status=404, description = "Tournament or room not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rooms"
+)]
+async fn get_room_closures(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, location_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadRooms) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let room = get_room_of_tournament(tournament_id, location_id, id, pool).await?;
+    match RoomClosure::get_all_for_room(room.id, pool).await {
+        Ok(closures) => Ok(Json(closures).into_response()),
+        Err(e) => {
+            error!("Error getting closures of a room with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Close a room for a period of time
+///
+/// Use it to take a room out of use, e.g. for maintenance.
+/// A closed room is occupied, and no debate can be held in it.
+/// Debates booked in the room during the closure must be moved to other rooms first.
+/// Available only to the tournament Organizers.
+#[utoipa::path(post, request_body=RoomClosure,
+    path = "/tournaments/{tournament_id}/locations/{location_id}/rooms/{id}/closures",
+    responses
+    (
+        (status=200, description = "Room closed successfully", body=RoomClosure),
+        (status=400, description = "The closure ends before it starts"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to change occupation of rooms within this tournament"
+        ),
+        (status=404, description = "Tournament or room not found"),
+        (status=409, description = "The room is booked for a debate during this closure"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rooms"
+)]
+async fn create_room_closure(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, location_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(mut json): Json<RoomClosure>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ChangeRoomOccupationStatus) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let room = get_room_of_tournament(tournament_id, location_id, id, pool).await?;
+    json.room_id = room.id;
+    match RoomClosure::post(json, pool).await {
+        Ok(closure) => Ok(Json(closure).into_response()),
+        Err(e) => {
+            error!("Error closing a room with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Delete a closure of a room
+///
+/// Available only to the tournament Organizers.
+#[utoipa::path(delete,
+    path = "/tournaments/{tournament_id}/locations/{location_id}/rooms/{room_id}/closures/{id}",
+    responses
+    (
+        (status=204, description = "Closure deleted successfully"),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to change occupation of rooms within this tournament"
+        ),
+        (status=404, description = "Tournament, room or closure not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rooms"
+)]
+async fn delete_room_closure(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, location_id, room_id, id)): Path<(Uuid, Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ChangeRoomOccupationStatus) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let room = get_room_of_tournament(tournament_id, location_id, room_id, pool).await?;
+    let closure = RoomClosure::get_by_id(id, pool).await?;
+    if closure.room_id != room.id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    match closure.delete(pool).await {
+        Ok(_) => Ok(StatusCode::NO_CONTENT.into_response()),
+        Err(e) => {
+            error!("Error deleting a closure with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn get_room_of_tournament(
+    tournament_id: Uuid,
+    location_id: Uuid,
+    room_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<Room, OmniError> {
+    let location = Location::get_by_id(location_id, pool).await?;
+    let room = Room::get_by_id(room_id, pool).await?;
+    match location.tournament_id == tournament_id && room.location_id == location.id {
+        true => Ok(room),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
+
 fn get_room_example() -> String {
     r#"
     {
+        "building": "Main Hall",
+        "capacity": 12,
+        "floor": 3,
+        "has_projector": true,
         "is_occupied": true,
+        "is_online_capable": false,
+        "is_step_free": true,
         "location_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
-        "name": "Room 32",
-        "remarks": "Third floor"
+        "name": "Room 32"
     }
     "#
     .to_owned()
@@ -309,16 +532,26 @@ fn get_rooms_list_example() -> String {
     r#"
     [
         {
+            "building": "Main Hall",
+            "capacity": 12,
+            "floor": 3,
+            "has_projector": true,
             "is_occupied": true,
+            "is_online_capable": false,
+            "is_step_free": true,
             "location_id": "3fa85f64-5717-4562-b3fc-2c963f66afa6",
-            "name": "Room 32",
-            "remarks": "Third floor"
+            "name": "Room 32"
         },
         {
-            "is_occupied": true,
+            "building": "Main Hall",
+            "capacity": 20,
+            "floor": 4,
+            "has_projector": false,
+            "is_occupied": false,
+            "is_online_capable": true,
+            "is_step_free": false,
             "location_id": "77abaf34-5782-4562-b3fc-93963f66afa6",
-            "name": "Room 44",
-            "remarks": "Fourth floor"
+            "name": "Room 44"
         }
     ]
     "#
@@ -2,9 +2,10 @@ use axum::{
     extract::{Path, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
+use chrono::Utc;
 use tower_cookies::Cookies;
 use tracing::error;
 use uuid::Uuid;
@@ -13,8 +14,13 @@ use crate::{
     omni_error::OmniError,
     setup::AppState,
     tournaments::{
+        debate_judges::DebateJudgeAssignment,
+        debates::Debate,
+        motions::Motion,
         phases::Phase,
+        room_bookings::RoomBooking,
         rounds::{Round, RoundPatch},
+        rule_overrides::RuleOverrides,
         Tournament,
     },
     users::{permissions::Permission, TournamentUser},
@@ -34,10 +40,24 @@ pub fn route() -> Router<AppState> {
                 .patch(patch_round_by_id)
                 .delete(delete_round_by_id),
         )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/release_motion",
+            post(release_motion),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/allocate_rooms",
+            post(allocate_rooms),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/allocate_judges",
+            post(allocate_judges),
+        )
 }
 
 /// Create a new round
 ///
+/// If the motion release time is given without a planned start time,
+/// the round is planned to start once the preparation time after the release has passed.
 /// Requires the WriteRounds permission.
 /// Available only to tournament Organizers and the infrastructure admin.
 ///
@@ -57,6 +77,7 @@ pub fn route() -> Router<AppState> {
             description = "The user is not permitted to modify rounds within this tournament"
         ),
         (status=404, description = "Tournament or round not found"),
+        (status=409, description = "The motion is not from this tournament"),
         (status=500, description = "Internal server error"),
     ),
     tag="rounds"
@@ -66,7 +87,7 @@ async fn create_round(
     headers: HeaderMap,
     cookies: Cookies,
     Path((tournament_id, _phase_id)): Path<(Uuid, Uuid)>,
-    Json(json): Json<Round>,
+    Json(mut json): Json<Round>,
 ) -> Result<Response, OmniError> {
     let pool = &state.connection_pool;
     let tournament_user =
@@ -77,9 +98,12 @@ async fn create_round(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
 
+    let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    if json.planned_start_time.is_none() {
+        json.start_preparation_window(tournament.get_debate_preparation_time());
+    }
     json.validate(pool).await?;
 
-    let _tournament = Tournament::get_by_id(tournament_id, pool).await?;
     match Round::post(json, pool).await {
         Ok(round) => Ok(Json(round).into_response()),
         Err(e) => {
@@ -110,6 +134,7 @@ async fn create_round(
 )]
 /// Get a list of all rounds
 ///
+/// Motions are hidden until released, unless the user is permitted to modify motions.
 /// The user must be given a role within this tournament to use this endpoint.
 async fn get_rounds(
     State(state): State<AppState>,
@@ -128,15 +153,21 @@ async fn get_rounds(
 
     let phase = Phase::get_by_id(phase_id, pool).await?;
     match phase.get_rounds(pool).await {
-        Ok(rounds) => Ok(Json(rounds).into_response()),
+        Ok(mut rounds) => {
+            for round in &mut rounds {
+                round.hide_unreleased_motion(&tournament_user);
+            }
+            Ok(Json(rounds).into_response())
+        }
         Err(e) => Err(e)?,
     }
 }
 
 /// Get details of an existing round
 ///
+/// The motion is hidden until released, unless the user is permitted to modify motions.
 /// The user must be given a role within this tournament to use this endpoint.
-#[utoipa::path(get, path = "/tournaments/{tournament_id}/rounds/{id}",
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}",
     responses(
         (
             status=200, description = "Ok", body=Round,
@@ -157,11 +188,11 @@ async fn get_round_by_id(
     State(state): State<AppState>,
     headers: HeaderMap,
     cookies: Cookies,
-    Path((_tournament_id, id)): Path<(Uuid, Uuid)>,
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
 ) -> Result<Response, OmniError> {
     let pool = &state.connection_pool;
     let tournament_user =
-        TournamentUser::authenticate(id, &headers, cookies, pool).await?;
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
 
     match tournament_user.has_permission(Permission::ReadRounds) {
         true => (),
@@ -169,7 +200,11 @@ async fn get_round_by_id(
     }
 
     match Round::get_by_id(id, pool).await {
-        Ok(round) => Ok(Json(round).into_response()),
+        Ok(round) if round.phase_id != phase_id => Err(OmniError::ResourceNotFoundError),
+        Ok(mut round) => {
+            round.hide_unreleased_motion(&tournament_user);
+            Ok(Json(round).into_response())
+        }
         Err(e) => {
             error!("Error getting a round with id {id}: {e}");
             Err(e)?
@@ -180,6 +215,10 @@ async fn get_round_by_id(
 /// Patch an existing round
 ///
 /// Patches any debates assigned to this round, if applicable.
+/// If the motion release time is changed without a new planned start time,
+/// the round is moved to start once the preparation time after the release has passed.
+/// A round can only be finished once all of its debates are concluded or cancelled,
+/// and it cannot be moved to a time at which the rooms of its debates are booked.
 /// Requires the WriteRounds permission.
 /// Available only to tournament Organizers and the infrastructure admin.
 #[utoipa::path(patch, path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}",
@@ -226,7 +265,16 @@ async fn patch_round_by_id(
         return Err(OmniError::BadRequestError);
     }
 
-    let new_round = patch.create_round_with(round.clone());
+    let moves_start_time =
+        patch.motion_release_time.is_some() && patch.planned_start_time.is_none();
+    let mut new_round = patch.create_round_with(round.clone());
+    if moves_start_time {
+        let mut tournament = Tournament::get_by_id(tournament_id, pool).await?;
+        RuleOverrides::apply_for_round(&mut tournament, &new_round, pool).await?;
+        new_round.start_preparation_window(tournament.get_debate_preparation_time());
+    }
+    Motion::validate_assignment(new_round.motion_id, tournament_id, pool).await?;
+    new_round.validate_status(pool).await?;
 
     match round.patch(new_round, pool).await {
         Ok(patched_round) => {
@@ -234,8 +282,84 @@ async fn patch_round_by_id(
             Ok(Json(patched_round).into_response())
         }
         Err(e) => {
-            error!("{}", e);
-            Err(OmniError::InternalServerError)
+            error!("Error patching a round with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Release the motion of a round
+///
+/// Announces the motion to Judges, Marshals and participants right away
+/// and starts the preparation window. The round is planned to start
+/// once the tournament's debate_preparation_time has passed.
+/// Requires the WriteMotions permission.
+/// Available only to tournament Organizers and the infrastructure admin.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/release_motion",
+    responses(
+        (
+            status=200, description = "Motion released successfully",
+            body=Round,
+            example=json!(get_round_example())
+        ),
+        (status=400, description = "Bad request"),
+        (
+            status=401,
+            description = "The user is not permitted to modify motions within this tournament"
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (
+            status=409,
+            description = "The round has no motion or its motion is already released",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn release_motion(
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteMotions) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let mut tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    let round = Round::get_by_id(id, pool).await?;
+    if phase.tournament_id != tournament_id || round.phase_id != phase_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+    if round.motion_id.is_none() {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "The round has no motion to release".to_owned(),
+        });
+    }
+    if round.motion_is_released() {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "The motion of this round is already released".to_owned(),
+        });
+    }
+
+    let mut new_round = round.clone();
+    new_round.motion_release_time = Some(Utc::now());
+    RuleOverrides::apply_for_round(&mut tournament, &new_round, pool).await?;
+    new_round.start_preparation_window(tournament.get_debate_preparation_time());
+
+    match round.patch(new_round, pool).await {
+        Ok(released_round) => Ok(Json(released_round).into_response()),
+        Err(e) => {
+            error!("Error releasing the motion of a round with id {id}: {e}");
+            Err(e)
         }
     }
 }
@@ -293,6 +417,127 @@ async fn delete_round_by_id(
     }
 }
 
+/// Allocate rooms to debates of a round
+///
+/// Assigns a free room to every debate of the round that is not held in one yet.
+/// A room is free if it is neither booked for another debate nor closed at the same time.
+/// It must also fit the speakers and Judges of the debate and meet
+/// the accessibility requirements of its teams and Judges.
+/// Debates with the most demanding needs are served first.
+/// Rooms at the location most of the teams and Judges of a debate
+/// were at in the previous round are preferred, and locations they cannot reach
+/// within the travel time between the rounds are left out.
+/// Otherwise, rooms are picked in order of their locations and names.
+/// Requires the WriteDebates permission.
+/// Available only to tournament Organizers and the infrastructure admin.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/allocate_rooms",
+    responses(
+        (status=200, description = "Rooms allocated successfully", body=Vec<Debate>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament"
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (
+            status=409,
+            description = "There are not enough free rooms for every debate of the round, or travel times leave no free room for a debate",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn allocate_rooms(
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    let round = Round::get_by_id(id, pool).await?;
+    if phase.tournament_id != tournament_id || round.phase_id != phase_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+
+    match RoomBooking::allocate_rooms(&round, pool).await {
+        Ok(debates) => Ok(Json(debates).into_response()),
+        Err(e) => {
+            error!("Error allocating rooms to debates of a round with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Allocate Judges to debates of a round
+///
+/// Places a Judge on the panel of every debate of the round that has none yet.
+/// Only Judges who do not sit on any panel of the round yet are picked,
+/// and never for a debate attended by a team they're affiliated with.
+/// Judges at the location of the room of the debate in the previous round
+/// are preferred, and Judges who cannot reach it within the travel time
+/// between the rounds are left out. It is best to allocate rooms first.
+/// Requires the WriteDebates permission.
+/// Available only to tournament Organizers and the infrastructure admin.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/allocate_judges",
+    responses(
+        (
+            status=200, description = "Judges allocated successfully",
+            body=Vec<DebateJudgeAssignment>
+        ),
+        (status=400, description = "Bad request"),
+        (
+            status=401,
+            description = "The user is not permitted to modify debates within this tournament"
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (
+            status=409,
+            description = "There are not enough free Judges for every debate of the round, or travel times leave no free Judge for a debate",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn allocate_judges(
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    let round = Round::get_by_id(id, pool).await?;
+    if phase.tournament_id != tournament_id || round.phase_id != phase_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+
+    match DebateJudgeAssignment::allocate(&round, pool).await {
+        Ok(assignments) => Ok(Json(assignments).into_response()),
+        Err(e) => {
+            error!("Error allocating Judges to debates of a round with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
 fn get_round_example() -> String {
     r#"
     {
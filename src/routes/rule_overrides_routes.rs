@@ -0,0 +1,374 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        debates::Debate,
+        phases::Phase,
+        rounds::Round,
+        rule_overrides::{EffectiveRules, RuleOverrides, RuleScope},
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/phases/{id}/rule_overrides",
+            get(get_phase_rule_overrides).put(put_phase_rule_overrides),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/rule_overrides",
+            get(get_round_rule_overrides).put(put_round_rule_overrides),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/rule_overrides",
+            get(get_debate_rule_overrides).put(put_debate_rule_overrides),
+        )
+        .route(
+            "/tournaments/{tournament_id}/debates/{id}/rules",
+            get(get_effective_rules),
+        )
+}
+
+/// Get rule overrides of a phase
+///
+/// Unset values are inherited from the tournament.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/phases/{id}/rule_overrides",
+    responses(
+        (status=200, description = "Ok", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read phases within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="phases"
+)]
+async fn get_phase_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadPhases) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let scope = get_phase_scope(tournament_id, id, pool).await?;
+    get_rule_overrides(scope, pool).await
+}
+
+/// Override rules within a phase
+///
+/// Replaces the previous overrides of the phase.
+/// Unset values are inherited from the tournament.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put, path = "/tournaments/{tournament_id}/phases/{id}/rule_overrides",
+    request_body=RuleOverrides,
+    responses(
+        (status=200, description = "Rules overridden successfully", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify phases within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="phases"
+)]
+async fn put_phase_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    Json(json): Json<RuleOverrides>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WritePhases) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let scope = get_phase_scope(tournament_id, id, pool).await?;
+    put_rule_overrides(json, tournament_id, scope, pool).await
+}
+
+/// Get rule overrides of a round
+///
+/// Unset values are inherited from the phase and the tournament.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get,
+    path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/rule_overrides",
+    responses(
+        (status=200, description = "Ok", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read rounds within this tournament",
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn get_round_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadRounds) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let scope = get_round_scope(tournament_id, phase_id, id, pool).await?;
+    get_rule_overrides(scope, pool).await
+}
+
+/// Override rules within a round
+///
+/// Replaces the previous overrides of the round.
+/// Unset values are inherited from the phase and the tournament.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put,
+    path = "/tournaments/{tournament_id}/phases/{phase_id}/rounds/{id}/rule_overrides",
+    request_body=RuleOverrides,
+    responses(
+        (status=200, description = "Rules overridden successfully", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify rounds within this tournament",
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn put_round_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id, id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(json): Json<RuleOverrides>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteRounds) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let scope = get_round_scope(tournament_id, phase_id, id, pool).await?;
+    put_rule_overrides(json, tournament_id, scope, pool).await
+}
+
+/// Get rule overrides of a debate
+///
+/// Unset values are inherited from the round, the phase and the tournament.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/rule_overrides",
+    responses(
+        (status=200, description = "Ok", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_debate_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    get_rule_overrides(RuleScope::Debate(debate.id), pool).await
+}
+
+/// Override rules of a debate
+///
+/// Replaces the previous overrides of the debate.
+/// Unset values are inherited from the round, the phase and the tournament.
+/// Available only to the tournament Organizers.
+#[utoipa::path(put, path = "/tournaments/{tournament_id}/debates/{id}/rule_overrides",
+    request_body=RuleOverrides,
+    responses(
+        (status=200, description = "Rules overridden successfully", body=RuleOverrides),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn put_debate_rule_overrides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+    Json(json): Json<RuleOverrides>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    put_rule_overrides(json, tournament_id, RuleScope::Debate(debate.id), pool).await
+}
+
+/// Get effective rules of a debate
+///
+/// Applies rule overrides in order: tournament -> phase -> round -> debate.
+/// Debate timers follow these rules.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/rules",
+    responses(
+        (status=200, description = "Ok", body=EffectiveRules),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_effective_rules(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(id, tournament_id, pool).await?;
+    match EffectiveRules::get_for_debate(&debate, pool).await {
+        Ok(rules) => Ok(Json(rules).into_response()),
+        Err(e) => {
+            error!("Error resolving rules of a debate with id {id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn get_rule_overrides(
+    scope: RuleScope,
+    pool: &Pool<Postgres>,
+) -> Result<Response, OmniError> {
+    match RuleOverrides::get(scope, pool).await {
+        Ok(overrides) => Ok(Json(overrides).into_response()),
+        Err(e) => {
+            error!("Error getting rule overrides: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn put_rule_overrides(
+    overrides: RuleOverrides,
+    tournament_id: Uuid,
+    scope: RuleScope,
+    pool: &Pool<Postgres>,
+) -> Result<Response, OmniError> {
+    match overrides.put(tournament_id, scope, pool).await {
+        Ok(overrides) => Ok(Json(overrides).into_response()),
+        Err(e) => {
+            error!("Error overriding rules: {e}");
+            Err(e)
+        }
+    }
+}
+
+async fn get_phase_scope(
+    tournament_id: Uuid,
+    phase_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<RuleScope, OmniError> {
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    match phase.tournament_id == tournament_id {
+        true => Ok(RuleScope::Phase(phase.id)),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
+
+async fn get_round_scope(
+    tournament_id: Uuid,
+    phase_id: Uuid,
+    round_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<RuleScope, OmniError> {
+    get_phase_scope(tournament_id, phase_id, pool).await?;
+    let round = Round::get_by_id(round_id, pool).await?;
+    match round.phase_id == phase_id {
+        true => Ok(RuleScope::Round(round.id)),
+        false => Err(OmniError::ResourceNotFoundError),
+    }
+}
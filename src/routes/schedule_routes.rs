@@ -0,0 +1,155 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use sqlx::{Pool, Postgres};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        rounds::Round,
+        schedules::{Schedule, ScheduleShift},
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/tournaments/{tournament_id}/schedule",
+            post(generate_schedule),
+        )
+        .route(
+            "/tournaments/{tournament_id}/schedule/shift",
+            post(shift_schedule),
+        )
+}
+
+/// Generate the schedule of a tournament
+///
+/// Lays out every round of the tournament in order, following previous_round_id,
+/// and sets their planned start and end times. Each round takes up
+/// debate_preparation_time and debate_time_slot minutes, following rule overrides,
+/// and has to fit between the start and the end of a day without overlapping any break.
+/// Ongoing and Finished rounds keep their times.
+/// Fails if two rounds follow the same round or some round does not follow the first one.
+/// With preview set, the rounds are returned without being changed.
+/// Requires the WriteRounds permission.
+/// Available only to tournament Organizers and the infrastructure admin.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/schedule",
+    request_body=Schedule,
+    responses(
+        (status=200, description = "Rounds of the tournament in order", body=Vec<Round>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify rounds within this tournament"
+        ),
+        (status=404, description = "Tournament not found"),
+        (
+            status=409,
+            description = "Rounds do not follow each other in a single line, or a room would be double booked or used while it is closed",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn generate_schedule(
+    Path(tournament_id): Path<Uuid>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Json(json): Json<Schedule>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteRounds) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let rounds = json.lay_out(tournament_id, pool).await?;
+    apply_schedule(rounds, json.preview, &tournament_user, pool).await
+}
+
+/// Shift the schedule of a tournament
+///
+/// Moves the planned times of every Planned round following the given round
+/// by the given number of minutes, e.g. after a delay.
+/// Ongoing and Finished rounds stay in place, and no round may be moved
+/// to start before the round it follows ends.
+/// Release times of motions that are not released yet are moved as well.
+/// With preview set, the moved rounds are returned without being changed.
+/// Requires the WriteRounds permission.
+/// Available only to tournament Organizers and the infrastructure admin.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/schedule/shift",
+    request_body=ScheduleShift,
+    responses(
+        (status=200, description = "Moved rounds in order", body=Vec<Round>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to modify rounds within this tournament"
+        ),
+        (status=404, description = "Tournament or round not found"),
+        (
+            status=409,
+            description = "Rounds do not follow each other in a single line, or a room would be double booked or used while it is closed",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="rounds"
+)]
+async fn shift_schedule(
+    Path(tournament_id): Path<Uuid>,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Json(json): Json<ScheduleShift>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::WriteRounds) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let rounds = json.shift(tournament_id, pool).await?;
+    apply_schedule(rounds, json.preview, &tournament_user, pool).await
+}
+
+async fn apply_schedule(
+    rounds: Vec<Round>,
+    preview: bool,
+    tournament_user: &TournamentUser,
+    pool: &Pool<Postgres>,
+) -> Result<Response, OmniError> {
+    let mut rounds = match preview {
+        true => rounds,
+        false => match Schedule::apply(rounds, pool).await {
+            Ok(rounds) => rounds,
+            Err(e) => {
+                error!("Error applying a schedule: {e}");
+                return Err(e);
+            }
+        },
+    };
+
+    for round in &mut rounds {
+        round.hide_unreleased_motion(tournament_user);
+    }
+    Ok(Json(rounds).into_response())
+}
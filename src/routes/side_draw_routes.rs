@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{debates::Debate, side_draws::SideDraw},
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new().route(
+        "/tournaments/{tournament_id}/debates/{id}/side_draws",
+        get(get_side_draws).post(draw_sides),
+    )
+}
+
+/// Draw sides of a debate
+///
+/// Randomly assigns the teams of the debate to the positions of its format,
+/// like a coin toss deciding the Proposition and the Opposition.
+/// The seed, the time and the user drawing the sides are recorded.
+/// If the tournament has a side_draw_seed, the draw can be reproduced from it.
+/// Sides can be drawn until the preparation starts;
+/// the debate moves on to SidesDrawn.
+/// Available to the Marshal conducting the debate and the tournament Organizers.
+#[utoipa::path(post, path = "/tournaments/{tournament_id}/debates/{id}/side_draws",
+    responses(
+        (status=200, description = "Sides drawn successfully", body=SideDraw),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to conduct this debate",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (
+            status=409,
+            description = "The debate lacks teams or its preparation has already started",
+        ),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn draw_sides(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    let conducts_debate = debate.marshal_user_id == Some(tournament_user.user.id)
+        && tournament_user.has_permission(Permission::OperateDebateTimers);
+    if !conducts_debate && !tournament_user.has_permission(Permission::WriteDebates) {
+        return Err(OmniError::InsufficientPermissionsError);
+    }
+
+    match SideDraw::draw(debate, tournament_user.user.id, pool).await {
+        Ok(side_draw) => Ok(Json(side_draw).into_response()),
+        Err(e) => {
+            error!("Error drawing sides of a debate with id {debate_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Get all side draws of a debate
+///
+/// Serves as an audit trail, starting with the earliest draw.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/debates/{id}/side_draws",
+    responses(
+        (status=200, description = "Ok", body=Vec<SideDraw>),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or debate not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="debates"
+)]
+async fn get_side_draws(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let debate = Debate::get_by_id_in_tournament(debate_id, tournament_id, pool).await?;
+    match SideDraw::get_all_of_debate(debate.id, pool).await {
+        Ok(side_draws) => Ok(Json(side_draws).into_response()),
+        Err(e) => {
+            error!("Error getting side draws of a debate with id {debate_id}: {e}");
+            Err(e)
+        }
+    }
+}
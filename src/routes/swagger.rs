@@ -6,11 +6,18 @@ use crate::routes::auth;
 use crate::routes::user_routes;
 use crate::setup::AppState;
 
+use crate::routes::accessibility_requirements_routes;
 use crate::routes::affiliation_routes;
 use crate::routes::attendee_routes;
+use crate::routes::debate_format_routes;
+use crate::routes::debate_judge_routes;
 use crate::routes::debate_routes;
+use crate::routes::debate_team_routes;
+use crate::routes::debate_timer_routes;
+use crate::routes::draw_routes;
 use crate::routes::ladder_routes;
 use crate::routes::location_routes;
+use crate::routes::motion_bank_routes;
 use crate::routes::motion_routes;
 use crate::routes::permissions_routes;
 use crate::routes::phase_routes;
@@ -18,22 +25,43 @@ use crate::routes::plans_routes;
 use crate::routes::roles_routes;
 use crate::routes::room_routes;
 use crate::routes::round_routes;
+use crate::routes::rule_overrides_routes;
+use crate::routes::schedule_routes;
+use crate::routes::side_draw_routes;
+use crate::routes::tab_routes;
 use crate::routes::team_routes;
 use crate::routes::tournament_routes;
 use crate::routes::verdicts_routes;
 
 use crate::routes::version;
 use crate::tournaments;
+use crate::tournaments::accessibility_requirements;
 use crate::tournaments::affiliations;
 use crate::tournaments::attendees;
+use crate::tournaments::bracket_links;
+use crate::tournaments::debate_formats;
+use crate::tournaments::debate_judges;
+use crate::tournaments::debate_results;
+use crate::tournaments::debate_teams;
+use crate::tournaments::debate_timers;
 use crate::tournaments::debates;
+use crate::tournaments::draws;
+use crate::tournaments::location_travel_times;
 use crate::tournaments::locations;
+use crate::tournaments::motion_balance;
+use crate::tournaments::motion_bank;
 use crate::tournaments::motions;
 use crate::tournaments::phases;
 use crate::tournaments::plans;
 use crate::tournaments::roles;
+use crate::tournaments::room_bookings;
+use crate::tournaments::room_closures;
 use crate::tournaments::rooms;
 use crate::tournaments::rounds;
+use crate::tournaments::rule_overrides;
+use crate::tournaments::schedules;
+use crate::tournaments::side_draws;
+use crate::tournaments::tab;
 use crate::tournaments::teams;
 use crate::tournaments::verdicts;
 use crate::users::permissions;
@@ -70,6 +98,13 @@ pub fn route() -> Router<AppState> {
         motion_routes::get_motion_by_id,
         motion_routes::patch_motion_by_id,
         motion_routes::delete_motion_by_id,
+        motion_routes::get_motion_balance,
+        motion_bank_routes::get_bank_motions,
+        motion_bank_routes::create_bank_motion,
+        motion_bank_routes::get_bank_motion_by_id,
+        motion_bank_routes::patch_bank_motion_by_id,
+        motion_bank_routes::delete_bank_motion_by_id,
+        motion_bank_routes::copy_bank_motion_to_tournament,
         ladder_routes::get_ladder,
         team_routes::get_teams,
         team_routes::create_team,
@@ -81,6 +116,43 @@ pub fn route() -> Router<AppState> {
         debate_routes::get_debate_by_id,
         debate_routes::patch_debate_by_id,
         debate_routes::delete_debate_by_id,
+        debate_routes::get_debate_result,
+        debate_routes::change_debate_status,
+        side_draw_routes::get_side_draws,
+        side_draw_routes::draw_sides,
+        rule_overrides_routes::get_phase_rule_overrides,
+        rule_overrides_routes::put_phase_rule_overrides,
+        rule_overrides_routes::get_round_rule_overrides,
+        rule_overrides_routes::put_round_rule_overrides,
+        rule_overrides_routes::get_debate_rule_overrides,
+        rule_overrides_routes::put_debate_rule_overrides,
+        rule_overrides_routes::get_effective_rules,
+        debate_team_routes::get_debate_teams,
+        debate_team_routes::create_debate_team,
+        debate_team_routes::get_debate_team_by_id,
+        debate_team_routes::patch_debate_team_by_id,
+        debate_team_routes::delete_debate_team_by_id,
+        debate_format_routes::get_debate_formats,
+        debate_format_routes::create_debate_format,
+        debate_format_routes::get_debate_format_by_id,
+        debate_format_routes::patch_debate_format_by_id,
+        debate_format_routes::delete_debate_format_by_id,
+        debate_format_routes::get_debate_format_presets,
+        debate_format_routes::create_debate_format_from_preset,
+        debate_timer_routes::get_debate_timer,
+        debate_timer_routes::operate_debate_timer,
+        debate_timer_routes::watch_debate_timer,
+        debate_judge_routes::get_debate_judges,
+        debate_judge_routes::create_debate_judge,
+        debate_judge_routes::get_debate_judge_by_id,
+        debate_judge_routes::delete_debate_judge_by_id,
+        draw_routes::draw_round_robin,
+        draw_routes::draw_swiss,
+        draw_routes::seed_bracket,
+        tab_routes::get_tournament_tab,
+        tab_routes::get_phase_tab,
+        tab_routes::get_tournament_speaker_tab,
+        tab_routes::get_phase_speaker_tab,
         attendee_routes::get_attendees,
         attendee_routes::create_attendee,
         attendee_routes::get_attendee_by_id,
@@ -94,11 +166,21 @@ pub fn route() -> Router<AppState> {
         location_routes::get_location_by_id,
         location_routes::patch_location_by_id,
         location_routes::delete_location_by_id,
+        location_routes::get_travel_times,
+        location_routes::put_travel_time,
         room_routes::create_room,
         room_routes::get_rooms,
         room_routes::get_room_by_id,
         room_routes::patch_room_by_id,
         room_routes::delete_room_by_id,
+        room_routes::get_room_occupancy,
+        room_routes::get_room_closures,
+        room_routes::create_room_closure,
+        room_routes::delete_room_closure,
+        accessibility_requirements_routes::get_team_accessibility_requirements,
+        accessibility_requirements_routes::put_team_accessibility_requirements,
+        accessibility_requirements_routes::get_judge_accessibility_requirements,
+        accessibility_requirements_routes::put_judge_accessibility_requirements,
         user_routes::get_users,
         user_routes::create_user,
         user_routes::get_user_by_id,
@@ -119,6 +201,11 @@ pub fn route() -> Router<AppState> {
         round_routes::get_rounds,
         round_routes::patch_round_by_id,
         round_routes::delete_round_by_id,
+        round_routes::release_motion,
+        round_routes::allocate_rooms,
+        round_routes::allocate_judges,
+        schedule_routes::generate_schedule,
+        schedule_routes::shift_schedule,
         phase_routes::create_phase,
         phase_routes::get_phase_by_id,
         phase_routes::get_phases,
@@ -141,10 +228,67 @@ pub fn route() -> Router<AppState> {
         plans::TournamentPlanPatch,
         motions::Motion,
         motions::MotionPatch,
+        motion_bank::BankMotion,
+        motion_bank::BankMotionPatch,
+        motion_bank::MotionDifficulty,
+        motion_balance::SideBalance,
+        motion_balance::PhaseSideBalance,
+        motion_balance::MotionBalance,
+        motion_balance::MotionBalanceReport,
         teams::Team,
         teams::TeamPatch,
         debates::Debate,
         debates::DebatePatch,
+        debates::DebateDetails,
+        debates::DebateStatus,
+        debates::DebateStatusChange,
+        side_draws::SideDraw,
+        side_draws::DrawnSide,
+        rule_overrides::RuleOverrides,
+        rule_overrides::EffectiveRules,
+        schedules::Schedule,
+        schedules::ScheduleBreak,
+        schedules::ScheduleShift,
+        debate_teams::DebateTeamAssignment,
+        debate_teams::DebateTeamAssignmentPatch,
+        debate_formats::Side,
+        debate_formats::SpeechDefinition,
+        debate_formats::DebateFormat,
+        debate_formats::DebateFormatPatch,
+        debate_formats::DebateFormatPreset,
+        debate_timers::Speech,
+        debate_timers::TimerStatus,
+        debate_timers::TimerAction,
+        debate_timers::TimerCommand,
+        debate_timers::ProtectedWindow,
+        debate_timers::BeepKind,
+        debate_timers::Beep,
+        debate_timers::DebateTimerState,
+        debate_timers::DebateTimerEvent,
+        debate_judges::DebateJudgeAssignment,
+        bracket_links::BracketLink,
+        debate_results::DebateResult,
+        debate_results::TeamPlacement,
+        debate_results::DebateResultStatus,
+        debate_results::VerdictTiePolicy,
+        draws::DrawOptions,
+        draws::Draw,
+        draws::DrawnDebate,
+        draws::DrawnBye,
+        draws::knockout::SeedingOptions,
+        draws::knockout::Seeding,
+        draws::knockout::Seed,
+        draws::swiss::SwissDrawOptions,
+        draws::swiss::SwissDraw,
+        draws::swiss::PullUp,
+        draws::Constraint,
+        draws::BrokenConstraint,
+        tab::TieBreak,
+        tab::TieBreakOrder,
+        tab::TeamTab,
+        tab::TeamTabEntry,
+        tab::SpeakerTab,
+        tab::SpeakerTabEntry,
         attendees::Attendee,
         attendees::AttendeePatch,
         permissions::Permission,
@@ -152,8 +296,12 @@ pub fn route() -> Router<AppState> {
         auth::LoginRequest,
         locations::Location,
         locations::LocationPatch,
+        location_travel_times::TravelTime,
         rooms::Room,
         rooms::RoomPatch,
+        room_closures::RoomClosure,
+        room_bookings::RoomOccupancy,
+        accessibility_requirements::AccessibilityRequirements,
         user_routes::UserWithPassword,
         user_routes::UserPasswordPatch,
         crate::users::UserPatch,
@@ -168,7 +316,9 @@ pub fn route() -> Router<AppState> {
         rounds::RoundPatch,
         rounds::RoundStatus,
         verdicts::Verdict,
-        verdicts::VerdictPatch
+        verdicts::VerdictPatch,
+        verdicts::TeamRanking,
+        verdicts::SpeakerScore
     ))
 )]
 pub struct ApiDoc;
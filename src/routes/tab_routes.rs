@@ -0,0 +1,222 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use tower_cookies::Cookies;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    setup::AppState,
+    tournaments::{
+        phases::Phase,
+        tab::{SpeakerTab, TeamTab},
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+pub fn route() -> Router<AppState> {
+    Router::new()
+        .route("/tournaments/{tournament_id}/tab", get(get_tournament_tab))
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/tab",
+            get(get_phase_tab),
+        )
+        .route(
+            "/tournaments/{tournament_id}/speaker_tab",
+            get(get_tournament_speaker_tab),
+        )
+        .route(
+            "/tournaments/{tournament_id}/phases/{phase_id}/speaker_tab",
+            get(get_phase_speaker_tab),
+        )
+}
+
+/// Get team standings of the whole tournament
+///
+/// Teams are ranked by results of all debates of the tournament,
+/// applying tie-breaks in the order configured as the tournament's tab_tie_breaks.
+/// Debates whose panels haven't submitted all verdicts yet are not counted.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/tab",
+    responses(
+        (status=200, description = "Ok", body=TeamTab),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="tab"
+)]
+async fn get_tournament_tab(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match TeamTab::get(tournament_id, None, pool).await {
+        Ok(tab) => Ok(Json(tab).into_response()),
+        Err(e) => {
+            error!("Error getting the tab of a tournament with id {tournament_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Get team standings of a single phase
+///
+/// Only debates of the phase are counted and only teams that debated in it are listed.
+/// Tie-breaks are applied in the order configured as the tournament's tab_tie_breaks.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/phases/{phase_id}/tab",
+    responses(
+        (status=200, description = "Ok", body=TeamTab),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="tab"
+)]
+async fn get_phase_tab(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    if phase.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+
+    match TeamTab::get(tournament_id, Some(phase_id), pool).await {
+        Ok(tab) => Ok(Json(tab).into_response()),
+        Err(e) => {
+            error!("Error getting the tab of a phase with id {phase_id}: {e}");
+            Err(e)
+        }
+    }
+}
+
+/// Get speaker standings of the whole tournament
+///
+/// Speakers are ranked by the total of their scores, then by the average score.
+/// The score of a speaker in a debate is the average of scores given by its panel.
+/// Debates whose panels haven't submitted all verdicts yet are not counted.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/speaker_tab",
+    responses(
+        (status=200, description = "Ok", body=SpeakerTab),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="tab"
+)]
+async fn get_tournament_speaker_tab(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path(tournament_id): Path<Uuid>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    match SpeakerTab::get(tournament_id, None, pool).await {
+        Ok(tab) => Ok(Json(tab).into_response()),
+        Err(e) => {
+            error!(
+                "Error getting the speaker tab of a tournament with id {tournament_id}: {e}"
+            );
+            Err(e)
+        }
+    }
+}
+
+/// Get speaker standings of a single phase
+///
+/// Only scores given in debates of the phase are counted.
+/// The user must be given a role within this tournament to use this endpoint.
+#[utoipa::path(get, path = "/tournaments/{tournament_id}/phases/{phase_id}/speaker_tab",
+    responses(
+        (status=200, description = "Ok", body=SpeakerTab),
+        (status=400, description = "Bad request"),
+        (status=401, description = "Authentication error"),
+        (
+            status=403,
+            description = "The user is not permitted to read debates within this tournament",
+        ),
+        (status=404, description = "Tournament or phase not found"),
+        (status=500, description = "Internal server error"),
+    ),
+    tag="tab"
+)]
+async fn get_phase_speaker_tab(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    cookies: Cookies,
+    Path((tournament_id, phase_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, OmniError> {
+    let pool = &state.connection_pool;
+    let tournament_user =
+        TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
+
+    match tournament_user.has_permission(Permission::ReadDebates) {
+        true => (),
+        false => return Err(OmniError::InsufficientPermissionsError),
+    }
+
+    let phase = Phase::get_by_id(phase_id, pool).await?;
+    if phase.tournament_id != tournament_id {
+        return Err(OmniError::ResourceNotFoundError);
+    }
+
+    match SpeakerTab::get(tournament_id, Some(phase_id), pool).await {
+        Ok(tab) => Ok(Json(tab).into_response()),
+        Err(e) => {
+            error!("Error getting the speaker tab of a phase with id {phase_id}: {e}");
+            Err(e)
+        }
+    }
+}
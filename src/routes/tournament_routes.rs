@@ -54,7 +54,7 @@ async fn get_tournaments(
 
     let tournaments = Tournament::get_all(pool).await?;
     let mut visible_tournaments: Vec<Tournament> = vec![];
-    for tournament in tournaments {
+    for mut tournament in tournaments {
         let tournament_id = tournament.id;
         let roles = user.get_roles(tournament_id, pool).await?;
         let tournament_user = TournamentUser {
@@ -62,6 +62,7 @@ async fn get_tournaments(
             roles,
         };
         if tournament_user.has_permission(Permission::ReadTournament) {
+            tournament.hide_side_draw_seed(&tournament_user);
             visible_tournaments.push(tournament);
         }
     }
@@ -112,6 +113,7 @@ async fn create_tournament(
 
 /// Get details of an existing tournament
 ///
+/// The side draw seed is visible only to Organizers.
 /// The user must be given any role within the tournament to use this endpoint.
 #[utoipa::path(get, path = "/tournaments/{id}",
     responses
@@ -148,7 +150,10 @@ async fn get_tournament_by_id(
         false => return Err(OmniError::InsufficientPermissionsError),
     }
     match Tournament::get_by_id(id, pool).await {
-        Ok(tournament) => Ok(Json(tournament).into_response()),
+        Ok(mut tournament) => {
+            tournament.hide_side_draw_seed(&tournament_user);
+            Ok(Json(tournament).into_response())
+        }
         Err(e) => Err(e),
     }
 }
@@ -171,7 +176,11 @@ async fn get_tournament_by_id(
             description = "The user is not permitted to modify this tournament"
         ),
         (status=404, description = "Tournament not found"),
-        (status=409, description = "A tournament with this name already exists"),
+        (
+            status=409,
+            description = "A tournament with this name already exists \
+            or the debate format is not from this tournament"
+        ),
         (status=500, description = "Internal server error")
     ),
     tag="tournaments"
@@ -263,7 +272,13 @@ fn get_tournament_example_with_id() -> String {
     "debate_preparation_time": 15,
     "beep_on_speech_end": true,
     "beep_on_protected_time": true,
-    "visualize_protected_time": false
+    "visualize_protected_time": false,
+    "verdict_tie_policy": "Tied",
+    "tab_tie_breaks": ["Wins", "Ballots", "HeadToHead", "Random"],
+    "speaker_score_min": 60.0,
+    "speaker_score_max": 80.0,
+    "speaker_score_step": 0.5,
+    "allow_low_point_wins": false
     }
     "#
     .to_owned()
@@ -284,7 +299,13 @@ fn get_tournaments_list_example() -> String {
     "debate_preparation_time": 15,
     "beep_on_speech_end": true,
     "beep_on_protected_time": true,
-    "visualize_protected_time": false
+    "visualize_protected_time": false,
+    "verdict_tie_policy": "Tied",
+    "tab_tie_breaks": ["Wins", "Ballots", "HeadToHead", "Random"],
+    "speaker_score_min": 60.0,
+    "speaker_score_max": 80.0,
+    "speaker_score_step": 0.5,
+    "allow_low_point_wins": false
     },
     {
     "id": "019cddac-ab1e-72e0-8486-6e6a93930628",
@@ -298,7 +319,13 @@ fn get_tournaments_list_example() -> String {
     "debate_preparation_time": 15,
     "beep_on_speech_end": true,
     "beep_on_protected_time": true,
-    "visualize_protected_time": true
+    "visualize_protected_time": true,
+    "verdict_tie_policy": "Tied",
+    "tab_tie_breaks": ["Wins", "Ballots", "HeadToHead", "Random"],
+    "speaker_score_min": 60.0,
+    "speaker_score_max": 80.0,
+    "speaker_score_step": 0.5,
+    "allow_low_point_wins": false
     }
         ]
     "#
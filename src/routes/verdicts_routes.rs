@@ -5,7 +5,6 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
-use sqlx::query_as;
 use tower_cookies::Cookies;
 use tracing::error;
 use uuid::Uuid;
@@ -13,10 +12,16 @@ use uuid::Uuid;
 use crate::{
     omni_error::OmniError,
     setup::AppState,
-    tournaments::verdicts::{Verdict, VerdictPatch},
+    tournaments::{
+        debates::Debate,
+        verdicts::{Verdict, VerdictPatch},
+    },
     users::{permissions::Permission, TournamentUser},
 };
 
+const DUPLICATE_VERDICT_ERROR: &str =
+    "This judge has already made a verdict on this debate";
+
 pub fn route() -> Router<AppState> {
     Router::new()
         .route(
@@ -33,14 +38,40 @@ pub fn route() -> Router<AppState> {
 
 /// Create a new verdict
 ///
-/// Requires SubmitOwnVerdictVote permission. Available to Judges, Organizers and the admin.
+/// Once every Judge on the panel has ruled, the debate is concluded.
+/// The winner of a concluded finals debate advances to the linked debate
+/// of the next round.
+/// Requires SubmitOwnVerdictVote or SubmitVerdict permission.
+/// Available to Judges, Marshals, Organizers and the admin.
+/// The judge must sit on the panel of the debate and must not be affiliated
+/// with any of the debating teams.
+/// Submitting a verdict on behalf of another judge requires SubmitVerdict permission.
+/// Every judge makes a single verdict on a debate.
+/// Verdicts of two-team debates name the winning side with proposition_won,
+/// while verdicts of four-team debates rank every position from 1 to 4 instead.
+/// Speaker scores are optional, but once given, every speaker of the debate
+/// must be scored within the range configured for the tournament.
+/// Better placed teams must have higher totals of scores,
+/// unless the tournament allows low-point wins.
 #[utoipa::path(post, request_body=Verdict, path = "/tournaments/{tournament_id}/debates/{debate_id}/verdicts",
     responses(
         (status=200, description = "Ok", body=Verdict),
         (status=400, description = "Bad request"),
         (status=401, description = "Unauthorized"),
         (status=404, description = "Resource not found"),
-        (status=422, description = "Unprocessable entity"),
+        (
+            status=409,
+            description = "The judge is not on the panel or is affiliated with a debating team, \
+            has already made a verdict on the debate, \
+            the debate has been cancelled, \
+            the winning team has a lower total of speaker scores, \
+            or the debate the winner advances to has already started",
+        ),
+        (
+            status=422,
+            description = "The ballot does not suit the debate format, \
+            or speaker scores are incomplete or out of the tournament's range",
+        ),
         (status=500, description = "Internal server error"),
     ),
     tag="verdicts"
@@ -49,20 +80,35 @@ async fn create_verdict(
     State(state): State<AppState>,
     headers: HeaderMap,
     cookies: Cookies,
-    Path((tournament_id, _debate_id)): Path<(Uuid, Uuid)>,
+    Path((tournament_id, debate_id)): Path<(Uuid, Uuid)>,
     Json(verdict): Json<Verdict>,
 ) -> Result<Response, OmniError> {
+    if verdict.debate_id != debate_id {
+        return Err(OmniError::BadRequestError);
+    }
+
     let pool = &state.connection_pool;
     let tournament_user =
         TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
 
-    match tournament_user.has_permission(Permission::SubmitOwnVerdictVote) {
-        true => (),
-        false => return Err(OmniError::InsufficientPermissionsError),
+    if !tournament_user.has_permission(Permission::SubmitOwnVerdictVote)
+        && !tournament_user.has_permission(Permission::SubmitVerdict)
+    {
+        return Err(OmniError::InsufficientPermissionsError);
     }
 
+    verdict
+        .validate(&tournament_user, tournament_id, pool)
+        .await?;
+    if verdict.already_exists(pool).await? {
+        return Err(duplicate_verdict_error());
+    }
     match Verdict::post(verdict, pool).await {
-        Ok(verdict) => Ok(Json(verdict).into_response()),
+        Ok(verdict) => {
+            forget_timer_if_concluded(&state, verdict.debate_id).await?;
+            Ok(Json(verdict).into_response())
+        }
+        Err(e) if e.is_sqlx_unique_violation() => Err(duplicate_verdict_error()),
         Err(e) => {
             error!("Error creating a new verdict: {e}");
             Err(e)
@@ -94,14 +140,7 @@ async fn get_verdicts(
     let pool = &state.connection_pool;
     TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
 
-    match query_as!(
-        Verdict,
-        "SELECT * FROM verdicts WHERE debate_id = $1",
-        debate_id
-    )
-    .fetch_all(&state.connection_pool)
-    .await
-    {
+    match Verdict::get_all_of_debate(debate_id, pool).await {
         Ok(verdicts) => Ok(Json(verdicts).into_response()),
         Err(e) => {
             error!("Error getting verdicts of debate {}: {e}", debate_id);
@@ -140,8 +179,11 @@ async fn get_verdict_by_id(
 /// Requires SubmitOwnVerdictVote permission to update your own verdicts.
 /// Requires SubmitVerdict permission to change the judge or correct verdicts on behalf of others.
 /// Available to Judges, Organizers and the admin.
-/// The judge specified in the verdict must have either SubmitOwnVerdictVote or SubmitVerdict permission.
+/// The judge specified in the verdict must have either SubmitOwnVerdictVote or SubmitVerdict permission,
+/// must sit on the panel of the debate and must not be affiliated with any of the debating teams.
 /// Attempting to change the verdict's judge without SubmitVerdict permission will result in 401.
+/// Amending a verdict of a finals debate re-evaluates which team advances from it,
+/// unless the debate the team has advanced to has already started.
 /// Patch an existing verdict
 #[utoipa::path(patch, path = "/tournaments/{tournament_id}/debates/{debate_id}/verdicts/{verdict_id}",
     request_body=Verdict,
@@ -150,6 +192,12 @@ async fn get_verdict_by_id(
         (status=400, description = "Bad request"),
         (status=401, description = "Unauthorized"),
         (status=404, description = "Resource not found"),
+        (
+            status=409,
+            description = "The judge is not on the panel or is affiliated with a debating team, \
+            has already made a verdict on the debate, \
+            or the debate the winner has advanced to has already started",
+        ),
         (status=422, description = "Unprocessable entity"),
         (status=500, description = "Internal server error"),
     ),
@@ -168,9 +216,10 @@ async fn patch_verdict_by_id(
     let tournament_user =
         TournamentUser::authenticate(tournament_id, &headers, cookies, pool).await?;
 
-    match tournament_user.has_permission(Permission::SubmitOwnVerdictVote) {
-        true => (),
-        false => return Err(OmniError::InsufficientPermissionsError),
+    if !tournament_user.has_permission(Permission::SubmitOwnVerdictVote)
+        && !tournament_user.has_permission(Permission::SubmitVerdict)
+    {
+        return Err(OmniError::InsufficientPermissionsError);
     }
 
     let old_verdict = Verdict::get_by_id(id, pool).await?;
@@ -181,9 +230,11 @@ async fn patch_verdict_by_id(
             .judge_user_id
             .unwrap_or(old_verdict.judge_user_id),
         debate_id: new_verdict.debate_id.unwrap_or(old_verdict.debate_id),
-        proposition_won: new_verdict
-            .proposition_won
-            .unwrap_or(old_verdict.proposition_won),
+        proposition_won: new_verdict.proposition_won.or(old_verdict.proposition_won),
+        rankings: new_verdict.rankings.unwrap_or(old_verdict.rankings.clone()),
+        speaker_scores: new_verdict
+            .speaker_scores
+            .unwrap_or(old_verdict.speaker_scores.clone()),
     };
 
     if old_verdict.judge_user_id != new_verdict.judge_user_id {
@@ -211,16 +262,20 @@ async fn patch_verdict_by_id(
         });
     }
 
+    new_verdict
+        .validate(&tournament_user, tournament_id, pool)
+        .await?;
+
     if new_verdict.already_exists(pool).await? {
-        return Err(OmniError::ExplicitError {
-            status: StatusCode::CONFLICT,
-            message: "there is already an identical verdict regarding this debate"
-                .to_string(),
-        });
+        return Err(duplicate_verdict_error());
     }
 
     match old_verdict.patch(new_verdict, pool).await {
-        Ok(verdict) => Ok(Json(verdict).into_response()),
+        Ok(verdict) => {
+            forget_timer_if_concluded(&state, verdict.debate_id).await?;
+            Ok(Json(verdict).into_response())
+        }
+        Err(e) if e.is_sqlx_unique_violation() => Err(duplicate_verdict_error()),
         Err(e) => Err(e)?,
     }
 }
@@ -228,12 +283,15 @@ async fn patch_verdict_by_id(
 /// Delete an existing verdict
 ///
 /// Requires SubmitOwnVerdictVote permission. Available to Judges, Organizers and the admin.
+/// Verdicts of finals debates cannot be deleted
+/// once the debate their winner has advanced to has started.
 #[utoipa::path(delete, path = "/tournaments/{tournament_id}/debates/{debate_id}/verdicts/{verdict_id}",
     responses(
         (status=204, description = "No content"),
         (status=400, description = "Bad request"),
         (status=401, description = "Unauthorized"),
         (status=404, description = "Resource not found"),
+        (status=409, description = "The debate the winner has advanced to has already started"),
         (status=422, description = "Unprocessable entity"),
         (status=500, description = "Internal server error"),
     ),
@@ -264,3 +322,22 @@ async fn delete_verdict_by_id(
         }
     }
 }
+
+/// Timers of debates are no longer needed once the panel has ruled on them
+async fn forget_timer_if_concluded(
+    state: &AppState,
+    debate_id: Uuid,
+) -> Result<(), OmniError> {
+    let debate = Debate::get_by_id(debate_id, &state.connection_pool).await?;
+    if debate.status.is_over() {
+        state.debate_timers.remove(debate_id);
+    }
+    Ok(())
+}
+
+fn duplicate_verdict_error() -> OmniError {
+    OmniError::ExplicitError {
+        status: StatusCode::CONFLICT,
+        message: DUPLICATE_VERDICT_ERROR.to_owned(),
+    }
+}
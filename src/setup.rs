@@ -7,7 +7,7 @@ use tower_http::cors::CorsLayer;
 use tracing::{error, info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
 
-use crate::database;
+use crate::{database, tournaments::debate_timers::DebateTimers};
 
 const CRYPTO_SECRET_CORRECT: &str = "Cryptographic SECRET is set.";
 const CRYPTO_SECRET_NOT_SET: &str = "Cryptographic SECRET is not set. This may lead to increased predictability in token generation.";
@@ -69,17 +69,20 @@ pub fn get_local_socket_addr() -> SocketAddrV4 {
 #[derive(Clone)]
 pub struct AppState {
     pub connection_pool: Pool<Postgres>,
+    pub debate_timers: DebateTimers,
 }
 
 pub async fn create_app_state() -> AppState {
     AppState {
         connection_pool: database::get_connection_pool().await,
+        debate_timers: DebateTimers::default(),
     }
 }
 
 pub fn create_app_state_with_pool(pool: Pool<Postgres>) -> AppState {
     AppState {
         connection_pool: pool,
+        debate_timers: DebateTimers::default(),
     }
 }
 
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::omni_error::OmniError;
+
+#[derive(Serialize, Deserialize, ToSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// What a room must offer to hold debates of a team or judged by a Judge.
+/// The room allocator only assigns rooms meeting the needs
+/// of every team and Judge of a debate.
+pub struct AccessibilityRequirements {
+    /// The room must be reachable without stairs
+    #[serde(default)]
+    pub needs_step_free_access: bool,
+    #[serde(default)]
+    pub needs_projector: bool,
+    /// The room must be equipped for remote participants
+    #[serde(default)]
+    pub needs_online_room: bool,
+}
+
+/// Participant of the tournament the requirements belong to
+#[derive(Clone, Copy)]
+pub enum RequirementHolder {
+    Team(Uuid),
+    /// A Judge, by their user ID
+    Judge(Uuid),
+}
+
+impl RequirementHolder {
+    fn ids(self) -> (Option<Uuid>, Option<Uuid>) {
+        match self {
+            RequirementHolder::Team(id) => (Some(id), None),
+            RequirementHolder::Judge(id) => (None, Some(id)),
+        }
+    }
+}
+
+impl AccessibilityRequirements {
+    /// Returns requirements of the team or the Judge within the tournament.
+    /// If there are none, nothing is required.
+    pub async fn get(
+        holder: RequirementHolder,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<AccessibilityRequirements, OmniError> {
+        let (team_id, judge_user_id) = holder.ids();
+        let requirements = query_as!(
+            AccessibilityRequirements,
+            r#"SELECT needs_step_free_access, needs_projector, needs_online_room
+            FROM accessibility_requirements
+            WHERE tournament_id = $1 AND (team_id = $2 OR judge_user_id = $3)"#,
+            tournament_id,
+            team_id,
+            judge_user_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(requirements.unwrap_or_default())
+    }
+
+    /// Replaces requirements of the team or the Judge within the tournament
+    pub async fn put(
+        self,
+        holder: RequirementHolder,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<AccessibilityRequirements, OmniError> {
+        let (team_id, judge_user_id) = holder.ids();
+        let mut transaction = pool.begin().await?;
+        query!(
+            r#"DELETE FROM accessibility_requirements
+            WHERE tournament_id = $1 AND (team_id = $2 OR judge_user_id = $3)"#,
+            tournament_id,
+            team_id,
+            judge_user_id
+        )
+        .execute(&mut *transaction)
+        .await?;
+        query!(
+            r#"INSERT INTO accessibility_requirements
+            (
+                id, tournament_id, team_id, judge_user_id,
+                needs_step_free_access, needs_projector, needs_online_room
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            Uuid::now_v7(),
+            tournament_id,
+            team_id,
+            judge_user_id,
+            self.needs_step_free_access,
+            self.needs_projector,
+            self.needs_online_room
+        )
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        Ok(self)
+    }
+}
@@ -1,5 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::{query, query_as, Executor, Pool, Postgres};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -125,6 +125,32 @@ impl Affiliation {
         Ok(())
     }
 
+    /// Checks whether the Judge is affiliated with any team attending the debate
+    pub async fn exists_between_judge_and_debate<'e, E>(
+        judge_user_id: Uuid,
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<bool, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM judge_team_assignments j
+                JOIN debate_teams_assignments a ON a.team_id = j.team_id
+                WHERE j.judge_user_id = $1 AND a.debate_id = $2
+            )"#,
+            judge_user_id,
+            debate_id
+        )
+        .fetch_one(executor)
+        .await
+        {
+            Ok(result) => Ok(result.exists.unwrap()),
+            Err(e) => Err(e)?,
+        }
+    }
+
     pub async fn infer_tournament_id(
         &self,
         pool: &Pool<Postgres>,
@@ -0,0 +1,234 @@
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Executor, Postgres, Transaction};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_results::DebateResult,
+        debate_teams::DebateTeamAssignment,
+        debates::{Debate, DebateStatus},
+        rounds::Round,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+/// Connects a finals debate with the debate of the next round
+/// its winner advances to
+pub struct BracketLink {
+    pub id: Uuid,
+    pub source_debate_id: Uuid,
+    pub target_debate_id: Uuid,
+    /// Side the winner of the source debate takes in the target debate
+    pub is_proposition: bool,
+}
+
+impl BracketLink {
+    /// Returns bracket links of all debates within a tournament
+    pub async fn get_all<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<BracketLink>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let links = query_as!(
+            BracketLink,
+            r#"
+            SELECT l.id, l.source_debate_id, l.target_debate_id, l.is_proposition
+            FROM bracket_links l
+            JOIN debates d ON d.id = l.source_debate_id
+            WHERE d.tournament_id = $1
+            "#,
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(links)
+    }
+
+    pub async fn get_by_source_debate_id<'e, E>(
+        source_debate_id: Uuid,
+        executor: E,
+    ) -> Result<Option<BracketLink>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let link = query_as!(
+            BracketLink,
+            "SELECT * FROM bracket_links WHERE source_debate_id = $1",
+            source_debate_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(link)
+    }
+
+    /// Wires consecutive finals rounds into a bracket.
+    /// Debates of each round are taken in the order of creation;
+    /// winners of debates 2k and 2k+1 meet in debate k of the next round,
+    /// as the Proposition and the Opposition respectively.
+    /// Missing debates of later rounds are created
+    /// and teams previously advanced into them are removed.
+    pub async fn link_rounds_with_transaction(
+        rounds: &[Round],
+        tournament_id: Uuid,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<Vec<BracketLink>, OmniError> {
+        let mut links = vec![];
+        for (round, next_round) in rounds.iter().zip(rounds.iter().skip(1)) {
+            let mut debates = round.get_debates(&mut **transaction).await?;
+            debates.sort_by_key(|debate| debate.id);
+            let mut next_debates = next_round.get_debates(&mut **transaction).await?;
+            next_debates.sort_by_key(|debate| debate.id);
+            while next_debates.len() < debates.len().div_ceil(2) {
+                let debate = Debate::post_with_transaction(
+                    transaction,
+                    tournament_id,
+                    Debate {
+                        id: Uuid::now_v7(),
+                        motion_id: next_round.motion_id,
+                        marshal_user_id: None,
+                        tournament_id,
+                        round_id: next_round.id,
+                        debate_format_id: None,
+                        room_id: None,
+                        status: DebateStatus::Scheduled,
+                        preparation_end_time: None,
+                    },
+                )
+                .await?;
+                next_debates.push(debate);
+            }
+
+            for debate in &next_debates {
+                query!(
+                    "DELETE FROM bracket_links WHERE target_debate_id = $1",
+                    debate.id
+                )
+                .execute(&mut **transaction)
+                .await?;
+                DebateTeamAssignment::delete_all_of_debate_with_transaction(
+                    debate.id,
+                    transaction,
+                )
+                .await?;
+            }
+
+            for (index, debate) in debates.iter().enumerate() {
+                query!(
+                    "DELETE FROM bracket_links WHERE source_debate_id = $1",
+                    debate.id
+                )
+                .execute(&mut **transaction)
+                .await?;
+                let link = query_as!(
+                    BracketLink,
+                    r#"INSERT INTO bracket_links
+                        (id, source_debate_id, target_debate_id, is_proposition)
+                    VALUES ($1, $2, $3, $4)
+                    RETURNING id, source_debate_id, target_debate_id, is_proposition"#,
+                    Uuid::now_v7(),
+                    debate.id,
+                    next_debates[index / 2].id,
+                    index % 2 == 0
+                )
+                .fetch_one(&mut **transaction)
+                .await?;
+                links.push(link);
+            }
+        }
+
+        Ok(links)
+    }
+
+    /// Moves the winner of a debate into the linked debate of the next round.
+    /// Until the result of the debate is decided,
+    /// the slot in the next debate is left empty.
+    /// Does nothing for debates without a bracket link.
+    /// Four-team debates are rejected, as two of their teams would have to advance.
+    /// Once the next debate has started, the team in the slot can no longer be replaced.
+    pub async fn advance_winner_with_transaction(
+        debate_id: Uuid,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        let Some(link) =
+            BracketLink::get_by_source_debate_id(debate_id, &mut **transaction).await?
+        else {
+            return Ok(());
+        };
+
+        let ranks_teams = query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM verdicts WHERE debate_id = $1 AND proposition_won IS NULL
+            ) AS "exists!""#,
+            debate_id
+        )
+        .fetch_one(&mut **transaction)
+        .await?
+        .exists;
+        if ranks_teams {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "Only the winner of a two-team debate can advance".to_owned(),
+            });
+        }
+
+        let winner_team_id = DebateResult::get_by_debate_id(debate_id, transaction)
+            .await?
+            .winner_team_id;
+        let target_status = query!(
+            "SELECT status FROM debates WHERE id = $1 FOR NO KEY UPDATE",
+            link.target_debate_id
+        )
+        .fetch_one(&mut **transaction)
+        .await?
+        .status;
+        let advanced_team_id = query!(
+            r#"SELECT team_id FROM debate_teams_assignments
+            WHERE debate_id = $1 AND is_proposition = $2"#,
+            link.target_debate_id,
+            link.is_proposition
+        )
+        .fetch_optional(&mut **transaction)
+        .await?
+        .map(|assignment| assignment.team_id);
+        if advanced_team_id == winner_team_id {
+            return Ok(());
+        }
+        if DebateStatus::try_from(target_status)?.has_started() {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The debate the winner has advanced to has already started"
+                    .to_owned(),
+            });
+        }
+
+        query!(
+            "DELETE FROM debate_teams_assignments WHERE debate_id = $1 AND is_proposition = $2",
+            link.target_debate_id,
+            link.is_proposition
+        )
+        .execute(&mut **transaction)
+        .await?;
+        if let Some(team_id) = winner_team_id {
+            DebateTeamAssignment::post_with_transaction(
+                transaction,
+                DebateTeamAssignment {
+                    id: Uuid::now_v7(),
+                    team_id,
+                    debate_id: link.target_debate_id,
+                    is_proposition: Some(link.is_proposition),
+                    side: None,
+                },
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,759 @@
+use std::fmt;
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, Error, Executor, Pool, Postgres};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::omni_error::OmniError;
+
+use super::{debates::Debate, Tournament};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Position of a team in a debate. Two-team formats use the Proposition
+/// and the Opposition, while British Parliamentary debates are attended
+/// by two Government and two Opposition teams.
+pub enum Side {
+    Proposition,
+    Opposition,
+    OpeningGovernment,
+    OpeningOpposition,
+    ClosingGovernment,
+    ClosingOpposition,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A single speech in the order of speeches of a debate format
+pub struct SpeechDefinition {
+    pub side: Side,
+    /// Position of the speaker within their team, starting from 1
+    pub speaker_position: i32,
+    /// Length of the speech in seconds
+    pub duration: i32,
+    /// Indicates how much time (in seconds) at the start of the speech is protected time
+    /// during which points of information do not have to be answered
+    #[serde(default)]
+    pub start_protected_time: i32,
+    /// Indicates how much time (in seconds) at the end of the speech is protected time
+    /// during which points of information do not have to be answered
+    #[serde(default)]
+    pub end_protected_time: i32,
+    /// Indicates whether the speech is a reply speech summarizing the debate for its side
+    #[serde(default)]
+    pub is_reply: bool,
+    /// Indicates whether the other side can be given an ad vocem reply to the speech
+    #[serde(default)]
+    pub allows_ad_vocem: bool,
+}
+
+/// Defines how debates are conducted: how many speakers each team has
+/// and the order of their speeches.
+///
+/// Formats belong to a tournament. The tournament chooses a format for its debates,
+/// and a single debate can use another one. Until the tournament chooses a format,
+/// its debates follow the Oxford format with the speech rules of the tournament.
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DebateFormat {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    /// Parent tournament ID. Taken from the request path.
+    #[serde(skip_deserializing)]
+    #[serde(default)]
+    pub tournament_id: Uuid,
+    /// Name of the format, e.g. "WSDC". Must be unique within a tournament.
+    pub name: String,
+    /// Attendees of a team can take speaker positions from 1 up to this number
+    pub speakers_per_team: i32,
+    /// Time of a separate ad vocem speech in seconds.
+    /// Ad vocem speeches are not allowed if zero.
+    #[serde(default)]
+    pub ad_vocem_time: i32,
+    /// Speeches of a debate, in the order they are given
+    pub speeches: Vec<SpeechDefinition>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DebateFormatPatch {
+    name: Option<String>,
+    speakers_per_team: Option<i32>,
+    ad_vocem_time: Option<i32>,
+    /// Replaces the whole order of speeches
+    speeches: Option<Vec<SpeechDefinition>>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+/// Commonly used debate formats that can be added to a tournament as they are
+pub enum DebateFormatPreset {
+    /// Four speakers per team taking turns, with ad vocem replies.
+    /// Follows speech_time, protected time and ad_vocem_time of the tournament.
+    Oxford,
+    /// World Schools: three speakers per team followed by reply speeches
+    #[serde(rename = "WSDC")]
+    Wsdc,
+    /// Australs: three speakers per team followed by reply speeches,
+    /// without points of information
+    Australs,
+    /// British Parliamentary: four teams of two speakers, ranked by the panel
+    #[serde(rename = "BP")]
+    BritishParliamentary,
+}
+
+impl DebateFormat {
+    pub async fn post(
+        tournament_id: Uuid,
+        format: DebateFormat,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateFormat, OmniError> {
+        let format = DebateFormat {
+            tournament_id,
+            ..format
+        };
+        format.validate()?;
+        let mut transaction = pool.begin().await?;
+        query!(
+            r#"INSERT INTO debate_formats
+            (id, tournament_id, name, speakers_per_team, ad_vocem_time)
+            VALUES ($1, $2, $3, $4, $5)"#,
+            format.id,
+            format.tournament_id,
+            format.name,
+            format.speakers_per_team,
+            format.ad_vocem_time
+        )
+        .execute(&mut *transaction)
+        .await?;
+        format.insert_speeches(&mut transaction).await?;
+        transaction.commit().await?;
+        Ok(format)
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateFormat, OmniError> {
+        let record = match query!(
+            r#"SELECT id, tournament_id, name, speakers_per_team, ad_vocem_time
+            FROM debate_formats WHERE id = $1"#,
+            id
+        )
+        .fetch_one(pool)
+        .await
+        {
+            Ok(record) => record,
+            Err(e) => match e {
+                Error::RowNotFound => return Err(OmniError::ResourceNotFoundError),
+                _ => {
+                    error!("Failed to get a debate format with id {id}: {e}");
+                    return Err(e)?;
+                }
+            },
+        };
+        let speeches = query!(
+            r#"SELECT side, speaker_position, duration, start_protected_time,
+            end_protected_time, is_reply, allows_ad_vocem
+            FROM debate_format_speeches WHERE debate_format_id = $1
+            ORDER BY speech_order"#,
+            id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut format = DebateFormat {
+            id: record.id,
+            tournament_id: record.tournament_id,
+            name: record.name,
+            speakers_per_team: record.speakers_per_team,
+            ad_vocem_time: record.ad_vocem_time,
+            speeches: Vec::with_capacity(speeches.len()),
+        };
+        for speech in speeches {
+            format.speeches.push(SpeechDefinition {
+                side: Side::try_from(speech.side)?,
+                speaker_position: speech.speaker_position,
+                duration: speech.duration,
+                start_protected_time: speech.start_protected_time,
+                end_protected_time: speech.end_protected_time,
+                is_reply: speech.is_reply,
+                allows_ad_vocem: speech.allows_ad_vocem,
+            });
+        }
+        Ok(format)
+    }
+
+    pub async fn get_all(
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<DebateFormat>, OmniError> {
+        let records = query!(
+            r#"SELECT id, tournament_id, name, speakers_per_team, ad_vocem_time
+            FROM debate_formats WHERE tournament_id = $1 ORDER BY name"#,
+            tournament_id
+        )
+        .fetch_all(pool)
+        .await?;
+        let speeches = query!(
+            r#"SELECT s.debate_format_id, s.side, s.speaker_position, s.duration,
+            s.start_protected_time, s.end_protected_time, s.is_reply, s.allows_ad_vocem
+            FROM debate_format_speeches s
+            JOIN debate_formats f ON f.id = s.debate_format_id
+            WHERE f.tournament_id = $1
+            ORDER BY s.debate_format_id, s.speech_order"#,
+            tournament_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut formats: Vec<DebateFormat> = records
+            .into_iter()
+            .map(|record| DebateFormat {
+                id: record.id,
+                tournament_id: record.tournament_id,
+                name: record.name,
+                speakers_per_team: record.speakers_per_team,
+                ad_vocem_time: record.ad_vocem_time,
+                speeches: vec![],
+            })
+            .collect();
+        for speech in speeches {
+            let Some(format) = formats
+                .iter_mut()
+                .find(|format| format.id == speech.debate_format_id)
+            else {
+                continue;
+            };
+            format.speeches.push(SpeechDefinition {
+                side: Side::try_from(speech.side)?,
+                speaker_position: speech.speaker_position,
+                duration: speech.duration,
+                start_protected_time: speech.start_protected_time,
+                end_protected_time: speech.end_protected_time,
+                is_reply: speech.is_reply,
+                allows_ad_vocem: speech.allows_ad_vocem,
+            });
+        }
+        Ok(formats)
+    }
+
+    /// Returns the format chosen by the tournament,
+    /// or the Oxford format following speech rules of the tournament
+    pub async fn get_for_tournament(
+        tournament: &Tournament,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateFormat, OmniError> {
+        match tournament.debate_format_id {
+            Some(id) => DebateFormat::get_by_id(id, pool).await,
+            None => Ok(tournament.get_oxford_format()),
+        }
+    }
+
+    /// Returns the format of a debate, which overrides the format of its tournament
+    pub async fn get_for_debate(
+        debate: &Debate,
+        tournament: &Tournament,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateFormat, OmniError> {
+        match debate.debate_format_id {
+            Some(id) => DebateFormat::get_by_id(id, pool).await,
+            None => DebateFormat::get_for_tournament(tournament, pool).await,
+        }
+    }
+
+    pub async fn patch(
+        self,
+        patch: DebateFormatPatch,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateFormat, OmniError> {
+        let speeches_replaced = patch.speeches.is_some();
+        let format = DebateFormat {
+            id: self.id,
+            tournament_id: self.tournament_id,
+            name: patch.name.unwrap_or(self.name),
+            speakers_per_team: patch.speakers_per_team.unwrap_or(self.speakers_per_team),
+            ad_vocem_time: patch.ad_vocem_time.unwrap_or(self.ad_vocem_time),
+            speeches: patch.speeches.unwrap_or(self.speeches),
+        };
+        format.validate()?;
+        let mut transaction = pool.begin().await?;
+        query!(
+            r#"UPDATE debate_formats SET name = $1, speakers_per_team = $2, ad_vocem_time = $3
+            WHERE id = $4"#,
+            format.name,
+            format.speakers_per_team,
+            format.ad_vocem_time,
+            format.id
+        )
+        .execute(&mut *transaction)
+        .await?;
+        if speeches_replaced {
+            query!(
+                "DELETE FROM debate_format_speeches WHERE debate_format_id = $1",
+                format.id
+            )
+            .execute(&mut *transaction)
+            .await?;
+            format.insert_speeches(&mut transaction).await?;
+        }
+        transaction.commit().await?;
+        Ok(format)
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        match query!("DELETE FROM debate_formats WHERE id = $1", self.id)
+            .execute(pool)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("Error deleting a debate format with id {}: {e}", self.id);
+                Err(e)?
+            }
+        }
+    }
+
+    /// Ensures that a format to be used within a tournament belongs to it
+    pub async fn validate_assignment(
+        debate_format_id: Option<Uuid>,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        let Some(debate_format_id) = debate_format_id else {
+            return Ok(());
+        };
+        let format_is_from_tournament = query!(
+            "SELECT EXISTS(SELECT 1 FROM debate_formats WHERE id = $1 AND tournament_id = $2)",
+            debate_format_id,
+            tournament_id
+        )
+        .fetch_one(pool)
+        .await?
+        .exists
+        .unwrap_or(false);
+
+        match format_is_from_tournament {
+            true => Ok(()),
+            false => Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The debate format must be from the same tournament".to_owned(),
+            }),
+        }
+    }
+
+    pub fn is_valid_speaker_position(&self, position: i32) -> bool {
+        (1..=self.speakers_per_team).contains(&position)
+    }
+
+    /// Positions taken by teams in debates of this format, in the order of precedence.
+    /// Formats speaking from British Parliamentary positions are four-team formats.
+    pub fn positions(&self) -> &'static [Side] {
+        match self
+            .speeches
+            .iter()
+            .any(|speech| Side::FOUR_TEAM.contains(&speech.side))
+        {
+            true => &Side::FOUR_TEAM,
+            false => &Side::TWO_TEAM,
+        }
+    }
+
+    pub fn teams_per_debate(&self) -> usize {
+        self.positions().len()
+    }
+
+    /// Number of teams meeting in a debate of the format chosen by a tournament,
+    /// without loading the whole format
+    pub async fn get_teams_per_debate<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<usize, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let four_team_sides: Vec<String> =
+            Side::FOUR_TEAM.iter().map(Side::to_string).collect();
+        let is_four_team = query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM debate_format_speeches s
+                JOIN tournaments t ON t.debate_format_id = s.debate_format_id
+                WHERE t.id = $1 AND s.side = ANY($2)
+            )"#,
+            tournament_id,
+            &four_team_sides
+        )
+        .fetch_one(executor)
+        .await?
+        .exists
+        .unwrap_or(false);
+
+        match is_four_team {
+            true => Ok(Side::FOUR_TEAM.len()),
+            false => Ok(Side::TWO_TEAM.len()),
+        }
+    }
+
+    async fn insert_speeches(
+        &self,
+        transaction: &mut sqlx::Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        for (speech_order, speech) in (1..).zip(&self.speeches) {
+            query!(
+                r#"INSERT INTO debate_format_speeches
+                (debate_format_id, speech_order, side, speaker_position, duration,
+                start_protected_time, end_protected_time, is_reply, allows_ad_vocem)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#,
+                self.id,
+                speech_order,
+                speech.side.to_string(),
+                speech.speaker_position,
+                speech.duration,
+                speech.start_protected_time,
+                speech.end_protected_time,
+                speech.is_reply,
+                speech.allows_ad_vocem
+            )
+            .execute(&mut **transaction)
+            .await?;
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), OmniError> {
+        let invalid = |message: &str| {
+            Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: message.to_owned(),
+            })
+        };
+        if self.name.trim().is_empty() {
+            return invalid("Debate format name must not be empty");
+        }
+        if self.speakers_per_team < 1 {
+            return invalid("speakers_per_team must be positive");
+        }
+        if self.ad_vocem_time < 0 {
+            return invalid("ad_vocem_time must not be negative");
+        }
+        if self.speeches.is_empty() {
+            return invalid("Debate format must define at least one speech");
+        }
+        let positions = self.positions();
+        if self
+            .speeches
+            .iter()
+            .any(|speech| !positions.contains(&speech.side))
+        {
+            return invalid("Speeches must not mix two-team and four-team positions");
+        }
+        if positions.len() == Side::FOUR_TEAM.len()
+            && positions
+                .iter()
+                .any(|side| !self.speeches.iter().any(|speech| speech.side == *side))
+        {
+            return invalid("Every team of a four-team format must give a speech");
+        }
+        for speech in &self.speeches {
+            if !self.is_valid_speaker_position(speech.speaker_position) {
+                return invalid(&format!(
+                    "Speaker positions must be in range of 1-{}",
+                    self.speakers_per_team
+                ));
+            }
+            if speech.duration <= 0 {
+                return invalid("Speech duration must be positive");
+            }
+            if speech.start_protected_time < 0
+                || speech.end_protected_time < 0
+                || speech.start_protected_time + speech.end_protected_time
+                    > speech.duration
+            {
+                return invalid("Protected time must fit within the speech");
+            }
+            if speech.allows_ad_vocem && self.ad_vocem_time == 0 {
+                return invalid("Speeches allow ad vocem, but ad_vocem_time is zero");
+            }
+        }
+        Ok(())
+    }
+
+    /// Speakers of both sides take turns, starting with the Proposition.
+    /// Each speech can be followed by an ad vocem.
+    pub fn oxford(
+        tournament_id: Uuid,
+        speech_time: i32,
+        start_protected_time: i32,
+        end_protected_time: i32,
+        ad_vocem_time: i32,
+    ) -> DebateFormat {
+        let speakers_per_team = 4;
+        DebateFormat {
+            id: Uuid::now_v7(),
+            tournament_id,
+            name: "Oxford".to_owned(),
+            speakers_per_team,
+            ad_vocem_time,
+            speeches: (1..=speakers_per_team)
+                .flat_map(|speaker_position| {
+                    [Side::Proposition, Side::Opposition].map(|side| SpeechDefinition {
+                        side,
+                        speaker_position,
+                        duration: speech_time,
+                        start_protected_time,
+                        end_protected_time,
+                        is_reply: false,
+                        allows_ad_vocem: ad_vocem_time > 0,
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Eight-minute speeches with the first and the last minute protected,
+    /// then four-minute replies by the first speakers, the Opposition replying first.
+    /// Points of information are not offered during replies.
+    pub fn wsdc(tournament_id: Uuid) -> DebateFormat {
+        DebateFormat {
+            name: "WSDC".to_owned(),
+            ..with_replies(tournament_id, 480, (60, 60), 240)
+        }
+    }
+
+    /// Eight-minute speeches then four-minute replies by the first speakers,
+    /// the Opposition replying first. Points of information are not offered at all.
+    pub fn australs(tournament_id: Uuid) -> DebateFormat {
+        DebateFormat {
+            name: "Australs".to_owned(),
+            ..with_replies(tournament_id, 480, (480, 0), 240)
+        }
+    }
+
+    /// Seven-minute speeches with the first and the last minute protected.
+    /// Opening teams speak first, the Government and the Opposition taking turns,
+    /// followed by closing teams.
+    pub fn british_parliamentary(tournament_id: Uuid) -> DebateFormat {
+        let speakers_per_team = 2;
+        let benches = [
+            [Side::OpeningGovernment, Side::OpeningOpposition],
+            [Side::ClosingGovernment, Side::ClosingOpposition],
+        ];
+        DebateFormat {
+            id: Uuid::now_v7(),
+            tournament_id,
+            name: "BP".to_owned(),
+            speakers_per_team,
+            ad_vocem_time: 0,
+            speeches: benches
+                .into_iter()
+                .flat_map(|sides| {
+                    (1..=speakers_per_team).flat_map(move |speaker_position| {
+                        sides.map(|side| SpeechDefinition {
+                            side,
+                            speaker_position,
+                            duration: 420,
+                            start_protected_time: 60,
+                            end_protected_time: 60,
+                            is_reply: false,
+                            allows_ad_vocem: false,
+                        })
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Three speakers of both sides take turns, starting with the Proposition.
+/// Reply speeches are fully protected.
+fn with_replies(
+    tournament_id: Uuid,
+    speech_time: i32,
+    (start_protected_time, end_protected_time): (i32, i32),
+    reply_time: i32,
+) -> DebateFormat {
+    let speakers_per_team = 3;
+    let mut speeches: Vec<SpeechDefinition> = (1..=speakers_per_team)
+        .flat_map(|speaker_position| {
+            [Side::Proposition, Side::Opposition].map(|side| SpeechDefinition {
+                side,
+                speaker_position,
+                duration: speech_time,
+                start_protected_time,
+                end_protected_time,
+                is_reply: false,
+                allows_ad_vocem: false,
+            })
+        })
+        .collect();
+    for side in [Side::Opposition, Side::Proposition] {
+        speeches.push(SpeechDefinition {
+            side,
+            speaker_position: 1,
+            duration: reply_time,
+            start_protected_time: reply_time,
+            end_protected_time: 0,
+            is_reply: true,
+            allows_ad_vocem: false,
+        });
+    }
+    DebateFormat {
+        id: Uuid::now_v7(),
+        tournament_id,
+        name: String::new(),
+        speakers_per_team,
+        ad_vocem_time: 0,
+        speeches,
+    }
+}
+
+impl Side {
+    pub const TWO_TEAM: [Side; 2] = [Side::Proposition, Side::Opposition];
+    pub const FOUR_TEAM: [Side; 4] = [
+        Side::OpeningGovernment,
+        Side::OpeningOpposition,
+        Side::ClosingGovernment,
+        Side::ClosingOpposition,
+    ];
+
+    /// Returns the position facing this one on the other bench
+    pub fn other(self) -> Side {
+        match self {
+            Side::Proposition => Side::Opposition,
+            Side::Opposition => Side::Proposition,
+            Side::OpeningGovernment => Side::OpeningOpposition,
+            Side::OpeningOpposition => Side::OpeningGovernment,
+            Side::ClosingGovernment => Side::ClosingOpposition,
+            Side::ClosingOpposition => Side::ClosingGovernment,
+        }
+    }
+
+    pub fn from_is_proposition(is_proposition: bool) -> Side {
+        match is_proposition {
+            true => Side::Proposition,
+            false => Side::Opposition,
+        }
+    }
+
+    /// True for the Proposition and both Government positions
+    pub fn is_government(self) -> bool {
+        matches!(
+            self,
+            Side::Proposition | Side::OpeningGovernment | Side::ClosingGovernment
+        )
+    }
+
+    /// True for the Proposition, false for the Opposition.
+    /// Empty for positions of four-team debates.
+    pub fn is_proposition(self) -> Option<bool> {
+        match self {
+            Side::Proposition => Some(true),
+            Side::Opposition => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Side::Proposition => write!(f, "Proposition"),
+            Side::Opposition => write!(f, "Opposition"),
+            Side::OpeningGovernment => write!(f, "OpeningGovernment"),
+            Side::OpeningOpposition => write!(f, "OpeningOpposition"),
+            Side::ClosingGovernment => write!(f, "ClosingGovernment"),
+            Side::ClosingOpposition => write!(f, "ClosingOpposition"),
+        }
+    }
+}
+
+impl TryFrom<String> for Side {
+    type Error = OmniError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Proposition" => Ok(Side::Proposition),
+            "Opposition" => Ok(Side::Opposition),
+            "OpeningGovernment" => Ok(Side::OpeningGovernment),
+            "OpeningOpposition" => Ok(Side::OpeningOpposition),
+            "ClosingGovernment" => Ok(Side::ClosingGovernment),
+            "ClosingOpposition" => Ok(Side::ClosingOpposition),
+            _ => {
+                error!("Unknown debate side: {value}");
+                Err(OmniError::InternalServerError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{DebateFormat, Side};
+
+    #[test]
+    fn presets_should_be_valid() {
+        let tournament_id = Uuid::now_v7();
+        for format in [
+            DebateFormat::oxford(tournament_id, 300, 0, 30, 60),
+            DebateFormat::wsdc(tournament_id),
+            DebateFormat::australs(tournament_id),
+            DebateFormat::british_parliamentary(tournament_id),
+        ] {
+            assert!(format.validate().is_ok(), "{} is invalid", format.name);
+        }
+    }
+
+    #[test]
+    fn replies_should_follow_substantive_speeches() {
+        let format = DebateFormat::wsdc(Uuid::now_v7());
+        let replies: Vec<(Side, bool)> = format.speeches[6..]
+            .iter()
+            .map(|speech| (speech.side, speech.is_reply))
+            .collect();
+
+        assert_eq!(format.speeches.len(), 8);
+        assert!(format.speeches[..6].iter().all(|speech| !speech.is_reply));
+        assert_eq!(
+            replies,
+            vec![(Side::Opposition, true), (Side::Proposition, true)]
+        );
+    }
+
+    #[test]
+    fn speaker_positions_should_follow_the_speaker_count() {
+        let mut format = DebateFormat::australs(Uuid::now_v7());
+        assert!(format.is_valid_speaker_position(3));
+        assert!(!format.is_valid_speaker_position(4));
+
+        format.speeches[0].speaker_position = 4;
+        assert!(format.validate().is_err());
+    }
+
+    #[test]
+    fn british_parliamentary_should_be_a_four_team_format() {
+        let mut format = DebateFormat::british_parliamentary(Uuid::now_v7());
+        let opening: Vec<Side> = format.speeches[..4]
+            .iter()
+            .map(|speech| speech.side)
+            .collect();
+
+        assert_eq!(format.teams_per_debate(), 4);
+        assert_eq!(DebateFormat::wsdc(Uuid::now_v7()).teams_per_debate(), 2);
+        assert_eq!(
+            opening,
+            vec![
+                Side::OpeningGovernment,
+                Side::OpeningOpposition,
+                Side::OpeningGovernment,
+                Side::OpeningOpposition
+            ]
+        );
+
+        format.speeches[0].side = Side::Proposition;
+        assert!(format.validate().is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Executor, Pool, Postgres, Transaction};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        affiliations::Affiliation, debates::Debate, location_travel_times::TravelTimes,
+        roles::Role, room_bookings::RoomBooking, rounds::Round,
+    },
+    users::User,
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+/// Places a Judge on the panel of a debate.
+/// Only panel members can rule the debate.
+/// A Judge can sit on at most one panel per round
+/// and never on a debate attended by a team they're affiliated with.
+pub struct DebateJudgeAssignment {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    pub judge_user_id: Uuid,
+    pub debate_id: Uuid,
+}
+
+impl DebateJudgeAssignment {
+    /// Returns panels of all debates within a tournament
+    pub async fn get_all<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<DebateJudgeAssignment>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let assignments = query_as!(
+            DebateJudgeAssignment,
+            r#"
+            SELECT a.id, a.judge_user_id, a.debate_id
+            FROM debate_judge_assignments a
+            JOIN debates d ON d.id = a.debate_id
+            WHERE d.tournament_id = $1
+            "#,
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(assignments)
+    }
+
+    pub async fn get_by_debate_id<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<DebateJudgeAssignment>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let assignments = query_as!(
+            DebateJudgeAssignment,
+            "SELECT * FROM debate_judge_assignments WHERE debate_id = $1",
+            debate_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(assignments)
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateJudgeAssignment, OmniError> {
+        let assignment = query_as!(
+            DebateJudgeAssignment,
+            "SELECT * FROM debate_judge_assignments WHERE id = $1",
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(assignment)
+    }
+
+    /// Validates the assignment against the debate and saves it
+    pub async fn post(
+        assignment: DebateJudgeAssignment,
+        debate: &Debate,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateJudgeAssignment, OmniError> {
+        let mut transaction = pool.begin().await?;
+        assignment.validate(debate, &mut transaction).await?;
+        let assignment = query_as!(
+            DebateJudgeAssignment,
+            r#"INSERT INTO debate_judge_assignments(id, judge_user_id, debate_id)
+            VALUES ($1, $2, $3) RETURNING id, judge_user_id, debate_id"#,
+            assignment.id,
+            assignment.judge_user_id,
+            assignment.debate_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+        Debate::follow_verdicts(assignment.debate_id, &mut *transaction).await?;
+        transaction.commit().await?;
+
+        Ok(assignment)
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        query!(
+            "DELETE FROM debate_judge_assignments WHERE id = $1",
+            self.id
+        )
+        .execute(pool)
+        .await?;
+        Debate::follow_verdicts(self.debate_id, pool).await?;
+
+        Ok(())
+    }
+
+    /// Places a Judge on the panel of every debate of the round that has none yet.
+    /// Judges who do not sit on any panel of the round are picked in order,
+    /// skipping Judges affiliated with a team of the debate.
+    /// Judges are kept in the location of their debate of the previous round
+    /// where possible, and are never sent to a location they cannot reach
+    /// in the time between the rounds.
+    /// Fails without placing any Judge if there are not enough of them.
+    pub async fn allocate(
+        round: &Round,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<DebateJudgeAssignment>, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let tournament_id = query!(
+            "SELECT tournament_id FROM phases WHERE id = $1",
+            round.phase_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .tournament_id;
+        let travel_times = TravelTimes::get(tournament_id, &mut *transaction).await?;
+        let previous_locations =
+            RoomBooking::get_previous_locations_of_judges(round, &mut *transaction)
+                .await?;
+        let mut free_judge_ids: Vec<Uuid> = query!(
+            r#"SELECT user_id FROM roles
+            WHERE tournament_id = $1 AND 'Judge' = ANY(roles)
+            AND user_id NOT IN (
+                SELECT a.judge_user_id FROM debate_judge_assignments a
+                JOIN debates d ON d.id = a.debate_id
+                WHERE d.round_id = $2
+            )
+            ORDER BY user_id"#,
+            tournament_id,
+            round.id
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|record| record.user_id)
+        .collect();
+        let affiliations: HashSet<(Uuid, Uuid)> = query!(
+            r#"SELECT j.judge_user_id, a.debate_id
+            FROM judge_team_assignments j
+            JOIN debate_teams_assignments a ON a.team_id = j.team_id
+            JOIN debates d ON d.id = a.debate_id
+            WHERE d.round_id = $1"#,
+            round.id
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|record| (record.judge_user_id, record.debate_id))
+        .collect();
+        let debates = query!(
+            r#"SELECT debates.id, rooms.location_id AS "location_id?"
+            FROM debates
+            LEFT JOIN rooms ON rooms.id = debates.room_id
+            WHERE debates.round_id = $1
+            AND debates.status NOT IN ('Concluded', 'Cancelled')
+            AND NOT EXISTS (
+                SELECT 1 FROM debate_judge_assignments a WHERE a.debate_id = debates.id
+            )
+            ORDER BY debates.id"#,
+            round.id
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+
+        let mut assignments = vec![];
+        for debate in debates {
+            let unaffiliated_judge_ids: Vec<Uuid> = free_judge_ids
+                .iter()
+                .copied()
+                .filter(|judge_id| !affiliations.contains(&(*judge_id, debate.id)))
+                .collect();
+            let judge_id = unaffiliated_judge_ids
+                .iter()
+                .copied()
+                .filter(|judge_id| {
+                    match (previous_locations.get(judge_id), debate.location_id) {
+                        (Some(previous), Some(location_id)) => {
+                            previous.can_reach(location_id, &travel_times)
+                        }
+                        _ => true,
+                    }
+                })
+                .min_by_key(|judge_id| {
+                    match (previous_locations.get(judge_id), debate.location_id) {
+                        (Some(previous), Some(location_id)) => {
+                            previous.location_id != location_id
+                        }
+                        _ => true,
+                    }
+                });
+            let Some(judge_id) = judge_id else {
+                let message = match unaffiliated_judge_ids.is_empty() {
+                    true => {
+                        "There are not enough free Judges for every debate of the round"
+                    }
+                    false => {
+                        "No free Judge can reach a debate in time, \
+                        given the travel times between locations"
+                    }
+                };
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::CONFLICT,
+                    message: message.to_owned(),
+                });
+            };
+            free_judge_ids.retain(|id| *id != judge_id);
+            let assignment = query_as!(
+                DebateJudgeAssignment,
+                r#"INSERT INTO debate_judge_assignments(id, judge_user_id, debate_id)
+                VALUES ($1, $2, $3) RETURNING id, judge_user_id, debate_id"#,
+                Uuid::now_v7(),
+                judge_id,
+                debate.id
+            )
+            .fetch_one(&mut *transaction)
+            .await?;
+            assignments.push(assignment);
+        }
+        transaction.commit().await?;
+
+        Ok(assignments)
+    }
+
+    /// Checks whether the Judge sits on the panel of the debate
+    pub async fn judge_is_on_panel(
+        judge_user_id: Uuid,
+        debate_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<bool, OmniError> {
+        match query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM debate_judge_assignments
+                WHERE judge_user_id = $1 AND debate_id = $2
+            )"#,
+            judge_user_id,
+            debate_id
+        )
+        .fetch_one(pool)
+        .await
+        {
+            Ok(result) => Ok(result.exists.unwrap()),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    /// Checks that the Judge may rule the debate and sits on no other panel of its round.
+    /// The debate and the Judge are locked until the end of the transaction,
+    /// so that concurrent assignments of either are checked one after another.
+    pub async fn validate(
+        &self,
+        debate: &Debate,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        query!(
+            "SELECT id FROM debates WHERE id = $1 FOR NO KEY UPDATE",
+            debate.id
+        )
+        .fetch_one(&mut **transaction)
+        .await?;
+        query!(
+            "SELECT id FROM users WHERE id = $1 FOR NO KEY UPDATE",
+            self.judge_user_id
+        )
+        .fetch_optional(&mut **transaction)
+        .await?;
+
+        let judge = User::get_by_id(self.judge_user_id, &mut **transaction).await?;
+        if !judge
+            .has_role(Role::Judge, debate.tournament_id, &mut **transaction)
+            .await?
+        {
+            return Err(OmniError::NotAJudgeError);
+        }
+
+        if Affiliation::exists_between_judge_and_debate(
+            judge.id,
+            debate.id,
+            &mut **transaction,
+        )
+        .await?
+        {
+            return Err(OmniError::AffiliatedJudgeError);
+        }
+
+        if self
+            .judge_rules_elsewhere_in_round(debate, &mut **transaction)
+            .await?
+        {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "This Judge is already assigned to a debate in this round"
+                    .to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn judge_rules_elsewhere_in_round<'e, E>(
+        &self,
+        debate: &Debate,
+        executor: E,
+    ) -> Result<bool, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM debate_judge_assignments a
+                JOIN debates d ON d.id = a.debate_id
+                WHERE a.judge_user_id = $1 AND d.round_id = $2 AND a.id != $3
+            )"#,
+            self.judge_user_id,
+            debate.round_id,
+            self.id
+        )
+        .fetch_one(executor)
+        .await
+        {
+            Ok(result) => Ok(result.exists.unwrap()),
+            Err(e) => Err(e)?,
+        }
+    }
+}
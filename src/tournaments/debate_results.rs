@@ -0,0 +1,510 @@
+use std::{collections::HashMap, fmt};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query, PgConnection};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{omni_error::OmniError, tournaments::debate_formats::Side};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug, Default, PartialEq)]
+/// Decides the outcome of a debate in which the Judges' verdicts are split evenly.
+/// In four-team debates, it decides between teams with equal rank totals.
+pub enum VerdictTiePolicy {
+    /// The debate has no winner
+    #[default]
+    Tied,
+    /// The Proposition wins the debate.
+    /// Government teams are placed above Opposition teams.
+    Proposition,
+    /// The Opposition wins the debate.
+    /// Opposition teams are placed above Government teams.
+    Opposition,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+pub enum DebateResultStatus {
+    /// Not every Judge on the panel has submitted a verdict yet
+    Incomplete,
+    /// The verdicts are split evenly and the tie policy names no winner,
+    /// or teams of a four-team debate have rank totals the tie policy can't separate
+    Tied,
+    /// The debate has a winner
+    Decided,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// Outcome of a debate, computed from verdicts submitted by its panel
+pub struct DebateResult {
+    pub debate_id: Uuid,
+    pub status: DebateResultStatus,
+    /// Side that won the debate. Empty unless the result is decided.
+    pub proposition_won: Option<bool>,
+    /// Team that won the debate.
+    /// Empty unless the result is decided and the winning side has a team assigned.
+    pub winner_team_id: Option<Uuid>,
+    pub proposition_team_id: Option<Uuid>,
+    pub opposition_team_id: Option<Uuid>,
+    /// Number of Judges assigned to the debate
+    pub panel_size: i64,
+    /// Number of Judges on the panel favouring the Proposition
+    pub proposition_ballots: i64,
+    /// Number of Judges on the panel favouring the Opposition
+    pub opposition_ballots: i64,
+    /// Indicates whether every verdict favours the winner.
+    /// In four-team debates, whether every verdict ranks the teams the same way.
+    /// False for split decisions and debates without a winner.
+    pub is_unanimous: bool,
+    /// Places of the teams of a four-team debate, from the best to the worst.
+    /// Empty for two-team debates, until every Judge has ranked the teams
+    /// and while the ranking is tied.
+    pub placements: Vec<TeamPlacement>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// Final place of a team in a four-team debate
+pub struct TeamPlacement {
+    pub side: Side,
+    /// Team taking the position. Empty if no team is assigned to it.
+    pub team_id: Option<Uuid>,
+    /// 1 for the best team
+    pub rank: i32,
+    /// Points scored on the tab: 3 for the first place down to 0 for the last one
+    pub points: i32,
+}
+
+/// Ballot counts of a single debate, as stored in the database
+struct Ballots {
+    debate_id: Uuid,
+    proposition_team_id: Option<Uuid>,
+    opposition_team_id: Option<Uuid>,
+    panel_size: i64,
+    proposition_ballots: i64,
+    opposition_ballots: i64,
+    tie_policy: VerdictTiePolicy,
+    /// Number of verdicts ranking the teams of a four-team debate
+    ranked_ballots: i64,
+    rank_totals: Vec<RankTotal>,
+}
+
+/// Sum of ranks given to a position of a four-team debate by all ballots
+struct RankTotal {
+    side: Side,
+    team_id: Option<Uuid>,
+    rank_total: i64,
+}
+
+impl DebateResult {
+    /// Returns results of all debates within a tournament,
+    /// using the tie policy configured for the tournament
+    pub async fn get_all(
+        tournament_id: Uuid,
+        connection: &mut PgConnection,
+    ) -> Result<Vec<DebateResult>, OmniError> {
+        Ok(get_ballots(Some(tournament_id), None, connection)
+            .await?
+            .into_iter()
+            .map(DebateResult::from_ballots)
+            .collect())
+    }
+
+    /// Returns the result of a single debate,
+    /// using the tie policy configured for its tournament
+    pub async fn get_by_debate_id(
+        debate_id: Uuid,
+        connection: &mut PgConnection,
+    ) -> Result<DebateResult, OmniError> {
+        match get_ballots(None, Some(debate_id), connection).await?.pop() {
+            Some(ballots) => Ok(DebateResult::from_ballots(ballots)),
+            None => Err(OmniError::ResourceNotFoundError),
+        }
+    }
+
+    /// A debate is ruled once every Judge on its panel has submitted a verdict.
+    /// The side favoured by the majority of verdicts wins;
+    /// if the verdicts are split evenly, the tie policy decides.
+    fn from_ballots(ballots: Ballots) -> DebateResult {
+        if ballots.ranked_ballots > 0 {
+            return DebateResult::from_rankings(ballots);
+        }
+        let ballots_count = ballots.proposition_ballots + ballots.opposition_ballots;
+        let is_complete = ballots_count > 0 && ballots_count >= ballots.panel_size;
+        let proposition_won =
+            match ballots.proposition_ballots.cmp(&ballots.opposition_ballots) {
+                _ if !is_complete => None,
+                std::cmp::Ordering::Greater => Some(true),
+                std::cmp::Ordering::Less => Some(false),
+                std::cmp::Ordering::Equal => match ballots.tie_policy {
+                    VerdictTiePolicy::Tied => None,
+                    VerdictTiePolicy::Proposition => Some(true),
+                    VerdictTiePolicy::Opposition => Some(false),
+                },
+            };
+        let status = match (is_complete, proposition_won) {
+            (false, _) => DebateResultStatus::Incomplete,
+            (true, None) => DebateResultStatus::Tied,
+            (true, Some(_)) => DebateResultStatus::Decided,
+        };
+        let winner_team_id = match proposition_won {
+            Some(true) => ballots.proposition_team_id,
+            Some(false) => ballots.opposition_team_id,
+            None => None,
+        };
+        let is_unanimous = match proposition_won {
+            Some(true) => ballots.opposition_ballots == 0,
+            Some(false) => ballots.proposition_ballots == 0,
+            None => false,
+        };
+
+        DebateResult {
+            debate_id: ballots.debate_id,
+            status,
+            proposition_won,
+            winner_team_id,
+            proposition_team_id: ballots.proposition_team_id,
+            opposition_team_id: ballots.opposition_team_id,
+            panel_size: ballots.panel_size,
+            proposition_ballots: ballots.proposition_ballots,
+            opposition_ballots: ballots.opposition_ballots,
+            is_unanimous,
+            placements: vec![],
+        }
+    }
+
+    /// A four-team debate is ruled once every Judge on its panel has ranked the teams.
+    /// Teams are placed by the sum of their ranks, the lowest sum first.
+    /// Equal sums are settled by the tie policy; if it favours neither team,
+    /// the result is tied.
+    fn from_rankings(ballots: Ballots) -> DebateResult {
+        let is_complete = ballots.ranked_ballots >= ballots.panel_size;
+        let tie_policy = ballots.tie_policy;
+        let mut totals = ballots.rank_totals;
+        totals.sort_by_key(|total| (total.rank_total, !tie_policy.favours(total.side)));
+        let is_tied = totals.windows(2).any(|pair| {
+            pair[0].rank_total == pair[1].rank_total
+                && tie_policy.favours(pair[0].side) == tie_policy.favours(pair[1].side)
+        });
+        let status = match (is_complete, is_tied) {
+            (false, _) => DebateResultStatus::Incomplete,
+            (true, true) => DebateResultStatus::Tied,
+            (true, false) => DebateResultStatus::Decided,
+        };
+        let placements: Vec<TeamPlacement> = match status {
+            DebateResultStatus::Decided => totals
+                .iter()
+                .enumerate()
+                .map(|(index, total)| TeamPlacement {
+                    side: total.side,
+                    team_id: total.team_id,
+                    rank: index as i32 + 1,
+                    points: (totals.len() - index - 1) as i32,
+                })
+                .collect(),
+            _ => vec![],
+        };
+        // Every Judge gave a team the same rank only if its total is that rank times
+        // the number of ballots; this holds for every team only if all ballots agree
+        let is_unanimous = is_complete
+            && totals
+                .iter()
+                .zip(1..)
+                .all(|(total, rank)| total.rank_total == rank * ballots.ranked_ballots);
+
+        DebateResult {
+            debate_id: ballots.debate_id,
+            status,
+            proposition_won: None,
+            winner_team_id: placements.first().and_then(|placement| placement.team_id),
+            proposition_team_id: None,
+            opposition_team_id: None,
+            panel_size: ballots.panel_size,
+            proposition_ballots: 0,
+            opposition_ballots: 0,
+            is_unanimous,
+            placements,
+        }
+    }
+
+    /// Teams that took part in the debate
+    pub fn team_ids(&self) -> Vec<Uuid> {
+        let mut team_ids: Vec<Uuid> = [self.proposition_team_id, self.opposition_team_id]
+            .into_iter()
+            .flatten()
+            .collect();
+        team_ids.extend(
+            self.placements
+                .iter()
+                .filter_map(|placement| placement.team_id),
+        );
+        team_ids
+    }
+}
+
+/// Counts ballots of debates of a tournament or of a single debate,
+/// whichever is given. Only one verdict of every Judge on the panel counts.
+async fn get_ballots(
+    tournament_id: Option<Uuid>,
+    debate_id: Option<Uuid>,
+    connection: &mut PgConnection,
+) -> Result<Vec<Ballots>, OmniError> {
+    let records = query!(
+        r#"
+        SELECT
+            d.id AS debate_id,
+            (SELECT team_id FROM debate_teams_assignments
+                WHERE debate_id = d.id AND is_proposition = true
+                ORDER BY id LIMIT 1) AS proposition_team_id,
+            (SELECT team_id FROM debate_teams_assignments
+                WHERE debate_id = d.id AND is_proposition = false
+                ORDER BY id LIMIT 1) AS opposition_team_id,
+            (SELECT COUNT(DISTINCT judge_user_id) FROM debate_judge_assignments
+                WHERE debate_id = d.id)
+                AS "panel_size!",
+            (SELECT COUNT(*) FROM panel_verdicts
+                WHERE debate_id = d.id AND proposition_won)
+                AS "proposition_ballots!",
+            (SELECT COUNT(*) FROM panel_verdicts
+                WHERE debate_id = d.id AND NOT proposition_won)
+                AS "opposition_ballots!",
+            (SELECT COUNT(*) FROM panel_verdicts
+                WHERE debate_id = d.id AND proposition_won IS NULL)
+                AS "ranked_ballots!",
+            t.verdict_tie_policy
+        FROM debates d
+        JOIN tournaments t ON t.id = d.tournament_id
+        WHERE ($1::UUID IS NULL OR d.tournament_id = $1)
+            AND ($2::UUID IS NULL OR d.id = $2)
+        ORDER BY d.id
+        "#,
+        tournament_id,
+        debate_id
+    )
+    .fetch_all(&mut *connection)
+    .await?;
+    let mut rank_totals: HashMap<Uuid, Vec<RankTotal>> = HashMap::new();
+    for total in query!(
+        r#"
+        SELECT
+            d.id AS debate_id,
+            r.side,
+            SUM(r.rank) AS "rank_total!",
+            (SELECT a.team_id FROM debate_teams_assignments a
+                WHERE a.debate_id = d.id AND a.side = r.side
+                ORDER BY a.id LIMIT 1) AS team_id
+        FROM verdict_rankings r
+        JOIN panel_verdicts v ON v.id = r.verdict_id
+        JOIN debates d ON d.id = v.debate_id
+        WHERE ($1::UUID IS NULL OR d.tournament_id = $1)
+            AND ($2::UUID IS NULL OR d.id = $2)
+        GROUP BY d.id, r.side
+        "#,
+        tournament_id,
+        debate_id
+    )
+    .fetch_all(&mut *connection)
+    .await?
+    {
+        rank_totals
+            .entry(total.debate_id)
+            .or_default()
+            .push(RankTotal {
+                side: Side::try_from(total.side)?,
+                team_id: total.team_id,
+                rank_total: total.rank_total,
+            });
+    }
+
+    Ok(records
+        .into_iter()
+        .map(|record| Ballots {
+            debate_id: record.debate_id,
+            proposition_team_id: record.proposition_team_id,
+            opposition_team_id: record.opposition_team_id,
+            panel_size: record.panel_size,
+            proposition_ballots: record.proposition_ballots,
+            opposition_ballots: record.opposition_ballots,
+            tie_policy: record.verdict_tie_policy.into(),
+            ranked_ballots: record.ranked_ballots,
+            rank_totals: rank_totals.remove(&record.debate_id).unwrap_or_default(),
+        })
+        .collect())
+}
+
+impl VerdictTiePolicy {
+    /// Indicates whether the policy places a team taking the side
+    /// above a team of the other bench with the same rank total
+    fn favours(self, side: Side) -> bool {
+        match self {
+            VerdictTiePolicy::Tied => false,
+            VerdictTiePolicy::Proposition => side.is_government(),
+            VerdictTiePolicy::Opposition => !side.is_government(),
+        }
+    }
+}
+
+impl fmt::Display for VerdictTiePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VerdictTiePolicy::Tied => write!(f, "Tied"),
+            VerdictTiePolicy::Proposition => write!(f, "Proposition"),
+            VerdictTiePolicy::Opposition => write!(f, "Opposition"),
+        }
+    }
+}
+
+impl From<String> for VerdictTiePolicy {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Proposition" => VerdictTiePolicy::Proposition,
+            "Opposition" => VerdictTiePolicy::Opposition,
+            _ => VerdictTiePolicy::Tied,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{Ballots, DebateResult, DebateResultStatus, RankTotal, VerdictTiePolicy};
+    use crate::tournaments::debate_formats::Side;
+
+    fn result(
+        panel_size: i64,
+        proposition_ballots: i64,
+        opposition_ballots: i64,
+        tie_policy: VerdictTiePolicy,
+    ) -> DebateResult {
+        DebateResult::from_ballots(Ballots {
+            debate_id: Uuid::now_v7(),
+            proposition_team_id: Some(Uuid::now_v7()),
+            opposition_team_id: Some(Uuid::now_v7()),
+            panel_size,
+            proposition_ballots,
+            opposition_ballots,
+            tie_policy,
+            ranked_ballots: 0,
+            rank_totals: vec![],
+        })
+    }
+
+    fn ranked_result(
+        panel_size: i64,
+        ranked_ballots: i64,
+        rank_totals: [i64; 4],
+        tie_policy: VerdictTiePolicy,
+    ) -> DebateResult {
+        DebateResult::from_ballots(Ballots {
+            debate_id: Uuid::now_v7(),
+            proposition_team_id: None,
+            opposition_team_id: None,
+            panel_size,
+            proposition_ballots: 0,
+            opposition_ballots: 0,
+            tie_policy,
+            ranked_ballots,
+            rank_totals: Side::FOUR_TEAM
+                .into_iter()
+                .zip(rank_totals)
+                .map(|(side, rank_total)| RankTotal {
+                    side,
+                    team_id: Some(Uuid::now_v7()),
+                    rank_total,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn result_should_be_incomplete_until_every_judge_votes() {
+        let missing_vote = result(3, 2, 0, VerdictTiePolicy::Tied);
+        assert_eq!(missing_vote.status, DebateResultStatus::Incomplete);
+        assert_eq!(missing_vote.proposition_won, None);
+        assert_eq!(missing_vote.winner_team_id, None);
+
+        let no_panel = result(0, 0, 0, VerdictTiePolicy::Proposition);
+        assert_eq!(no_panel.status, DebateResultStatus::Incomplete);
+    }
+
+    #[test]
+    fn majority_should_decide_the_result() {
+        let split = result(3, 1, 2, VerdictTiePolicy::Tied);
+        assert_eq!(split.status, DebateResultStatus::Decided);
+        assert_eq!(split.proposition_won, Some(false));
+        assert_eq!(split.winner_team_id, split.opposition_team_id);
+        assert!(!split.is_unanimous);
+
+        let unanimous = result(3, 3, 0, VerdictTiePolicy::Tied);
+        assert_eq!(unanimous.proposition_won, Some(true));
+        assert_eq!(unanimous.winner_team_id, unanimous.proposition_team_id);
+        assert!(unanimous.is_unanimous);
+    }
+
+    #[test]
+    fn tie_policy_should_decide_level_votes() {
+        let tied = result(2, 1, 1, VerdictTiePolicy::Tied);
+        assert_eq!(tied.status, DebateResultStatus::Tied);
+        assert_eq!(tied.winner_team_id, None);
+
+        for (policy, proposition_won) in [
+            (VerdictTiePolicy::Proposition, true),
+            (VerdictTiePolicy::Opposition, false),
+        ] {
+            let broken_tie = result(2, 1, 1, policy);
+            assert_eq!(broken_tie.status, DebateResultStatus::Decided);
+            assert_eq!(broken_tie.proposition_won, Some(proposition_won));
+            assert!(!broken_tie.is_unanimous);
+        }
+    }
+
+    #[test]
+    fn four_teams_should_be_placed_by_their_rank_totals() {
+        let incomplete = ranked_result(3, 2, [3, 5, 6, 6], VerdictTiePolicy::Tied);
+        assert_eq!(incomplete.status, DebateResultStatus::Incomplete);
+        assert!(incomplete.placements.is_empty());
+
+        // Closing Government ranked first by every Judge
+        let unanimous = ranked_result(3, 3, [6, 9, 3, 12], VerdictTiePolicy::Tied);
+        let order: Vec<(Side, i32, i32)> = unanimous
+            .placements
+            .iter()
+            .map(|placement| (placement.side, placement.rank, placement.points))
+            .collect();
+        assert_eq!(unanimous.status, DebateResultStatus::Decided);
+        assert_eq!(unanimous.winner_team_id, unanimous.placements[0].team_id);
+        assert!(unanimous.is_unanimous);
+        assert_eq!(
+            order,
+            vec![
+                (Side::ClosingGovernment, 1, 3),
+                (Side::OpeningGovernment, 2, 2),
+                (Side::OpeningOpposition, 3, 1),
+                (Side::ClosingOpposition, 4, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn tie_policy_should_decide_equal_rank_totals() {
+        // Opening teams tied on 4
+        let tied = ranked_result(2, 2, [4, 4, 5, 7], VerdictTiePolicy::Tied);
+        assert_eq!(tied.status, DebateResultStatus::Tied);
+        assert_eq!(tied.winner_team_id, None);
+        assert!(tied.placements.is_empty());
+
+        for (policy, first) in [
+            (VerdictTiePolicy::Proposition, Side::OpeningGovernment),
+            (VerdictTiePolicy::Opposition, Side::OpeningOpposition),
+        ] {
+            let broken_tie = ranked_result(2, 2, [4, 4, 5, 7], policy);
+            assert_eq!(broken_tie.status, DebateResultStatus::Decided);
+            assert!(!broken_tie.is_unanimous);
+            assert_eq!(broken_tie.placements[0].side, first);
+            assert_eq!(broken_tie.placements[1].side, first.other());
+        }
+
+        // Both Government teams tied on 5, which no policy can separate
+        let same_bench = ranked_result(2, 2, [5, 3, 5, 7], VerdictTiePolicy::Proposition);
+        assert_eq!(same_bench.status, DebateResultStatus::Tied);
+    }
+}
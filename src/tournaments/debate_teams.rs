@@ -0,0 +1,380 @@
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Executor, Pool, Postgres, Transaction};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_formats::{DebateFormat, Side},
+        debates::Debate,
+        teams::Team,
+        Tournament,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+/// Assigns a team to a debate.
+/// Every position of the debate format (e.g. the Proposition and the Opposition)
+/// is taken by exactly one team from the same tournament.
+/// A team can debate at most once per round.
+/// The side may be left empty until it is drawn.
+pub struct DebateTeamAssignment {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    pub team_id: Uuid,
+    pub debate_id: Uuid,
+    /// True for the Proposition, false for the Opposition.
+    /// Empty, if the sides have not been drawn yet or the debate has four teams.
+    pub is_proposition: Option<bool>,
+    /// Position of the team in the debate. Can be given instead of is_proposition.
+    /// Empty, if the sides have not been drawn yet.
+    #[serde(default)]
+    pub side: Option<Side>,
+}
+
+#[derive(Deserialize, ToSchema)]
+/// Can be used to change the team or the side of an existing assignment
+pub struct DebateTeamAssignmentPatch {
+    pub team_id: Option<Uuid>,
+    pub is_proposition: Option<bool>,
+    pub side: Option<Side>,
+}
+
+/// Team assignment as stored in the database
+struct DebateTeamAssignmentRecord {
+    id: Uuid,
+    team_id: Uuid,
+    debate_id: Uuid,
+    is_proposition: Option<bool>,
+    side: Option<String>,
+}
+
+impl DebateTeamAssignment {
+    /// Returns assignments of all debates within a tournament
+    pub async fn get_all<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<DebateTeamAssignment>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query_as!(
+            DebateTeamAssignmentRecord,
+            r#"
+            SELECT a.id, a.team_id, a.debate_id, a.is_proposition, a.side
+            FROM debate_teams_assignments a
+            JOIN debates d ON d.id = a.debate_id
+            WHERE d.tournament_id = $1
+            "#,
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(DebateTeamAssignment::try_from)
+        .collect()
+    }
+
+    pub async fn get_by_debate_id<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<DebateTeamAssignment>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query_as!(
+            DebateTeamAssignmentRecord,
+            "SELECT * FROM debate_teams_assignments WHERE debate_id = $1",
+            debate_id
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(DebateTeamAssignment::try_from)
+        .collect()
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateTeamAssignment, OmniError> {
+        let assignment = query_as!(
+            DebateTeamAssignmentRecord,
+            "SELECT * FROM debate_teams_assignments WHERE id = $1",
+            id
+        )
+        .fetch_one(pool)
+        .await?;
+
+        DebateTeamAssignment::try_from(assignment)
+    }
+
+    /// Validates the assignment against the debate and saves it
+    pub async fn post(
+        assignment: DebateTeamAssignment,
+        debate: &Debate,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateTeamAssignment, OmniError> {
+        let tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+        let format = DebateFormat::get_for_debate(debate, &tournament, pool).await?;
+        let mut transaction = pool.begin().await?;
+        assignment
+            .validate(debate, &format, &mut transaction)
+            .await?;
+        let assignment =
+            Self::post_with_transaction(&mut transaction, assignment).await?;
+        transaction.commit().await?;
+        Ok(assignment)
+    }
+
+    pub async fn post_with_transaction(
+        transaction: &mut Transaction<'_, Postgres>,
+        assignment: DebateTeamAssignment,
+    ) -> Result<DebateTeamAssignment, OmniError> {
+        let assignment = assignment.with_resolved_side();
+        let record = query_as!(
+            DebateTeamAssignmentRecord,
+            r#"INSERT INTO debate_teams_assignments(id, team_id, debate_id, side)
+            VALUES ($1, $2, $3, $4) RETURNING id, team_id, debate_id, is_proposition, side"#,
+            assignment.id,
+            assignment.team_id,
+            assignment.debate_id,
+            assignment.side.map(|side| side.to_string())
+        )
+        .fetch_one(&mut **transaction)
+        .await?;
+
+        DebateTeamAssignment::try_from(record)
+    }
+
+    pub async fn patch(
+        self,
+        patch: DebateTeamAssignmentPatch,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateTeamAssignment, OmniError> {
+        let (is_proposition, side) = match (patch.is_proposition, patch.side) {
+            (None, None) => (self.is_proposition, self.side),
+            given => given,
+        };
+        let assignment = DebateTeamAssignment {
+            id: self.id,
+            team_id: patch.team_id.unwrap_or(self.team_id),
+            debate_id: self.debate_id,
+            is_proposition,
+            side,
+        };
+        let debate = Debate::get_by_id(assignment.debate_id, pool).await?;
+        let tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+        let format = DebateFormat::get_for_debate(&debate, &tournament, pool).await?;
+        let mut transaction = pool.begin().await?;
+        assignment
+            .validate(&debate, &format, &mut transaction)
+            .await?;
+
+        let assignment = assignment.with_resolved_side();
+        let updated = query_as!(
+            DebateTeamAssignmentRecord,
+            r#"UPDATE debate_teams_assignments SET team_id = $1, side = $2
+            WHERE id = $3 RETURNING id, team_id, debate_id, is_proposition, side"#,
+            assignment.team_id,
+            assignment.side.map(|side| side.to_string()),
+            assignment.id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        DebateTeamAssignment::try_from(updated)
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        query!(
+            "DELETE FROM debate_teams_assignments WHERE id = $1",
+            self.id
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Moves the team to another position of the debate
+    pub async fn assign_side_with_transaction(
+        &self,
+        side: Side,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        query!(
+            "UPDATE debate_teams_assignments SET side = $1 WHERE id = $2",
+            side.to_string(),
+            self.id
+        )
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Removes all teams from a debate, e.g. before a new draw is written into it
+    pub async fn delete_all_of_debate_with_transaction(
+        debate_id: Uuid,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        query!(
+            "DELETE FROM debate_teams_assignments WHERE debate_id = $1",
+            debate_id
+        )
+        .execute(&mut **transaction)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Checks the assignment against the debate, its format
+    /// and other assignments of the debate and its round.
+    /// The debate and the team are locked until the end of the transaction,
+    /// so that concurrent assignments to either are checked one after another.
+    pub async fn validate(
+        &self,
+        debate: &Debate,
+        format: &DebateFormat,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        query!(
+            "SELECT id FROM debates WHERE id = $1 FOR NO KEY UPDATE",
+            debate.id
+        )
+        .fetch_one(&mut **transaction)
+        .await?;
+        query!(
+            "SELECT id FROM teams WHERE id = $1 FOR NO KEY UPDATE",
+            self.team_id
+        )
+        .fetch_optional(&mut **transaction)
+        .await?;
+
+        let team = Team::get_by_id(self.team_id, &mut **transaction).await?;
+        if team.tournament_id != debate.tournament_id {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The team must be from the same tournament as the debate"
+                    .to_owned(),
+            });
+        }
+
+        if let (Some(is_proposition), Some(side)) = (self.is_proposition, self.side) {
+            if side.is_proposition() != Some(is_proposition) {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::BAD_REQUEST,
+                    message: format!("is_proposition contradicts the side {side}"),
+                });
+            }
+        }
+
+        let other_assignments: Vec<DebateTeamAssignment> =
+            DebateTeamAssignment::get_by_debate_id(debate.id, &mut **transaction)
+                .await?
+                .into_iter()
+                .filter(|assignment| assignment.id != self.id)
+                .collect();
+        if other_assignments.len() >= format.teams_per_debate() {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: format!(
+                    "A debate can only be attended by {} teams",
+                    format.teams_per_debate()
+                ),
+            });
+        }
+        if let Some(side) = self.clone().with_resolved_side().side {
+            if !format.positions().contains(&side) {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::CONFLICT,
+                    message: format!(
+                        "{side} is not a position in debates of the {} format",
+                        format.name
+                    ),
+                });
+            }
+            if other_assignments
+                .iter()
+                .any(|assignment| assignment.side == Some(side))
+            {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::CONFLICT,
+                    message: format!("This debate already has a {side} team"),
+                });
+            }
+        }
+
+        if self
+            .team_debates_elsewhere_in_round(debate, &mut **transaction)
+            .await?
+        {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "This team is already assigned to a debate in this round"
+                    .to_owned(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn team_debates_elsewhere_in_round<'e, E>(
+        &self,
+        debate: &Debate,
+        executor: E,
+    ) -> Result<bool, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM debate_teams_assignments a
+                JOIN debates d ON d.id = a.debate_id
+                WHERE a.team_id = $1 AND d.round_id = $2 AND a.id != $3
+            )"#,
+            self.team_id,
+            debate.round_id,
+            self.id
+        )
+        .fetch_one(executor)
+        .await
+        {
+            Ok(result) => Ok(result.exists.unwrap()),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    /// Fills in the side from is_proposition, which is only stored as the side.
+    /// The side takes precedence if both are given.
+    fn with_resolved_side(self) -> DebateTeamAssignment {
+        let side = self
+            .side
+            .or(self.is_proposition.map(Side::from_is_proposition));
+        DebateTeamAssignment {
+            is_proposition: side.and_then(Side::is_proposition),
+            side,
+            ..self
+        }
+    }
+}
+
+impl TryFrom<DebateTeamAssignmentRecord> for DebateTeamAssignment {
+    type Error = OmniError;
+
+    fn try_from(record: DebateTeamAssignmentRecord) -> Result<Self, Self::Error> {
+        Ok(DebateTeamAssignment {
+            id: record.id,
+            team_id: record.team_id,
+            debate_id: record.debate_id,
+            is_proposition: record.is_proposition,
+            side: record.side.map(Side::try_from).transpose()?,
+        })
+    }
+}
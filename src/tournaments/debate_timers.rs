@@ -0,0 +1,610 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use axum::http::StatusCode;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::broadcast,
+    time::{sleep_until, Instant},
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::omni_error::OmniError;
+
+use super::debate_formats::SpeechDefinition;
+
+/// Number of events a slow client can fall behind before missing some
+const EVENTS_CAPACITY: usize = 32;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Speeches of a debate as defined by its format, along with the beep settings
+/// of the tournament
+pub struct SpeechRules {
+    pub speeches: Vec<SpeechDefinition>,
+    /// In seconds
+    pub ad_vocem_time: i32,
+    pub beep_on_speech_end: bool,
+    pub beep_on_protected_time: bool,
+    pub visualize_protected_time: bool,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A single speech of a debate
+pub struct Speech {
+    #[serde(flatten)]
+    pub definition: SpeechDefinition,
+    /// Indicates whether the speech is an ad vocem reply to the preceding one.
+    /// The speaker of an ad vocem is chosen by their team, so its speaker_position is 0.
+    pub is_ad_vocem: bool,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+pub enum TimerStatus {
+    /// The current speech hasn't started yet
+    Waiting,
+    Running,
+    Paused,
+    /// Every speech of the debate is over
+    Finished,
+}
+
+#[derive(Deserialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+pub enum TimerAction {
+    /// Starts the current speech
+    Start,
+    Pause,
+    Resume,
+    /// Ends the current speech and moves on to the next one
+    NextSpeech,
+    /// Ends the current speech and gives the other side an ad vocem reply to it
+    AdVocem,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimerCommand {
+    pub action: TimerAction,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+/// Part of a speech during which points of information do not have to be answered.
+/// Both bounds are in seconds from the start of the speech.
+pub struct ProtectedWindow {
+    pub start: i32,
+    pub end: i32,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+pub enum BeepKind {
+    ProtectedTimeStart,
+    ProtectedTimeEnd,
+    SpeechEnd,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+/// A sound signal during a speech
+pub struct Beep {
+    pub kind: BeepKind,
+    pub speech_index: usize,
+    /// Second of the speech at which the beep sounds
+    pub at: i32,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// Current state of a debate timer
+pub struct DebateTimerState {
+    pub debate_id: Uuid,
+    pub status: TimerStatus,
+    /// Index of the current speech in `speeches`
+    pub speech_index: usize,
+    pub speeches: Vec<Speech>,
+    /// Time elapsed in the current speech in milliseconds
+    pub elapsed_ms: i64,
+    /// Time left of the current speech in milliseconds.
+    /// Negative once the speaker runs over time.
+    pub remaining_ms: i64,
+    /// Time at which the current speech was last started or resumed.
+    /// Empty unless the timer is running.
+    /// Lets clients count down on their own between updates.
+    pub running_since: Option<DateTime<Utc>>,
+    pub server_time: DateTime<Utc>,
+    /// Protected time of the current speech
+    pub protected_windows: Vec<ProtectedWindow>,
+    pub is_protected_time: bool,
+    /// Indicates whether protected time should be shown to the audience
+    pub visualize_protected_time: bool,
+    /// Beeps of the current speech, in the order they sound
+    pub beeps: Vec<Beep>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(tag = "event", content = "data")]
+/// Message pushed to everyone watching a debate timer
+pub enum DebateTimerEvent {
+    /// The timer has been operated by a Marshal
+    State(DebateTimerState),
+    /// A beep is due now
+    Beep(Beep),
+}
+
+struct DebateTimer {
+    debate_id: Uuid,
+    rules: SpeechRules,
+    speeches: Vec<Speech>,
+    speech_index: usize,
+    status: TimerStatus,
+    /// Time elapsed in the current speech before it was last started or resumed
+    elapsed: TimeDelta,
+    running_since: Option<DateTime<Utc>>,
+    /// Changes with every action, so that beeps scheduled earlier can be dropped
+    revision: u64,
+    events: broadcast::Sender<DebateTimerEvent>,
+}
+
+#[derive(Clone, Default)]
+/// Timers of all debates, kept in memory of the server.
+/// Each timer is created once it is operated or watched live,
+/// and dropped once its debate is over or deleted.
+pub struct DebateTimers(Arc<Mutex<HashMap<Uuid, DebateTimer>>>);
+
+impl DebateTimers {
+    /// Returns the current state of a timer.
+    /// Timers that have not been created yet are shown waiting for the first speech.
+    pub fn get_state(&self, debate_id: Uuid, rules: SpeechRules) -> DebateTimerState {
+        let mut timers = self.lock();
+        match timers.get_mut(&debate_id) {
+            Some(timer) => {
+                timer.update_rules(rules);
+                timer.state(Utc::now())
+            }
+            None => DebateTimer::new(debate_id, rules).state(Utc::now()),
+        }
+    }
+
+    /// Returns the current state of a timer along with a receiver of its further events
+    pub fn subscribe(
+        &self,
+        debate_id: Uuid,
+        rules: SpeechRules,
+    ) -> (DebateTimerState, broadcast::Receiver<DebateTimerEvent>) {
+        let mut timers = self.lock();
+        let timer = get_or_create(&mut timers, debate_id, rules);
+        (timer.state(Utc::now()), timer.events.subscribe())
+    }
+
+    /// Operates a timer and notifies everyone watching it
+    pub fn apply(
+        &self,
+        debate_id: Uuid,
+        rules: SpeechRules,
+        action: TimerAction,
+    ) -> Result<DebateTimerState, OmniError> {
+        let now = Utc::now();
+        let (state, revision, beeps) = {
+            let mut timers = self.lock();
+            let timer = get_or_create(&mut timers, debate_id, rules);
+            timer.apply(action, now)?;
+            let state = timer.state(now);
+            let _ = timer.events.send(DebateTimerEvent::State(state.clone()));
+            (state, timer.revision, timer.upcoming_beeps(now))
+        };
+        self.schedule_beeps(debate_id, revision, beeps);
+        Ok(state)
+    }
+
+    /// Drops the timer of a debate, disconnecting everyone watching it
+    pub fn remove(&self, debate_id: Uuid) {
+        self.lock().remove(&debate_id);
+    }
+
+    /// Pushes beeps when they are due,
+    /// unless the timer has been operated in the meantime
+    fn schedule_beeps(
+        &self,
+        debate_id: Uuid,
+        revision: u64,
+        beeps: Vec<(TimeDelta, Beep)>,
+    ) {
+        if beeps.is_empty() {
+            return;
+        }
+        let timers = self.clone();
+        let scheduled_at = Instant::now();
+        tokio::spawn(async move {
+            for (delay, beep) in beeps {
+                sleep_until(scheduled_at + delay.to_std().unwrap_or_default()).await;
+                let timers = timers.lock();
+                match timers.get(&debate_id) {
+                    Some(timer) if timer.revision == revision => {
+                        let _ = timer.events.send(DebateTimerEvent::Beep(beep));
+                    }
+                    _ => return,
+                }
+            }
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Uuid, DebateTimer>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn get_or_create(
+    timers: &mut HashMap<Uuid, DebateTimer>,
+    debate_id: Uuid,
+    rules: SpeechRules,
+) -> &mut DebateTimer {
+    match timers.entry(debate_id) {
+        Entry::Occupied(entry) => {
+            let timer = entry.into_mut();
+            timer.update_rules(rules);
+            timer
+        }
+        Entry::Vacant(entry) => entry.insert(DebateTimer::new(debate_id, rules)),
+    }
+}
+
+impl DebateTimer {
+    fn new(debate_id: Uuid, rules: SpeechRules) -> DebateTimer {
+        let (events, _) = broadcast::channel(EVENTS_CAPACITY);
+        DebateTimer {
+            debate_id,
+            speeches: get_speeches(&rules),
+            rules,
+            speech_index: 0,
+            status: TimerStatus::Waiting,
+            elapsed: TimeDelta::zero(),
+            running_since: None,
+            revision: 0,
+            events,
+        }
+    }
+
+    /// Rules changed by the Organizers apply until the first speech starts
+    fn update_rules(&mut self, rules: SpeechRules) {
+        if self.status == TimerStatus::Waiting && self.speech_index == 0 {
+            self.speeches = get_speeches(&rules);
+            self.rules = rules;
+        }
+    }
+
+    fn elapsed(&self, now: DateTime<Utc>) -> TimeDelta {
+        match self.running_since {
+            Some(running_since) => self.elapsed + (now - running_since),
+            None => self.elapsed,
+        }
+    }
+
+    fn apply(
+        &mut self,
+        action: TimerAction,
+        now: DateTime<Utc>,
+    ) -> Result<(), OmniError> {
+        use TimerAction as A;
+        use TimerStatus as S;
+        match (action, self.status) {
+            (A::Start, S::Waiting) | (A::Resume, S::Paused) => {
+                self.running_since = Some(now);
+                self.status = S::Running;
+            }
+            (A::Pause, S::Running) => {
+                self.elapsed = self.elapsed(now);
+                self.running_since = None;
+                self.status = S::Paused;
+            }
+            (A::NextSpeech, S::Waiting | S::Running | S::Paused) => {
+                self.move_to_speech(self.speech_index + 1);
+            }
+            (A::AdVocem, S::Running | S::Paused) => {
+                let speech = &self.speeches[self.speech_index].definition;
+                if !speech.allows_ad_vocem || self.rules.ad_vocem_time <= 0 {
+                    return Err(OmniError::ExplicitError {
+                        status: StatusCode::CONFLICT,
+                        message: "Ad vocem is not allowed after this speech".to_owned(),
+                    });
+                }
+                let ad_vocem = Speech {
+                    definition: SpeechDefinition {
+                        side: speech.side.other(),
+                        speaker_position: 0,
+                        duration: self.rules.ad_vocem_time,
+                        start_protected_time: 0,
+                        end_protected_time: 0,
+                        is_reply: false,
+                        allows_ad_vocem: false,
+                    },
+                    is_ad_vocem: true,
+                };
+                self.speeches.insert(self.speech_index + 1, ad_vocem);
+                self.move_to_speech(self.speech_index + 1);
+            }
+            (action, status) => {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::CONFLICT,
+                    message: format!("Cannot {action} a timer that is {status}"),
+                })
+            }
+        }
+        self.revision += 1;
+        Ok(())
+    }
+
+    fn move_to_speech(&mut self, speech_index: usize) {
+        self.elapsed = TimeDelta::zero();
+        self.running_since = None;
+        match speech_index < self.speeches.len() {
+            true => {
+                self.speech_index = speech_index;
+                self.status = TimerStatus::Waiting;
+            }
+            false => self.status = TimerStatus::Finished,
+        }
+    }
+
+    fn state(&self, now: DateTime<Utc>) -> DebateTimerState {
+        let speech = &self.speeches[self.speech_index].definition;
+        let elapsed = self.elapsed(now);
+        let elapsed_seconds = elapsed.num_milliseconds() as f64 / 1000.0;
+        let protected_windows = self.get_protected_windows(speech);
+        let is_protected_time =
+            matches!(self.status, TimerStatus::Running | TimerStatus::Paused)
+                && protected_windows.iter().any(|window| {
+                    window.start as f64 <= elapsed_seconds
+                        && elapsed_seconds < window.end as f64
+                });
+        DebateTimerState {
+            debate_id: self.debate_id,
+            status: self.status,
+            speech_index: self.speech_index,
+            speeches: self.speeches.clone(),
+            elapsed_ms: elapsed.num_milliseconds(),
+            remaining_ms: i64::from(speech.duration) * 1000 - elapsed.num_milliseconds(),
+            running_since: self.running_since,
+            server_time: now,
+            protected_windows,
+            is_protected_time,
+            visualize_protected_time: self.rules.visualize_protected_time,
+            beeps: self.get_beeps(),
+        }
+    }
+
+    fn get_protected_windows(&self, speech: &SpeechDefinition) -> Vec<ProtectedWindow> {
+        let mut windows = vec![];
+        if speech.start_protected_time > 0 {
+            windows.push(ProtectedWindow {
+                start: 0,
+                end: speech.start_protected_time.min(speech.duration),
+            });
+        }
+        if speech.end_protected_time > 0 {
+            windows.push(ProtectedWindow {
+                start: (speech.duration - speech.end_protected_time).max(0),
+                end: speech.duration,
+            });
+        }
+        windows
+    }
+
+    /// Protected time is signalled only at the bounds
+    /// not already marked by the start or the end of the speech
+    fn get_beeps(&self) -> Vec<Beep> {
+        let speech = &self.speeches[self.speech_index].definition;
+        let mut beeps = vec![];
+        let mut beep = |kind, at| {
+            beeps.push(Beep {
+                kind,
+                speech_index: self.speech_index,
+                at,
+            })
+        };
+        if self.rules.beep_on_protected_time {
+            for window in self.get_protected_windows(speech) {
+                if window.start > 0 {
+                    beep(BeepKind::ProtectedTimeStart, window.start);
+                }
+                if window.end < speech.duration {
+                    beep(BeepKind::ProtectedTimeEnd, window.end);
+                }
+            }
+        }
+        if self.rules.beep_on_speech_end {
+            beep(BeepKind::SpeechEnd, speech.duration);
+        }
+        beeps.sort_by_key(|beep| beep.at);
+        beeps
+    }
+
+    /// Returns beeps of a running speech yet to sound, with the time left until each
+    fn upcoming_beeps(&self, now: DateTime<Utc>) -> Vec<(TimeDelta, Beep)> {
+        if self.status != TimerStatus::Running {
+            return vec![];
+        }
+        let elapsed = self.elapsed(now);
+        self.get_beeps()
+            .into_iter()
+            .map(|beep| (TimeDelta::seconds(beep.at.into()) - elapsed, beep))
+            .filter(|(delay, _)| *delay >= TimeDelta::zero())
+            .collect()
+    }
+}
+
+fn get_speeches(rules: &SpeechRules) -> Vec<Speech> {
+    rules
+        .speeches
+        .iter()
+        .map(|definition| Speech {
+            definition: definition.clone(),
+            is_ad_vocem: false,
+        })
+        .collect()
+}
+
+impl fmt::Display for TimerAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimerAction::Start => write!(f, "start"),
+            TimerAction::Pause => write!(f, "pause"),
+            TimerAction::Resume => write!(f, "resume"),
+            TimerAction::NextSpeech => write!(f, "advance"),
+            TimerAction::AdVocem => write!(f, "give an ad vocem on"),
+        }
+    }
+}
+
+impl fmt::Display for TimerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimerStatus::Waiting => write!(f, "waiting"),
+            TimerStatus::Running => write!(f, "running"),
+            TimerStatus::Paused => write!(f, "paused"),
+            TimerStatus::Finished => write!(f, "finished"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+    use tokio::sync::broadcast::error::TryRecvError;
+    use uuid::Uuid;
+
+    use super::{
+        BeepKind, DebateTimer, DebateTimers, SpeechRules, TimerAction, TimerStatus,
+    };
+    use crate::tournaments::debate_formats::{DebateFormat, Side};
+
+    fn rules() -> SpeechRules {
+        rules_of(DebateFormat::oxford(Uuid::now_v7(), 300, 30, 30, 60))
+    }
+
+    fn rules_of(format: DebateFormat) -> SpeechRules {
+        SpeechRules {
+            speeches: format.speeches,
+            ad_vocem_time: format.ad_vocem_time,
+            beep_on_speech_end: true,
+            beep_on_protected_time: true,
+            visualize_protected_time: true,
+        }
+    }
+
+    #[test]
+    fn timers_should_be_kept_only_while_watched() {
+        let timers = DebateTimers::default();
+        let debate_id = Uuid::now_v7();
+
+        let state = timers.get_state(debate_id, rules());
+        let was_kept_after_reading = timers.lock().contains_key(&debate_id);
+        let (_, mut events) = timers.subscribe(debate_id, rules());
+        let was_kept_while_watched = timers.lock().contains_key(&debate_id);
+        timers.remove(debate_id);
+
+        assert_eq!(state.status, TimerStatus::Waiting);
+        assert!(!was_kept_after_reading);
+        assert!(was_kept_while_watched);
+        assert!(timers.lock().is_empty());
+        assert_eq!(events.try_recv(), Err(TryRecvError::Closed));
+    }
+
+    #[test]
+    fn paused_time_should_not_count_towards_the_speech() {
+        let mut timer = DebateTimer::new(Uuid::now_v7(), rules());
+        let start = Utc::now();
+
+        timer.apply(TimerAction::Start, start).unwrap();
+        timer
+            .apply(TimerAction::Pause, start + TimeDelta::seconds(10))
+            .unwrap();
+        timer
+            .apply(TimerAction::Resume, start + TimeDelta::seconds(100))
+            .unwrap();
+        let state = timer.state(start + TimeDelta::seconds(120));
+
+        assert_eq!(state.status, TimerStatus::Running);
+        assert_eq!(state.elapsed_ms, 30_000);
+        assert_eq!(state.remaining_ms, 270_000);
+        assert!(!state.is_protected_time);
+    }
+
+    #[test]
+    fn protected_time_should_be_signalled_at_its_bounds() {
+        let mut timer = DebateTimer::new(Uuid::now_v7(), rules());
+        let start = Utc::now();
+
+        timer.apply(TimerAction::Start, start).unwrap();
+        let state = timer.state(start + TimeDelta::seconds(5));
+        let beeps: Vec<(BeepKind, i32)> = state
+            .beeps
+            .iter()
+            .map(|beep| (beep.kind, beep.at))
+            .collect();
+        let upcoming = timer.upcoming_beeps(start + TimeDelta::seconds(100));
+
+        assert!(state.is_protected_time);
+        assert_eq!(
+            beeps,
+            vec![
+                (BeepKind::ProtectedTimeEnd, 30),
+                (BeepKind::ProtectedTimeStart, 270),
+                (BeepKind::SpeechEnd, 300)
+            ]
+        );
+        assert_eq!(upcoming.len(), 2);
+        assert_eq!(upcoming[0].0, TimeDelta::seconds(170));
+    }
+
+    #[test]
+    fn ad_vocem_should_be_given_to_the_other_side() {
+        let mut timer = DebateTimer::new(Uuid::now_v7(), rules());
+        let start = Utc::now();
+
+        timer.apply(TimerAction::Start, start).unwrap();
+        timer.apply(TimerAction::AdVocem, start).unwrap();
+        let state = timer.state(start);
+
+        assert_eq!(state.speeches.len(), 9);
+        assert_eq!(state.speech_index, 1);
+        assert_eq!(state.speeches[1].definition.side, Side::Opposition);
+        assert!(state.speeches[1].is_ad_vocem);
+        assert!(state.protected_windows.is_empty());
+        assert!(timer.apply(TimerAction::AdVocem, start).is_err());
+    }
+
+    #[test]
+    fn timer_should_finish_after_the_last_speech() {
+        let mut timer = DebateTimer::new(Uuid::now_v7(), rules());
+        let start = Utc::now();
+
+        for _ in 0..8 {
+            timer.apply(TimerAction::NextSpeech, start).unwrap();
+        }
+
+        assert_eq!(timer.status, TimerStatus::Finished);
+        assert!(timer.apply(TimerAction::Start, start).is_err());
+    }
+
+    #[test]
+    fn timer_should_follow_the_debate_format() {
+        let mut timer = DebateTimer::new(
+            Uuid::now_v7(),
+            rules_of(DebateFormat::wsdc(Uuid::now_v7())),
+        );
+        let start = Utc::now();
+
+        for _ in 0..6 {
+            timer.apply(TimerAction::NextSpeech, start).unwrap();
+        }
+        timer.apply(TimerAction::Start, start).unwrap();
+        let state = timer.state(start + TimeDelta::seconds(10));
+
+        assert_eq!(state.speeches.len(), 8);
+        assert!(state.speeches[6].definition.is_reply);
+        assert_eq!(state.remaining_ms, 230_000);
+        assert!(state.is_protected_time);
+        assert!(timer.apply(TimerAction::AdVocem, start).is_err());
+    }
+}
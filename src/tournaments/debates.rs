@@ -1,153 +1,619 @@
-﻿use serde::{Deserialize, Serialize};
-use serde_inline_default::serde_inline_default;
-use sqlx::{query, query_as, Executor, Pool, Postgres, Transaction};
-use utoipa::ToSchema;
-use uuid::Uuid;
-
-use crate::omni_error::OmniError;
-
-#[derive(Serialize, Deserialize, ToSchema)]
-#[serde(deny_unknown_fields)]
-/// Debates are the core part of a tournament.
-/// Each debate is attended by two teams (Proposition and Opposition)
-/// debating on a motion. It is conducted by a Marshal
-/// and listened to by Judges.
-pub struct Debate {
-    #[serde(skip_deserializing)]
-    #[serde(default = "Uuid::now_v7")]
-    pub id: Uuid,
-    /// ID of a motion being debated
-    pub motion_id: Option<Uuid>,
-    /// ID of the Marshal conducting the debate
-    pub marshal_user_id: Option<Uuid>,
-    /// Parent tournament ID
-    /// TO-DO: remove to avoid redundancy
-    pub tournament_id: Uuid,
-    /// Parent round ID
-    pub round_id: Uuid,
-}
-
-#[serde_inline_default]
-#[derive(Deserialize, ToSchema)]
-/// Can be used to patch an existing debate
-pub struct DebatePatch {
-    pub motion_id: Option<Uuid>,
-    pub marshal_user_id: Option<Uuid>,
-    pub tournament_id: Option<Uuid>,
-    pub round_id: Option<Uuid>,
-}
-
-impl Debate {
-    pub async fn get_all<'e, E>(
-        tournament_id: Uuid,
-        executor: E,
-    ) -> Result<Vec<Debate>, OmniError>
-    where
-        E: Executor<'e, Database = Postgres>,
-    {
-        let debates = query_as!(
-            Debate,
-            "SELECT * FROM debates WHERE tournament_id = $1",
-            tournament_id
-        )
-        .fetch_all(executor)
-        .await?;
-
-        Ok(debates)
-    }
-
-    pub async fn post(
-        tournament_id: Uuid,
-        json: Debate,
-        pool: &Pool<Postgres>,
-    ) -> Result<Debate, OmniError> {
-        let mut transaction = pool.begin().await?;
-        let debate =
-            Self::post_with_transaction(&mut transaction, tournament_id, json).await?;
-        transaction.commit().await?;
-        Ok(debate)
-    }
-
-    pub async fn post_with_transaction(
-        transaction: &mut Transaction<'_, Postgres>,
-        tournament_id: Uuid,
-        json: Debate,
-    ) -> Result<Debate, OmniError> {
-        let debate = query_as!(
-            Debate,
-            r#"INSERT INTO debates(id, motion_id, marshal_user_id, tournament_id, round_id)
-            VALUES ($1, $2, $3, $4, $5) RETURNING id, motion_id, marshal_user_id, tournament_id, round_id"#,
-            json.id,
-            json.motion_id,
-            json.marshal_user_id,
-            tournament_id,
-            json.round_id
-        )
-        .fetch_one(&mut **transaction)
-        .await?;
-
-        Ok(debate)
-    }
-
-    pub async fn get_by_id(
-        id: Uuid,
-        connection_pool: &Pool<Postgres>,
-    ) -> Result<Debate, OmniError> {
-        match query_as!(Debate, "SELECT * FROM debates WHERE id = $1", id)
-            .fetch_one(connection_pool)
-            .await
-        {
-            Ok(debate) => Ok(debate),
-            Err(e) => Err(e)?,
-        }
-    }
-
-    pub async fn patch(
-        self,
-        patch: DebatePatch,
-        pool: &Pool<Postgres>,
-    ) -> Result<Debate, OmniError> {
-        let mut transaction = pool.begin().await?;
-        let debate = self.patch_with_transaction(&mut transaction, patch).await?;
-        transaction.commit().await?;
-        Ok(debate)
-    }
-
-    pub async fn patch_with_transaction(
-        self,
-        transaction: &mut Transaction<'_, Postgres>,
-        patch: DebatePatch,
-    ) -> Result<Debate, OmniError> {
-        let updated = query_as!(
-            Debate,
-            r#"UPDATE debates SET motion_id = $1, marshal_user_id = $2, round_id = $3 WHERE id = $4
-            RETURNING id, motion_id, marshal_user_id, tournament_id, round_id"#,
-            patch.motion_id,
-            patch.marshal_user_id,
-            patch.round_id,
-            self.id
-        )
-        .fetch_one(&mut **transaction)
-        .await?;
-
-        Ok(updated)
-    }
-
-    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
-        let mut transaction = pool.begin().await?;
-        self.delete_with_transaction(&mut transaction).await?;
-        transaction.commit().await?;
-        Ok(())
-    }
-
-    pub async fn delete_with_transaction(
-        self,
-        transaction: &mut Transaction<'_, Postgres>,
-    ) -> Result<(), OmniError> {
-        query!(r#"DELETE FROM debates WHERE id = $1"#, self.id)
-            .execute(&mut **transaction)
-            .await?;
-
-        Ok(())
-    }
-}
+﻿use std::{collections::HashSet, fmt};
+
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_inline_default::serde_inline_default;
+use sqlx::{query, query_as, Executor, Pool, Postgres, Transaction};
+use tracing::error;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_formats::{DebateFormat, Side},
+        debate_judges::DebateJudgeAssignment,
+        debate_teams::DebateTeamAssignment,
+        room_bookings::RoomBooking,
+        rounds::Round,
+        rule_overrides::RuleOverrides,
+        Tournament,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
+
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Debates are the core part of a tournament.
+/// Each debate is attended by one team per position of the debate format,
+/// debating on a motion. It is conducted by a Marshal
+/// and listened to by Judges.
+pub struct Debate {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    /// ID of a motion being debated
+    pub motion_id: Option<Uuid>,
+    /// ID of the Marshal conducting the debate
+    pub marshal_user_id: Option<Uuid>,
+    /// Parent tournament ID
+    /// TO-DO: remove to avoid redundancy
+    pub tournament_id: Uuid,
+    /// Parent round ID
+    pub round_id: Uuid,
+    /// ID of a debate format used instead of the format of the tournament
+    pub debate_format_id: Option<Uuid>,
+    /// ID of the room the debate is held in. The room is booked for the debate
+    /// from the planned start time of the round for debate_time_slot minutes.
+    pub room_id: Option<Uuid>,
+    /// Stage the debate has reached. Can only be changed through /debates/{id}/status.
+    #[serde(skip_deserializing)]
+    #[serde(default)]
+    pub status: DebateStatus,
+    /// Time at which the preparation ends. Set once the debate enters Preparation.
+    #[serde(skip_deserializing)]
+    pub preparation_end_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, PartialEq, Clone, Copy, Debug, Default)]
+/// Debates move through the following stages in order:
+/// Scheduled -> SidesDrawn -> Preparation -> InProgress -> AwaitingVerdicts -> Concluded.
+/// A debate can be Cancelled at any stage before it is Concluded.
+pub enum DebateStatus {
+    #[default]
+    Scheduled,
+    /// Every position of the debate format is taken by a team
+    SidesDrawn,
+    /// The teams are preparing for debate_preparation_time minutes
+    Preparation,
+    InProgress,
+    /// The debate is over and the panel is ruling on it
+    AwaitingVerdicts,
+    /// Every Judge on the panel has submitted a verdict
+    Concluded,
+    Cancelled,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Moves a debate to another stage
+pub struct DebateStatusChange {
+    pub status: DebateStatus,
+}
+
+#[serde_inline_default]
+#[derive(Deserialize, ToSchema)]
+/// Can be used to patch an existing debate
+pub struct DebatePatch {
+    pub motion_id: Option<Uuid>,
+    pub marshal_user_id: Option<Uuid>,
+    pub tournament_id: Option<Uuid>,
+    pub round_id: Option<Uuid>,
+    pub debate_format_id: Option<Uuid>,
+    pub room_id: Option<Uuid>,
+}
+
+#[derive(Serialize, ToSchema)]
+/// A debate along with the teams and the panel of Judges assigned to it
+pub struct DebateDetails {
+    #[serde(flatten)]
+    pub debate: Debate,
+    pub teams: Vec<DebateTeamAssignment>,
+    pub judges: Vec<DebateJudgeAssignment>,
+}
+
+/// Debate as stored in the database
+struct DebateRecord {
+    id: Uuid,
+    motion_id: Option<Uuid>,
+    marshal_user_id: Option<Uuid>,
+    tournament_id: Uuid,
+    round_id: Uuid,
+    debate_format_id: Option<Uuid>,
+    room_id: Option<Uuid>,
+    status: String,
+    preparation_end_time: Option<DateTime<Utc>>,
+}
+
+impl DebateDetails {
+    /// Matches team and Judge assignments with the debates they belong to
+    pub fn collect(
+        debates: Vec<Debate>,
+        teams: Vec<DebateTeamAssignment>,
+        judges: Vec<DebateJudgeAssignment>,
+    ) -> Vec<DebateDetails> {
+        debates
+            .into_iter()
+            .map(|debate| {
+                let teams = teams
+                    .iter()
+                    .filter(|assignment| assignment.debate_id == debate.id)
+                    .cloned()
+                    .collect();
+                let judges = judges
+                    .iter()
+                    .filter(|assignment| assignment.debate_id == debate.id)
+                    .cloned()
+                    .collect();
+                DebateDetails {
+                    debate,
+                    teams,
+                    judges,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Debate {
+    /// Hides motions of debates until their rounds release them,
+    /// unless the user is permitted to modify motions
+    pub fn hide_unreleased_motions(
+        debates: &mut [Debate],
+        rounds: &[Round],
+        tournament_user: &TournamentUser,
+    ) {
+        if tournament_user.has_permission(Permission::WriteMotions) {
+            return;
+        }
+        let released_rounds: HashSet<Uuid> = rounds
+            .iter()
+            .filter(|round| round.motion_is_released())
+            .map(|round| round.id)
+            .collect();
+        for debate in debates {
+            if !released_rounds.contains(&debate.round_id) {
+                debate.motion_id = None;
+            }
+        }
+    }
+
+    pub async fn get_all<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Debate>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query_as!(
+            DebateRecord,
+            "SELECT * FROM debates WHERE tournament_id = $1",
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(Debate::try_from)
+        .collect()
+    }
+
+    pub async fn get_all_of_round<'e, E>(
+        round_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Debate>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query_as!(
+            DebateRecord,
+            "SELECT * FROM debates WHERE round_id = $1",
+            round_id
+        )
+        .fetch_all(executor)
+        .await?
+        .into_iter()
+        .map(Debate::try_from)
+        .collect()
+    }
+
+    pub async fn post(
+        tournament_id: Uuid,
+        json: Debate,
+        pool: &Pool<Postgres>,
+    ) -> Result<Debate, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let debate =
+            Self::post_with_transaction(&mut transaction, tournament_id, json).await?;
+        RoomBooking::validate(&[debate.id], &mut transaction).await?;
+        transaction.commit().await?;
+        Ok(debate)
+    }
+
+    pub async fn post_with_transaction(
+        transaction: &mut Transaction<'_, Postgres>,
+        tournament_id: Uuid,
+        json: Debate,
+    ) -> Result<Debate, OmniError> {
+        query_as!(
+            DebateRecord,
+            r#"INSERT INTO debates
+            (id, motion_id, marshal_user_id, tournament_id, round_id, debate_format_id,
+            room_id, status, preparation_end_time)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *"#,
+            json.id,
+            json.motion_id,
+            json.marshal_user_id,
+            tournament_id,
+            json.round_id,
+            json.debate_format_id,
+            json.room_id,
+            json.status.to_string(),
+            json.preparation_end_time
+        )
+        .fetch_one(&mut **transaction)
+        .await?
+        .try_into()
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        connection_pool: &Pool<Postgres>,
+    ) -> Result<Debate, OmniError> {
+        match query_as!(DebateRecord, "SELECT * FROM debates WHERE id = $1", id)
+            .fetch_one(connection_pool)
+            .await
+        {
+            Ok(record) => Debate::try_from(record),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    /// Returns the debate, locking it until the transaction ends,
+    /// so that it cannot change while the transaction depends on it
+    pub async fn lock_with_transaction(
+        id: Uuid,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<Debate, OmniError> {
+        query_as!(
+            DebateRecord,
+            "SELECT * FROM debates WHERE id = $1 FOR NO KEY UPDATE",
+            id
+        )
+        .fetch_one(&mut **transaction)
+        .await?
+        .try_into()
+    }
+
+    /// Returns the debate, as long as it belongs to the tournament
+    pub async fn get_by_id_in_tournament(
+        id: Uuid,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Debate, OmniError> {
+        let debate = Debate::get_by_id(id, pool).await?;
+        match debate.tournament_id == tournament_id {
+            true => Ok(debate),
+            false => Err(OmniError::ResourceNotFoundError),
+        }
+    }
+
+    pub async fn with_details(
+        self,
+        pool: &Pool<Postgres>,
+    ) -> Result<DebateDetails, OmniError> {
+        let teams = DebateTeamAssignment::get_by_debate_id(self.id, pool).await?;
+        let judges = DebateJudgeAssignment::get_by_debate_id(self.id, pool).await?;
+        Ok(DebateDetails {
+            debate: self,
+            teams,
+            judges,
+        })
+    }
+
+    pub async fn patch(
+        self,
+        patch: DebatePatch,
+        pool: &Pool<Postgres>,
+    ) -> Result<Debate, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let debate = self.patch_with_transaction(&mut transaction, patch).await?;
+        RoomBooking::validate(&[debate.id], &mut transaction).await?;
+        transaction.commit().await?;
+        Ok(debate)
+    }
+
+    pub async fn patch_with_transaction(
+        self,
+        transaction: &mut Transaction<'_, Postgres>,
+        patch: DebatePatch,
+    ) -> Result<Debate, OmniError> {
+        let debate_format_id = patch.debate_format_id.or(self.debate_format_id);
+        let room_id = patch.room_id.or(self.room_id);
+        query_as!(
+            DebateRecord,
+            r#"UPDATE debates SET motion_id = $1, marshal_user_id = $2, round_id = $3,
+            debate_format_id = $4, room_id = $5 WHERE id = $6
+            RETURNING *"#,
+            patch.motion_id,
+            patch.marshal_user_id,
+            patch.round_id,
+            debate_format_id,
+            room_id,
+            self.id
+        )
+        .fetch_one(&mut **transaction)
+        .await?
+        .try_into()
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        let mut transaction = pool.begin().await?;
+        self.delete_with_transaction(&mut transaction).await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    pub async fn delete_with_transaction(
+        self,
+        transaction: &mut Transaction<'_, Postgres>,
+    ) -> Result<(), OmniError> {
+        query!(r#"DELETE FROM debates WHERE id = $1"#, self.id)
+            .execute(&mut **transaction)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Moves the debate to the given stage.
+    /// Entering Preparation starts the preparation time of the debate,
+    /// which can be overridden down from the tournament.
+    /// Debates entering AwaitingVerdicts are concluded right away
+    /// if the panel has already ruled on them.
+    pub async fn change_status(
+        self,
+        status: DebateStatus,
+        pool: &Pool<Postgres>,
+    ) -> Result<Debate, OmniError> {
+        self.validate_status_change(status, pool).await?;
+        let preparation_end_time = match status {
+            DebateStatus::Preparation => {
+                let mut tournament =
+                    Tournament::get_by_id(self.tournament_id, pool).await?;
+                RuleOverrides::apply_for_debate(&mut tournament, &self, pool).await?;
+                Some(Utc::now() + tournament.get_debate_preparation_time())
+            }
+            _ => self.preparation_end_time,
+        };
+
+        let mut transaction = pool.begin().await?;
+        query!(
+            "UPDATE debates SET status = $1, preparation_end_time = $2 WHERE id = $3",
+            status.to_string(),
+            preparation_end_time,
+            self.id
+        )
+        .execute(&mut *transaction)
+        .await?;
+        if status == DebateStatus::AwaitingVerdicts {
+            Debate::follow_verdicts(self.id, &mut *transaction).await?;
+        }
+        let debate =
+            query_as!(DebateRecord, "SELECT * FROM debates WHERE id = $1", self.id)
+                .fetch_one(&mut *transaction)
+                .await?
+                .try_into()?;
+        transaction.commit().await?;
+        Ok(debate)
+    }
+
+    async fn validate_status_change(
+        &self,
+        status: DebateStatus,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        let conflict = |message: String| {
+            Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message,
+            })
+        };
+        if status == DebateStatus::Concluded {
+            return conflict(
+                "A debate is concluded once every Judge on the panel has submitted a verdict"
+                    .to_owned(),
+            );
+        }
+        if !self.status.can_become(status) {
+            return conflict(format!(
+                "A debate cannot move from {} to {}",
+                self.status, status
+            ));
+        }
+        match status {
+            DebateStatus::SidesDrawn => {
+                let tournament = Tournament::get_by_id(self.tournament_id, pool).await?;
+                let format =
+                    DebateFormat::get_for_debate(self, &tournament, pool).await?;
+                let taken_sides: HashSet<Side> =
+                    DebateTeamAssignment::get_by_debate_id(self.id, pool)
+                        .await?
+                        .into_iter()
+                        .filter_map(|assignment| assignment.side)
+                        .collect();
+                if format
+                    .positions()
+                    .iter()
+                    .any(|side| !taken_sides.contains(side))
+                {
+                    return conflict(
+                        "Every position of the debate must be taken by a team".to_owned(),
+                    );
+                }
+            }
+            DebateStatus::Preparation if self.motion_id.is_none() => {
+                return conflict("The debate has no motion to prepare".to_owned());
+            }
+            DebateStatus::InProgress
+                if self
+                    .preparation_end_time
+                    .is_some_and(|end_time| end_time > Utc::now()) =>
+            {
+                return conflict("The preparation time has not run out yet".to_owned());
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Moves a scheduled debate on once its teams have been given their sides
+    pub async fn mark_sides_drawn<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<(), OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query!(
+            "UPDATE debates SET status = 'SidesDrawn' WHERE id = $1 AND status = 'Scheduled'",
+            debate_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Concludes a debate awaiting verdicts once every Judge on the panel
+    /// has submitted one, and reopens it if that is no longer the case.
+    /// Debates at any other stage are left as they are,
+    /// so verdicts never move a debate past the stages it has not gone through.
+    pub async fn follow_verdicts<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<(), OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        query!(
+            r#"
+            WITH panel AS (
+                SELECT
+                    (SELECT COUNT(DISTINCT judge_user_id) FROM debate_judge_assignments
+                        WHERE debate_id = $1) AS judges,
+                    (SELECT COUNT(*) FROM panel_verdicts WHERE debate_id = $1) AS verdicts
+            )
+            UPDATE debates
+            SET status = CASE
+                WHEN panel.judges > 0 AND panel.verdicts >= panel.judges
+                    THEN 'Concluded'
+                ELSE 'AwaitingVerdicts'
+            END
+            FROM panel
+            WHERE debates.id = $1
+                AND debates.status IN ('AwaitingVerdicts', 'Concluded')
+                AND (debates.status = 'Concluded')
+                    <> (panel.judges > 0 AND panel.verdicts >= panel.judges)
+            "#,
+            debate_id
+        )
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl DebateStatus {
+    /// Indicates whether the debate needs no further action
+    pub fn is_over(self) -> bool {
+        matches!(self, DebateStatus::Concluded | DebateStatus::Cancelled)
+    }
+
+    /// Indicates whether the teams have begun preparing for the debate
+    pub fn has_started(self) -> bool {
+        matches!(
+            self,
+            DebateStatus::Preparation
+                | DebateStatus::InProgress
+                | DebateStatus::AwaitingVerdicts
+                | DebateStatus::Concluded
+        )
+    }
+
+    fn can_become(self, next: DebateStatus) -> bool {
+        use DebateStatus as S;
+        match next {
+            S::Cancelled => !self.is_over(),
+            S::SidesDrawn => self == S::Scheduled,
+            S::Preparation => self == S::SidesDrawn,
+            S::InProgress => self == S::Preparation,
+            S::AwaitingVerdicts => self == S::InProgress,
+            S::Scheduled | S::Concluded => false,
+        }
+    }
+}
+
+impl TryFrom<DebateRecord> for Debate {
+    type Error = OmniError;
+
+    fn try_from(record: DebateRecord) -> Result<Self, Self::Error> {
+        Ok(Debate {
+            id: record.id,
+            motion_id: record.motion_id,
+            marshal_user_id: record.marshal_user_id,
+            tournament_id: record.tournament_id,
+            round_id: record.round_id,
+            debate_format_id: record.debate_format_id,
+            room_id: record.room_id,
+            status: DebateStatus::try_from(record.status)?,
+            preparation_end_time: record.preparation_end_time,
+        })
+    }
+}
+
+impl fmt::Display for DebateStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DebateStatus::Scheduled => write!(f, "Scheduled"),
+            DebateStatus::SidesDrawn => write!(f, "SidesDrawn"),
+            DebateStatus::Preparation => write!(f, "Preparation"),
+            DebateStatus::InProgress => write!(f, "InProgress"),
+            DebateStatus::AwaitingVerdicts => write!(f, "AwaitingVerdicts"),
+            DebateStatus::Concluded => write!(f, "Concluded"),
+            DebateStatus::Cancelled => write!(f, "Cancelled"),
+        }
+    }
+}
+
+impl TryFrom<String> for DebateStatus {
+    type Error = OmniError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Scheduled" => Ok(DebateStatus::Scheduled),
+            "SidesDrawn" => Ok(DebateStatus::SidesDrawn),
+            "Preparation" => Ok(DebateStatus::Preparation),
+            "InProgress" => Ok(DebateStatus::InProgress),
+            "AwaitingVerdicts" => Ok(DebateStatus::AwaitingVerdicts),
+            "Concluded" => Ok(DebateStatus::Concluded),
+            "Cancelled" => Ok(DebateStatus::Cancelled),
+            _ => {
+                error!("Unknown debate status: {value}");
+                Err(OmniError::InternalServerError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebateStatus as S;
+
+    #[test]
+    fn debates_should_only_move_forward_one_stage_at_a_time() {
+        assert!(S::Scheduled.can_become(S::SidesDrawn));
+        assert!(S::InProgress.can_become(S::AwaitingVerdicts));
+        assert!(!S::Scheduled.can_become(S::InProgress));
+        assert!(!S::Preparation.can_become(S::SidesDrawn));
+        assert!(!S::AwaitingVerdicts.can_become(S::Concluded));
+    }
+
+    #[test]
+    fn only_unfinished_debates_should_be_cancelled() {
+        assert!(S::AwaitingVerdicts.can_become(S::Cancelled));
+        assert!(!S::Concluded.can_become(S::Cancelled));
+        assert!(!S::Cancelled.can_become(S::Cancelled));
+    }
+}
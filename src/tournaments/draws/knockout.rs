@@ -0,0 +1,441 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_inline_default::serde_inline_default;
+use sqlx::{query, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    order_rounds, write_round_with_transaction, BrokenConstraint, Constraint, Draw,
+    Pairing, RoundPairings,
+};
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        bracket_links::BracketLink,
+        debate_results::{DebateResult, DebateResultStatus},
+        phases::{Phase, PhaseStatus},
+        Tournament,
+    },
+};
+
+#[serde_inline_default]
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SeedingOptions {
+    /// If set, the proposed bracket is returned without being saved
+    #[serde_inline_default(false)]
+    pub dry_run: bool,
+    /// If set, teams from the same group of the group phase are kept apart
+    /// in the first knockout round whenever possible.
+    /// Groups are known only if the group phase was drawn as round robins.
+    #[serde_inline_default(false)]
+    pub keep_groups_apart: bool,
+    /// Number of teams advancing to the bracket. Must be a power of 2.
+    /// Taken from the tournament plan if not given.
+    pub advancing_teams: Option<i32>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A team seeded into the bracket
+pub struct Seed {
+    /// Position of the team in the ranking, starting from 1
+    pub seed: i32,
+    pub team_id: Uuid,
+    /// Debates won in the group phase
+    pub wins: i32,
+    /// Verdicts ruled in the team's favour in the group phase
+    pub ballots: i32,
+    /// Index of the group the team debated in, starting from 0.
+    /// Empty if the group phase wasn't drawn as round robins.
+    pub group: Option<i32>,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Result of seeding a knockout bracket
+pub struct Seeding {
+    #[serde(flatten)]
+    pub draw: Draw,
+    pub seeds: Vec<Seed>,
+    pub broken_constraints: Vec<BrokenConstraint>,
+}
+
+#[derive(Clone, Default)]
+/// Result of a team in the group phase
+pub struct GroupStanding {
+    pub team_id: Uuid,
+    pub wins: i32,
+    pub ballots: i32,
+    pub group: Option<i32>,
+}
+
+/// Seeds the best teams of the finished group phase
+/// into the first round of a finals phase
+/// and links the rounds of the phase into a bracket.
+pub async fn seed_phase(
+    phase: &Phase,
+    options: &SeedingOptions,
+    pool: &Pool<Postgres>,
+) -> Result<Seeding, OmniError> {
+    if !phase.is_finals {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "Only finals phases can be seeded".to_owned(),
+        });
+    }
+    let group_phase = match phase.get_previous_phase(pool).await {
+        Ok(group_phase) => group_phase,
+        Err(e) if e.is_not_found_error() => {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The finals phase must follow a group phase".to_owned(),
+            })
+        }
+        Err(e) => return Err(e),
+    };
+    if group_phase.status != PhaseStatus::Finished {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "The group phase must be finished before seeding the bracket"
+                .to_owned(),
+        });
+    }
+
+    let tournament = Tournament::get_by_id(phase.tournament_id, pool).await?;
+    let advancing_teams = match options.advancing_teams {
+        Some(advancing_teams) => Some(advancing_teams),
+        None => tournament
+            .get_plan(pool)
+            .await?
+            .first()
+            .and_then(|plan| plan.advancing_teams),
+    };
+    let Some(advancing_teams) = advancing_teams else {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "advancing_teams must be given or set in the tournament plan"
+                .to_owned(),
+        });
+    };
+    if advancing_teams < 2 || (advancing_teams & (advancing_teams - 1)) != 0 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message:
+                "Number of advancing teams should be a power of 2 (e.g. 4, 8, 16, 32 ...)"
+                    .to_owned(),
+        });
+    }
+
+    let team_ids: Vec<Uuid> = tournament
+        .get_teams(pool)
+        .await?
+        .into_iter()
+        .map(|team| team.id)
+        .collect();
+    if team_ids.len() < advancing_teams as usize {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "There are fewer teams than advancing_teams".to_owned(),
+        });
+    }
+
+    let rounds = order_rounds(phase.get_rounds(pool).await?);
+    let Some(first_round) = rounds.first() else {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "The phase has no rounds to seed".to_owned(),
+        });
+    };
+
+    let standings = get_group_standings(&group_phase, &team_ids, pool).await?;
+    let (seeds, pairings, broken_constraints) = seed(
+        standings,
+        advancing_teams as usize,
+        options.keep_groups_apart,
+    );
+
+    let mut transaction = pool.begin().await?;
+    let (debates, byes) = write_round_with_transaction(
+        first_round,
+        phase.tournament_id,
+        pairings,
+        options.dry_run,
+        &mut transaction,
+    )
+    .await?;
+    if !options.dry_run {
+        BracketLink::link_rounds_with_transaction(
+            &rounds,
+            phase.tournament_id,
+            &mut transaction,
+        )
+        .await?;
+    }
+    match options.dry_run {
+        true => transaction.rollback().await?,
+        false => transaction.commit().await?,
+    }
+
+    Ok(Seeding {
+        draw: Draw {
+            debates,
+            byes,
+            dry_run: options.dry_run,
+        },
+        seeds,
+        broken_constraints,
+    })
+}
+
+/// Ranks teams by wins, then ballots, and pairs the top `advancing_teams`
+/// in a standard bracket (1 v N, 2 v N-1...), ordered so that
+/// the top two seeds can only meet in the final.
+/// The higher seed argues for the Proposition.
+pub fn seed(
+    mut standings: Vec<GroupStanding>,
+    advancing_teams: usize,
+    keep_groups_apart: bool,
+) -> (Vec<Seed>, RoundPairings, Vec<BrokenConstraint>) {
+    standings.sort_by_key(|standing| {
+        (
+            Reverse(standing.wins),
+            Reverse(standing.ballots),
+            standing.team_id,
+        )
+    });
+    standings.truncate(advancing_teams);
+
+    let seeds: Vec<Seed> = standings
+        .into_iter()
+        .enumerate()
+        .map(|(index, standing)| Seed {
+            seed: index as i32 + 1,
+            team_id: standing.team_id,
+            wins: standing.wins,
+            ballots: standing.ballots,
+            group: standing.group,
+        })
+        .collect();
+
+    let order = bracket_order(seeds.len());
+    let mut pairs: Vec<(usize, usize)> = order
+        .chunks(2)
+        .map(|chunk| (chunk[0] - 1, chunk[1] - 1))
+        .collect();
+
+    let mut broken_constraints = vec![];
+    if keep_groups_apart {
+        let same_group = |a: usize, b: usize| {
+            seeds[a].group.is_some() && seeds[a].group == seeds[b].group
+        };
+        for i in 0..pairs.len() {
+            let (higher, lower) = pairs[i];
+            if !same_group(higher, lower) {
+                continue;
+            }
+            let swap = (0..pairs.len())
+                .filter(|j| {
+                    let (other_higher, other_lower) = pairs[*j];
+                    *j != i
+                        && !same_group(higher, other_lower)
+                        && !same_group(other_higher, lower)
+                })
+                .min_by_key(|j| pairs[*j].1.abs_diff(lower));
+            match swap {
+                Some(j) => {
+                    pairs[i].1 = pairs[j].1;
+                    pairs[j].1 = lower;
+                }
+                None => broken_constraints.push(BrokenConstraint {
+                    constraint: Constraint::SeparateGroups,
+                    team_ids: vec![seeds[higher].team_id, seeds[lower].team_id],
+                }),
+            }
+        }
+    }
+
+    let pairings = RoundPairings {
+        pairings: pairs
+            .into_iter()
+            .map(|(higher, lower)| Pairing {
+                proposition: seeds[higher.min(lower)].team_id,
+                opposition: seeds[higher.max(lower)].team_id,
+            })
+            .collect(),
+        byes: vec![],
+    };
+    (seeds, pairings, broken_constraints)
+}
+
+/// Returns seeds in the order they appear in a standard bracket,
+/// e.g. 1, 8, 4, 5, 2, 7, 3, 6 for 8 teams
+pub fn bracket_order(teams_count: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < teams_count {
+        let seeds_count = order.len() * 2;
+        order = order
+            .into_iter()
+            .flat_map(|seed| [seed, seeds_count + 1 - seed])
+            .collect();
+    }
+    order
+}
+
+/// Computes wins and ballots of teams in the group phase.
+/// Only debates every Judge of the panel has ruled on count,
+/// and even splits are settled by the tie policy of the tournament.
+/// Groups are those the round-robin draw of the phase split the teams into.
+async fn get_group_standings(
+    group_phase: &Phase,
+    team_ids: &[Uuid],
+    pool: &Pool<Postgres>,
+) -> Result<Vec<GroupStanding>, OmniError> {
+    let debate_ids: HashSet<Uuid> = query!(
+        "SELECT d.id FROM debates d JOIN rounds r ON r.id = d.round_id WHERE r.phase_id = $1",
+        group_phase.id
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| record.id)
+    .collect();
+    let mut connection = pool.acquire().await?;
+    let results: Vec<DebateResult> =
+        DebateResult::get_all(group_phase.tournament_id, &mut connection)
+            .await?
+            .into_iter()
+            .filter(|result| {
+                debate_ids.contains(&result.debate_id)
+                    && result.status != DebateResultStatus::Incomplete
+            })
+            .collect();
+
+    let mut standings: HashMap<Uuid, GroupStanding> = team_ids
+        .iter()
+        .map(|team_id| {
+            (
+                *team_id,
+                GroupStanding {
+                    team_id: *team_id,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+
+    let groups: HashMap<Uuid, i32> = query!(
+        "SELECT team_id, group_index FROM phase_groups WHERE phase_id = $1",
+        group_phase.id
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|record| (record.team_id, record.group_index))
+    .collect();
+    for standing in standings.values_mut() {
+        standing.group = groups.get(&standing.team_id).copied();
+    }
+
+    for result in results {
+        for (team_id, ballots) in [
+            (result.proposition_team_id, result.proposition_ballots),
+            (result.opposition_team_id, result.opposition_ballots),
+        ] {
+            let Some(standing) = team_id.and_then(|team_id| standings.get_mut(&team_id))
+            else {
+                continue;
+            };
+            standing.ballots += ballots as i32;
+            if result.winner_team_id == Some(standing.team_id) {
+                standing.wins += 1;
+            }
+        }
+    }
+
+    Ok(standings.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{bracket_order, seed, GroupStanding};
+
+    fn standings(results: &[(i32, i32)]) -> Vec<GroupStanding> {
+        results
+            .iter()
+            .map(|(wins, group)| GroupStanding {
+                team_id: Uuid::now_v7(),
+                wins: *wins,
+                ballots: *wins,
+                group: Some(*group),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bracket_order_should_keep_top_seeds_apart() {
+        assert_eq!(bracket_order(2), vec![1, 2]);
+        assert_eq!(bracket_order(4), vec![1, 4, 2, 3]);
+        assert_eq!(bracket_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn best_teams_should_be_seeded_one_versus_n() {
+        let standings = standings(&[(0, 0), (3, 0), (1, 1), (2, 1), (5, 0), (4, 1)]);
+        let by_wins = |wins: i32| {
+            standings
+                .iter()
+                .find(|standing| standing.wins == wins)
+                .unwrap()
+                .team_id
+        };
+        let (first, second, third, fourth) =
+            (by_wins(5), by_wins(4), by_wins(3), by_wins(2));
+
+        let (seeds, pairings, broken_constraints) = seed(standings.clone(), 4, false);
+
+        assert_eq!(seeds.len(), 4);
+        assert_eq!(seeds[0].team_id, first);
+        assert_eq!(seeds[3].team_id, fourth);
+        assert!(broken_constraints.is_empty());
+        assert_eq!(pairings.pairings[0].proposition, first);
+        assert_eq!(pairings.pairings[0].opposition, fourth);
+        assert_eq!(pairings.pairings[1].proposition, second);
+        assert_eq!(pairings.pairings[1].opposition, third);
+    }
+
+    #[test]
+    fn teams_from_the_same_group_should_be_kept_apart() {
+        // Seeds 1 and 4 come from group 0, seeds 2 and 3 from group 1
+        let standings = standings(&[(4, 0), (3, 1), (2, 1), (1, 0)]);
+
+        let (seeds, pairings, broken_constraints) = seed(standings, 4, true);
+
+        assert!(broken_constraints.is_empty());
+        let group_of = |team_id: Uuid| {
+            seeds
+                .iter()
+                .find(|seed| seed.team_id == team_id)
+                .unwrap()
+                .group
+        };
+        for pairing in pairings.pairings {
+            assert_ne!(group_of(pairing.proposition), group_of(pairing.opposition));
+        }
+    }
+
+    #[test]
+    fn unavoidable_group_clashes_should_be_reported() {
+        let standings = standings(&[(4, 0), (3, 0), (2, 0), (1, 0)]);
+
+        let (_, _, broken_constraints) = seed(standings, 4, true);
+
+        assert_eq!(broken_constraints.len(), 2);
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_inline_default::serde_inline_default;
+use sqlx::{query, Pool, Postgres, Transaction};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_formats::{DebateFormat, Side},
+        debate_teams::DebateTeamAssignment,
+        debates::{Debate, DebateStatus},
+        rounds::Round,
+        Tournament,
+    },
+};
+
+pub(crate) mod knockout;
+pub(crate) mod round_robin;
+pub(crate) mod swiss;
+
+#[serde_inline_default]
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Options accepted by all draw generators
+pub struct DrawOptions {
+    /// If set, the proposed draw is returned without being saved
+    #[serde_inline_default(false)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+/// A single debate proposed by a draw generator
+pub struct DrawnDebate {
+    pub round_id: Uuid,
+    /// ID of the debate the teams are drawn into.
+    /// Empty in dry runs, if a new debate would have to be created.
+    pub debate_id: Option<Uuid>,
+    pub proposition_team_id: Uuid,
+    pub opposition_team_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema, Clone)]
+/// A team left without an opponent in a given round
+pub struct DrawnBye {
+    pub round_id: Uuid,
+    pub team_id: Uuid,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Result of a draw generator
+pub struct Draw {
+    pub debates: Vec<DrawnDebate>,
+    pub byes: Vec<DrawnBye>,
+    /// Indicates whether the draw was only proposed (true) or saved (false)
+    pub dry_run: bool,
+}
+
+#[derive(Serialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// Teams should not meet more than once
+    NoRepeatPairings,
+    /// A team should not get more than one bye
+    NoRepeatByes,
+    /// A team should not argue for one side more than once above the other
+    BalancedSides,
+    /// Teams from the same group should not meet in the first knockout round
+    SeparateGroups,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A constraint the draw had to break, along with the affected teams
+pub struct BrokenConstraint {
+    pub constraint: Constraint,
+    pub team_ids: Vec<Uuid>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Two teams meeting in a debate, with their sides already decided
+pub struct Pairing {
+    pub proposition: Uuid,
+    pub opposition: Uuid,
+}
+
+#[derive(Default, Clone, Debug)]
+/// Pairings of a single round
+pub struct RoundPairings {
+    pub pairings: Vec<Pairing>,
+    pub byes: Vec<Uuid>,
+}
+
+#[derive(Default, Clone)]
+/// Keeps track of sides taken by teams to keep them balanced
+pub struct SideHistory {
+    sides: HashMap<Uuid, TeamSides>,
+}
+
+#[derive(Default, Clone, Copy)]
+struct TeamSides {
+    proposition: i32,
+    opposition: i32,
+    last_was_proposition: Option<bool>,
+}
+
+impl TeamSides {
+    fn imbalance(&self) -> i32 {
+        self.proposition - self.opposition
+    }
+}
+
+impl SideHistory {
+    pub fn record(&mut self, team_id: Uuid, is_proposition: bool) {
+        let sides = self.sides.entry(team_id).or_default();
+        if is_proposition {
+            sides.proposition += 1;
+        } else {
+            sides.opposition += 1;
+        }
+        sides.last_was_proposition = Some(is_proposition);
+    }
+
+    /// Returns the difference between Proposition and Opposition appearances of a team
+    pub fn imbalance(&self, team_id: Uuid) -> i32 {
+        self.sides
+            .get(&team_id)
+            .map(|sides| sides.imbalance())
+            .unwrap_or_default()
+    }
+
+    /// Decides the sides of two teams and records them.
+    /// The team that argued for the Proposition less often gets to do it now.
+    /// Ties are resolved in favour of the team that was the Opposition last time.
+    pub fn assign_sides(&mut self, first: Uuid, second: Uuid) -> Pairing {
+        let a = self.sides.get(&first).copied().unwrap_or_default();
+        let b = self.sides.get(&second).copied().unwrap_or_default();
+        let first_is_proposition = match a.imbalance().cmp(&b.imbalance()) {
+            std::cmp::Ordering::Less => true,
+            std::cmp::Ordering::Greater => false,
+            std::cmp::Ordering::Equal => {
+                !(a.last_was_proposition == Some(true)
+                    && b.last_was_proposition != Some(true))
+            }
+        };
+        let pairing = match first_is_proposition {
+            true => Pairing {
+                proposition: first,
+                opposition: second,
+            },
+            false => Pairing {
+                proposition: second,
+                opposition: first,
+            },
+        };
+        self.record(pairing.proposition, true);
+        self.record(pairing.opposition, false);
+        pairing
+    }
+}
+
+/// Sorts rounds of a single phase by following their previous_round_id chain
+pub fn order_rounds(mut rounds: Vec<Round>) -> Vec<Round> {
+    let mut ordered = Vec::with_capacity(rounds.len());
+    let mut previous_id = rounds
+        .iter()
+        .find(|round| {
+            !rounds
+                .iter()
+                .any(|other| Some(other.id) == round.previous_round_id)
+        })
+        .and_then(|round| round.previous_round_id);
+    while let Some(index) = rounds
+        .iter()
+        .position(|round| round.previous_round_id == previous_id)
+    {
+        let round = rounds.remove(index);
+        previous_id = Some(round.id);
+        ordered.push(round);
+    }
+    // Rounds outside of the chain are drawn last
+    ordered.append(&mut rounds);
+    ordered
+}
+
+/// Draw generators pair two teams per debate,
+/// so tournaments following a four-team format are rejected
+pub async fn ensure_two_team_format(
+    tournament_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<(), OmniError> {
+    let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+    let format = DebateFormat::get_for_tournament(&tournament, pool).await?;
+    match format.teams_per_debate() == Side::TWO_TEAM.len() {
+        true => Ok(()),
+        false => Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: format!(
+                "Draws can only be generated for two-team debates, not for {}",
+                format.name
+            ),
+        }),
+    }
+}
+
+/// Rejects redrawing rounds that have already been ruled on
+pub async fn ensure_round_can_be_drawn(
+    round: &Round,
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(), OmniError> {
+    let has_verdicts = query!(
+        r#"SELECT EXISTS (
+            SELECT 1 FROM verdicts v
+            JOIN debates d ON d.id = v.debate_id
+            WHERE d.round_id = $1
+        )"#,
+        round.id
+    )
+    .fetch_one(&mut **transaction)
+    .await?
+    .exists
+    .unwrap();
+
+    if has_verdicts {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: format!(
+                "Round {} already has verdicts and cannot be drawn again",
+                round.name
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Turns pairings of a round into drawn debates.
+/// Existing debates of the round are reused in the order of creation
+/// and their previous team assignments are replaced.
+/// Missing debates are created, unless it's a dry run.
+pub async fn write_round_with_transaction(
+    round: &Round,
+    tournament_id: Uuid,
+    pairings: RoundPairings,
+    dry_run: bool,
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(Vec<DrawnDebate>, Vec<DrawnBye>), OmniError> {
+    ensure_round_can_be_drawn(round, transaction).await?;
+
+    let mut debates = round.get_debates(&mut **transaction).await?;
+    debates.sort_by_key(|debate| debate.id);
+    if !dry_run {
+        for debate in &debates {
+            DebateTeamAssignment::delete_all_of_debate_with_transaction(
+                debate.id,
+                transaction,
+            )
+            .await?;
+        }
+    }
+
+    let mut existing_debates = debates.into_iter();
+    let mut drawn_debates = Vec::with_capacity(pairings.pairings.len());
+    for pairing in pairings.pairings {
+        let debate_id = match (existing_debates.next(), dry_run) {
+            (Some(debate), _) => Some(debate.id),
+            (None, true) => None,
+            (None, false) => Some(
+                Debate::post_with_transaction(
+                    transaction,
+                    tournament_id,
+                    Debate {
+                        id: Uuid::now_v7(),
+                        motion_id: round.motion_id,
+                        marshal_user_id: None,
+                        tournament_id,
+                        round_id: round.id,
+                        debate_format_id: None,
+                        room_id: None,
+                        status: DebateStatus::Scheduled,
+                        preparation_end_time: None,
+                    },
+                )
+                .await?
+                .id,
+            ),
+        };
+
+        if let (Some(debate_id), false) = (debate_id, dry_run) {
+            for (team_id, is_proposition) in
+                [(pairing.proposition, true), (pairing.opposition, false)]
+            {
+                DebateTeamAssignment::post_with_transaction(
+                    transaction,
+                    DebateTeamAssignment {
+                        id: Uuid::now_v7(),
+                        team_id,
+                        debate_id,
+                        is_proposition: Some(is_proposition),
+                        side: None,
+                    },
+                )
+                .await?;
+            }
+            Debate::mark_sides_drawn(debate_id, &mut **transaction).await?;
+        }
+
+        drawn_debates.push(DrawnDebate {
+            round_id: round.id,
+            debate_id,
+            proposition_team_id: pairing.proposition,
+            opposition_team_id: pairing.opposition,
+        });
+    }
+
+    let byes = pairings
+        .byes
+        .into_iter()
+        .map(|team_id| DrawnBye {
+            round_id: round.id,
+            team_id,
+        })
+        .collect();
+
+    Ok((drawn_debates, byes))
+}
@@ -0,0 +1,282 @@
+use axum::http::StatusCode;
+use sqlx::{query, Pool, Postgres, Transaction};
+use uuid::Uuid;
+
+use super::{
+    order_rounds, write_round_with_transaction, Draw, DrawOptions, Pairing, RoundPairings,
+};
+use crate::{
+    omni_error::OmniError,
+    tournaments::{phases::Phase, Tournament},
+};
+
+/// Draws all rounds of a group phase as round robins.
+/// Teams of the tournament are split into groups according to the phase's group_size,
+/// which are saved along with the draw so that the bracket can keep them apart.
+pub async fn draw_phase(
+    phase: &Phase,
+    options: &DrawOptions,
+    pool: &Pool<Postgres>,
+) -> Result<Draw, OmniError> {
+    if phase.is_finals {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "Round robin draws are only available for group phases".to_owned(),
+        });
+    }
+    let group_size = match phase.group_size {
+        Some(group_size) if group_size >= 2 => group_size as usize,
+        _ => {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The phase must have a group_size of at least 2".to_owned(),
+            })
+        }
+    };
+
+    let tournament = Tournament::get_by_id(phase.tournament_id, pool).await?;
+    let mut team_ids: Vec<Uuid> = tournament
+        .get_teams(pool)
+        .await?
+        .into_iter()
+        .map(|team| team.id)
+        .collect();
+    team_ids.sort();
+    if team_ids.len() < 2 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "At least two teams are required to make a draw".to_owned(),
+        });
+    }
+
+    let rounds = order_rounds(phase.get_rounds(pool).await?);
+    if rounds.is_empty() {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "The phase has no rounds to draw".to_owned(),
+        });
+    }
+
+    let pairings = generate(&team_ids, group_size, rounds.len());
+    let mut draw = Draw {
+        debates: vec![],
+        byes: vec![],
+        dry_run: options.dry_run,
+    };
+    let mut transaction = pool.begin().await?;
+    write_groups_with_transaction(
+        phase.id,
+        &split_into_groups(&team_ids, group_size),
+        &mut transaction,
+    )
+    .await?;
+    for (round, round_pairings) in rounds.iter().zip(pairings) {
+        let (mut debates, mut byes) = write_round_with_transaction(
+            round,
+            phase.tournament_id,
+            round_pairings,
+            options.dry_run,
+            &mut transaction,
+        )
+        .await?;
+        draw.debates.append(&mut debates);
+        draw.byes.append(&mut byes);
+    }
+    match options.dry_run {
+        true => transaction.rollback().await?,
+        false => transaction.commit().await?,
+    }
+
+    Ok(draw)
+}
+
+/// Replaces the groups of the phase, numbering them from 0
+async fn write_groups_with_transaction(
+    phase_id: Uuid,
+    groups: &[Vec<Uuid>],
+    transaction: &mut Transaction<'_, Postgres>,
+) -> Result<(), OmniError> {
+    query!("DELETE FROM phase_groups WHERE phase_id = $1", phase_id)
+        .execute(&mut **transaction)
+        .await?;
+    for (group_index, group) in groups.iter().enumerate() {
+        for team_id in group {
+            query!(
+                "INSERT INTO phase_groups(phase_id, team_id, group_index) VALUES ($1, $2, $3)",
+                phase_id,
+                team_id,
+                group_index as i32
+            )
+            .execute(&mut **transaction)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// Splits teams into groups of at most `group_size` teams
+/// and schedules a single round robin within each group
+/// using the circle method. Every pair of teams meets at most once.
+/// Groups with an odd number of teams get a bye in every round.
+/// Sides follow Berger tables: in odd groups every team alternates sides,
+/// in even groups no team takes the same side more than twice in a row.
+/// Returns pairings for exactly `rounds_count` rounds;
+/// rounds beyond the length of the round robin are left empty.
+pub fn generate(
+    team_ids: &[Uuid],
+    group_size: usize,
+    rounds_count: usize,
+) -> Vec<RoundPairings> {
+    let mut rounds = vec![RoundPairings::default(); rounds_count];
+
+    for group in split_into_groups(team_ids, group_size) {
+        let mut slots: Vec<Option<Uuid>> = group.into_iter().map(Some).collect();
+        if slots.len() % 2 == 1 {
+            // The bye must take the fixed slot for the sides to alternate
+            slots.insert(0, None);
+        }
+        let slots_count = slots.len();
+
+        for (round_index, round) in rounds
+            .iter_mut()
+            .take(slots_count.saturating_sub(1))
+            .enumerate()
+        {
+            for i in 0..slots_count / 2 {
+                match (slots[i], slots[slots_count - 1 - i]) {
+                    (Some(first), Some(second)) => {
+                        let first_is_proposition = match i {
+                            0 => round_index % 2 == 0,
+                            _ => i % 2 == 1,
+                        };
+                        round.pairings.push(match first_is_proposition {
+                            true => Pairing {
+                                proposition: first,
+                                opposition: second,
+                            },
+                            false => Pairing {
+                                proposition: second,
+                                opposition: first,
+                            },
+                        })
+                    }
+                    (Some(team), None) | (None, Some(team)) => round.byes.push(team),
+                    (None, None) => (),
+                }
+            }
+            slots[1..].rotate_right(1);
+        }
+    }
+
+    rounds
+}
+
+/// Distributes teams between the smallest number of groups
+/// that respects `group_size`, keeping group sizes within one of each other
+pub fn split_into_groups(team_ids: &[Uuid], group_size: usize) -> Vec<Vec<Uuid>> {
+    if team_ids.is_empty() || group_size == 0 {
+        return vec![];
+    }
+    let groups_count = team_ids.len().div_ceil(group_size);
+    let mut groups = vec![vec![]; groups_count];
+    for (index, team_id) in team_ids.iter().enumerate() {
+        groups[index % groups_count].push(*team_id);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use uuid::Uuid;
+
+    use super::generate;
+
+    fn teams(count: usize) -> Vec<Uuid> {
+        (0..count).map(|_| Uuid::now_v7()).collect()
+    }
+
+    #[test]
+    fn every_pair_of_teams_should_meet_exactly_once() {
+        for group_size in 2..=9 {
+            let team_ids = teams(group_size);
+            let rounds = generate(&team_ids, group_size, group_size);
+
+            let mut pairs = HashSet::new();
+            for round in &rounds {
+                for pairing in &round.pairings {
+                    let pair = if pairing.proposition < pairing.opposition {
+                        (pairing.proposition, pairing.opposition)
+                    } else {
+                        (pairing.opposition, pairing.proposition)
+                    };
+                    assert!(pairs.insert(pair), "a pair of teams met twice");
+                }
+            }
+            assert_eq!(pairs.len(), group_size * (group_size - 1) / 2);
+        }
+    }
+
+    #[test]
+    fn teams_should_debate_at_most_once_per_round() {
+        let team_ids = teams(7);
+        for round in generate(&team_ids, 7, 7) {
+            let mut seen = HashSet::new();
+            for pairing in &round.pairings {
+                assert!(seen.insert(pairing.proposition));
+                assert!(seen.insert(pairing.opposition));
+            }
+            for bye in &round.byes {
+                assert!(seen.insert(*bye));
+            }
+            assert_eq!(seen.len(), 7);
+        }
+    }
+
+    #[test]
+    fn sides_should_be_balanced() {
+        for group_size in 2..=10 {
+            let team_ids = teams(group_size);
+            let mut imbalance: HashMap<Uuid, i32> = HashMap::new();
+            for round in generate(&team_ids, group_size, group_size) {
+                for pairing in round.pairings {
+                    *imbalance.entry(pairing.proposition).or_default() += 1;
+                    *imbalance.entry(pairing.opposition).or_default() -= 1;
+                }
+            }
+            assert!(imbalance.values().all(|imbalance| imbalance.abs() <= 1));
+        }
+    }
+
+    #[test]
+    fn sides_should_alternate_in_odd_groups() {
+        for group_size in [3, 5, 7, 9] {
+            let team_ids = teams(group_size);
+            let mut last_sides: HashMap<Uuid, bool> = HashMap::new();
+            for round in generate(&team_ids, group_size, group_size) {
+                for pairing in round.pairings {
+                    for (team_id, is_proposition) in
+                        [(pairing.proposition, true), (pairing.opposition, false)]
+                    {
+                        let last_side = last_sides.insert(team_id, is_proposition);
+                        assert_ne!(last_side, Some(is_proposition));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn teams_should_be_split_into_balanced_groups() {
+        let team_ids = teams(10);
+        let rounds = generate(&team_ids, 4, 3);
+
+        // 10 teams with group_size = 4 make groups of 4, 3 and 3
+        assert_eq!(rounds.len(), 3);
+        for round in rounds {
+            assert_eq!(round.pairings.len(), 4);
+            assert_eq!(round.byes.len(), 2);
+        }
+    }
+}
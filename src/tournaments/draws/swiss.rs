@@ -0,0 +1,502 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use axum::http::StatusCode;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use serde_inline_default::serde_inline_default;
+use sqlx::{query, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::{
+    write_round_with_transaction, BrokenConstraint, Constraint, Draw, RoundPairings,
+    SideHistory,
+};
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_results::DebateResult, phases::Phase, rounds::Round, Tournament,
+    },
+};
+
+/// Upper bound on the number of pairings tried while avoiding rematches.
+/// Once exceeded, the draw falls back to allowing rematches.
+const PAIRING_SEARCH_BUDGET: u32 = 100_000;
+
+#[serde_inline_default]
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SwissDrawOptions {
+    /// If set, the proposed draw is returned without being saved
+    #[serde_inline_default(false)]
+    pub dry_run: bool,
+    /// Seed used to shuffle teams within brackets.
+    /// Drawing the same round with the same seed yields the same draw.
+    /// A random seed is picked and reported if none is given.
+    pub seed: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A team paired against a team from a higher bracket
+pub struct PullUp {
+    pub team_id: Uuid,
+    /// Wins of the pulled up team
+    pub wins: i32,
+    /// Wins of its opponent
+    pub opponent_wins: i32,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Result of a Swiss draw
+pub struct SwissDraw {
+    #[serde(flatten)]
+    pub draw: Draw,
+    /// Seed the draw was made with
+    pub seed: u64,
+    pub pull_ups: Vec<PullUp>,
+    pub broken_constraints: Vec<BrokenConstraint>,
+}
+
+#[derive(Clone, Default)]
+/// Standing of a team before the round is drawn
+pub struct SwissTeam {
+    pub id: Uuid,
+    /// Debates won, byes included
+    pub wins: i32,
+    pub opponents: HashSet<Uuid>,
+    pub had_bye: bool,
+}
+
+pub struct SwissPairings {
+    pub round: RoundPairings,
+    pub pull_ups: Vec<PullUp>,
+    pub broken_constraints: Vec<BrokenConstraint>,
+}
+
+/// Draws a single round of a group phase using Swiss power pairing.
+/// Win counts, past opponents, byes and sides are taken
+/// from the rounds preceding the drawn one.
+pub async fn draw_round(
+    round: &Round,
+    options: &SwissDrawOptions,
+    pool: &Pool<Postgres>,
+) -> Result<SwissDraw, OmniError> {
+    let phase = Phase::get_by_id(round.phase_id, pool).await?;
+    if phase.is_finals {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "Swiss draws are only available for rounds of group phases"
+                .to_owned(),
+        });
+    }
+
+    let tournament = Tournament::get_by_id(phase.tournament_id, pool).await?;
+    let team_ids: Vec<Uuid> = tournament
+        .get_teams(pool)
+        .await?
+        .into_iter()
+        .map(|team| team.id)
+        .collect();
+    if team_ids.len() < 2 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: "At least two teams are required to make a draw".to_owned(),
+        });
+    }
+
+    let (teams, mut sides) =
+        get_standings(round, phase.tournament_id, &team_ids, pool).await?;
+    // Seeds are kept within 32 bits so that JavaScript clients can repeat them
+    let seed = options.seed.unwrap_or_else(|| rand::random::<u32>() as u64);
+    let pairings = pair(teams, &mut sides, seed);
+
+    let mut transaction = pool.begin().await?;
+    let (debates, byes) = write_round_with_transaction(
+        round,
+        phase.tournament_id,
+        pairings.round,
+        options.dry_run,
+        &mut transaction,
+    )
+    .await?;
+    match options.dry_run {
+        true => transaction.rollback().await?,
+        false => transaction.commit().await?,
+    }
+
+    Ok(SwissDraw {
+        draw: Draw {
+            debates,
+            byes,
+            dry_run: options.dry_run,
+        },
+        seed,
+        pull_ups: pairings.pull_ups,
+        broken_constraints: pairings.broken_constraints,
+    })
+}
+
+/// Pairs teams of equal or nearby win counts, avoiding rematches
+/// and balancing sides. Teams within a bracket are shuffled using the seed
+/// with ChaCha20, so that the draw does not change with the version of the rand crate.
+/// If the number of teams is odd, the lowest ranked team
+/// that hasn't had a bye yet gets one.
+pub fn pair(
+    mut teams: Vec<SwissTeam>,
+    sides: &mut SideHistory,
+    seed: u64,
+) -> SwissPairings {
+    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+    teams.sort_by_key(|team| team.id);
+    teams.shuffle(&mut rng);
+    teams.sort_by_key(|team| Reverse(team.wins));
+
+    let mut round = RoundPairings::default();
+    let mut pull_ups = vec![];
+    let mut broken_constraints = vec![];
+
+    if teams.len() % 2 == 1 {
+        let index = teams
+            .iter()
+            .rposition(|team| !team.had_bye)
+            .unwrap_or(teams.len() - 1);
+        let team = teams.remove(index);
+        if team.had_bye {
+            broken_constraints.push(BrokenConstraint {
+                constraint: Constraint::NoRepeatByes,
+                team_ids: vec![team.id],
+            });
+        }
+        round.byes.push(team.id);
+    }
+
+    let pairs = pair_without_rematches(&teams).unwrap_or_else(|| pair_greedily(&teams));
+    for (first, second) in pairs {
+        let (first, second) = (&teams[first], &teams[second]);
+        if first.opponents.contains(&second.id) {
+            broken_constraints.push(BrokenConstraint {
+                constraint: Constraint::NoRepeatPairings,
+                team_ids: vec![first.id, second.id],
+            });
+        }
+        if first.wins != second.wins {
+            let (lower, higher) = match first.wins < second.wins {
+                true => (first, second),
+                false => (second, first),
+            };
+            pull_ups.push(PullUp {
+                team_id: lower.id,
+                wins: lower.wins,
+                opponent_wins: higher.wins,
+            });
+        }
+
+        let pairing = sides.assign_sides(first.id, second.id);
+        let unbalanced_team_ids: Vec<Uuid> = [pairing.proposition, pairing.opposition]
+            .into_iter()
+            .filter(|team_id| sides.imbalance(*team_id).abs() > 1)
+            .collect();
+        if !unbalanced_team_ids.is_empty() {
+            broken_constraints.push(BrokenConstraint {
+                constraint: Constraint::BalancedSides,
+                team_ids: unbalanced_team_ids,
+            });
+        }
+        round.pairings.push(pairing);
+    }
+
+    SwissPairings {
+        round,
+        pull_ups,
+        broken_constraints,
+    }
+}
+
+/// Pairs teams top-down, each with the closest ranked team it hasn't met yet.
+/// Backtracks when the remaining teams cannot be paired without rematches.
+fn pair_without_rematches(teams: &[SwissTeam]) -> Option<Vec<(usize, usize)>> {
+    fn search(
+        teams: &[SwissTeam],
+        paired: &mut [bool],
+        pairs: &mut Vec<(usize, usize)>,
+        budget: &mut u32,
+    ) -> bool {
+        let Some(first) = paired.iter().position(|paired| !paired) else {
+            return true;
+        };
+        paired[first] = true;
+        for second in first + 1..teams.len() {
+            if paired[second] || teams[first].opponents.contains(&teams[second].id) {
+                continue;
+            }
+            if *budget == 0 {
+                break;
+            }
+            *budget -= 1;
+
+            paired[second] = true;
+            pairs.push((first, second));
+            if search(teams, paired, pairs, budget) {
+                return true;
+            }
+            pairs.pop();
+            paired[second] = false;
+        }
+        paired[first] = false;
+        false
+    }
+
+    let mut paired = vec![false; teams.len()];
+    let mut pairs = Vec::with_capacity(teams.len() / 2);
+    let mut budget = PAIRING_SEARCH_BUDGET;
+    match search(teams, &mut paired, &mut pairs, &mut budget) {
+        true => Some(pairs),
+        false => None,
+    }
+}
+
+/// Pairs teams top-down, preferring teams that haven't met, but allowing rematches
+fn pair_greedily(teams: &[SwissTeam]) -> Vec<(usize, usize)> {
+    let mut paired = vec![false; teams.len()];
+    let mut pairs = Vec::with_capacity(teams.len() / 2);
+    while let Some(first) = paired.iter().position(|paired| !paired) {
+        paired[first] = true;
+        let unpaired = (first + 1..teams.len()).filter(|index| !paired[*index]);
+        let second = unpaired
+            .clone()
+            .find(|index| !teams[first].opponents.contains(&teams[*index].id))
+            .or_else(|| unpaired.clone().next());
+        if let Some(second) = second {
+            paired[second] = true;
+            pairs.push((first, second));
+        }
+    }
+    pairs
+}
+
+/// Computes standings and side history of teams from the rounds preceding the given one.
+/// Wins are taken from the results of debates, so a debate counts only once
+/// every Judge on its panel has ruled, with even splits settled by the tie policy.
+/// Teams left out of a round that others debated in are considered to have had a bye.
+async fn get_standings(
+    round: &Round,
+    tournament_id: Uuid,
+    team_ids: &[Uuid],
+    pool: &Pool<Postgres>,
+) -> Result<(Vec<SwissTeam>, SideHistory), OmniError> {
+    let mut previous_round_ids = vec![];
+    let mut previous_round_id = round.previous_round_id;
+    // Rounds should never loop, but a loop must not hang the draw
+    while let Some(id) = previous_round_id {
+        if id == round.id || previous_round_ids.contains(&id) {
+            break;
+        }
+        previous_round_ids.push(id);
+        previous_round_id = Round::get_by_id(id, pool).await?.previous_round_id;
+    }
+    previous_round_ids.reverse();
+
+    let assignments = query!(
+        r#"
+        SELECT a.team_id, a.debate_id, a.is_proposition, d.round_id
+        FROM debate_teams_assignments a
+        JOIN debates d ON d.id = a.debate_id
+        WHERE d.round_id = ANY($1)
+        ORDER BY a.debate_id, a.id
+        "#,
+        &previous_round_ids
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let debate_ids: HashSet<Uuid> = assignments
+        .iter()
+        .map(|assignment| assignment.debate_id)
+        .collect();
+    let mut connection = pool.acquire().await?;
+    let winners: HashMap<Uuid, bool> =
+        DebateResult::get_all(tournament_id, &mut connection)
+            .await?
+            .into_iter()
+            .filter(|result| debate_ids.contains(&result.debate_id))
+            .filter_map(|result| Some((result.debate_id, result.proposition_won?)))
+            .collect();
+
+    let mut teams: HashMap<Uuid, SwissTeam> = team_ids
+        .iter()
+        .map(|id| {
+            (
+                *id,
+                SwissTeam {
+                    id: *id,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    let mut sides = SideHistory::default();
+
+    for round_id in previous_round_ids {
+        let round_assignments: Vec<_> = assignments
+            .iter()
+            .filter(|assignment| assignment.round_id == round_id)
+            .collect();
+        if round_assignments.is_empty() {
+            continue;
+        }
+
+        let debating_team_ids: HashSet<Uuid> = round_assignments
+            .iter()
+            .map(|assignment| assignment.team_id)
+            .collect();
+        for team in teams.values_mut() {
+            if !debating_team_ids.contains(&team.id) {
+                team.wins += 1;
+                team.had_bye = true;
+            }
+        }
+
+        for assignment in &round_assignments {
+            let Some(team) = teams.get_mut(&assignment.team_id) else {
+                continue;
+            };
+            team.opponents.extend(
+                round_assignments
+                    .iter()
+                    .filter(|other| {
+                        other.debate_id == assignment.debate_id
+                            && other.team_id != assignment.team_id
+                    })
+                    .map(|other| other.team_id),
+            );
+            if let Some(is_proposition) = assignment.is_proposition {
+                sides.record(team.id, is_proposition);
+                if winners.get(&assignment.debate_id) == Some(&is_proposition) {
+                    team.wins += 1;
+                }
+            }
+        }
+    }
+
+    Ok((teams.into_values().collect(), sides))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use uuid::Uuid;
+
+    use super::{pair, SwissTeam};
+    use crate::tournaments::draws::{Constraint, SideHistory};
+
+    fn teams_with_wins(wins: &[i32]) -> Vec<SwissTeam> {
+        wins.iter()
+            .map(|wins| SwissTeam {
+                id: Uuid::now_v7(),
+                wins: *wins,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn teams_should_be_paired_within_brackets() {
+        let teams = teams_with_wins(&[2, 2, 1, 1, 0, 0]);
+        let wins_of = |id: Uuid| teams.iter().find(|team| team.id == id).unwrap().wins;
+
+        let pairings = pair(teams.clone(), &mut SideHistory::default(), 7);
+
+        assert!(pairings.pull_ups.is_empty());
+        assert!(pairings.broken_constraints.is_empty());
+        for pairing in pairings.round.pairings {
+            assert_eq!(wins_of(pairing.proposition), wins_of(pairing.opposition));
+        }
+    }
+
+    #[test]
+    fn odd_brackets_should_pull_teams_up() {
+        let teams = teams_with_wins(&[2, 1, 1, 1]);
+
+        let pairings = pair(teams, &mut SideHistory::default(), 7);
+
+        assert_eq!(pairings.pull_ups.len(), 1);
+        assert_eq!(pairings.pull_ups[0].wins, 1);
+        assert_eq!(pairings.pull_ups[0].opponent_wins, 2);
+    }
+
+    #[test]
+    fn rematches_should_be_avoided() {
+        let mut teams = teams_with_wins(&[1, 1, 0, 0]);
+        let (first, second) = (teams[0].id, teams[1].id);
+        teams[0].opponents.insert(second);
+        teams[1].opponents.insert(first);
+
+        let pairings = pair(teams, &mut SideHistory::default(), 7);
+
+        assert!(pairings.broken_constraints.is_empty());
+        for pairing in pairings.round.pairings {
+            let pair = HashSet::from([pairing.proposition, pairing.opposition]);
+            assert_ne!(pair, HashSet::from([first, second]));
+        }
+    }
+
+    #[test]
+    fn unavoidable_rematches_should_be_reported() {
+        let mut teams = teams_with_wins(&[1, 0]);
+        let (first, second) = (teams[0].id, teams[1].id);
+        teams[0].opponents.insert(second);
+        teams[1].opponents.insert(first);
+
+        let pairings = pair(teams, &mut SideHistory::default(), 7);
+
+        assert_eq!(pairings.round.pairings.len(), 1);
+        assert_eq!(pairings.broken_constraints.len(), 1);
+        assert_eq!(
+            pairings.broken_constraints[0].constraint,
+            Constraint::NoRepeatPairings
+        );
+    }
+
+    #[test]
+    fn the_lowest_team_without_a_bye_should_get_one() {
+        let mut teams = teams_with_wins(&[2, 1, 0]);
+        teams[2].had_bye = true;
+        let expected_bye = teams[1].id;
+
+        let pairings = pair(teams, &mut SideHistory::default(), 7);
+
+        assert_eq!(pairings.round.byes, vec![expected_bye]);
+        assert!(pairings.broken_constraints.is_empty());
+    }
+
+    #[test]
+    fn sides_should_be_balanced() {
+        let teams = teams_with_wins(&[0, 0]);
+        let (first, second) = (teams[0].id, teams[1].id);
+        let mut sides = SideHistory::default();
+        sides.record(first, true);
+        sides.record(second, false);
+
+        let pairings = pair(teams, &mut sides, 7);
+
+        assert_eq!(pairings.round.pairings[0].proposition, second);
+        assert_eq!(pairings.round.pairings[0].opposition, first);
+    }
+
+    #[test]
+    fn draws_should_be_deterministic_for_a_seed() {
+        let teams = teams_with_wins(&[1, 1, 1, 1, 0, 0, 0, 0, 0]);
+
+        let first = pair(teams.clone(), &mut SideHistory::default(), 42);
+        let second = pair(teams, &mut SideHistory::default(), 42);
+
+        assert_eq!(first.round.pairings, second.round.pairings);
+        assert_eq!(first.round.byes, second.round.byes);
+        assert_eq!(first.pull_ups, second.pull_ups);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Executor, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::omni_error::OmniError;
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Time it takes to get from one location of a tournament to another.
+/// It is the same in both directions. When rounds run back to back,
+/// the room allocator only sends teams and Judges to locations
+/// they can reach from their previous debate in time.
+pub struct TravelTime {
+    /// The other location
+    #[serde(skip_deserializing)]
+    pub location_id: Uuid,
+    pub minutes: i32,
+}
+
+/// Travel times between every pair of locations of a tournament.
+/// Pairs without a travel time set can be travelled between instantly.
+pub struct TravelTimes(HashMap<(Uuid, Uuid), i32>);
+
+/// Pairs of locations are stored with the lower ID first
+fn ordered(a: Uuid, b: Uuid) -> (Uuid, Uuid) {
+    match a < b {
+        true => (a, b),
+        false => (b, a),
+    }
+}
+
+impl TravelTime {
+    /// Returns travel times from the location to other locations
+    pub async fn get_all_from(
+        location_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<TravelTime>, OmniError> {
+        let travel_times = query_as!(
+            TravelTime,
+            r#"SELECT
+                (CASE WHEN from_location_id = $1 THEN to_location_id
+                ELSE from_location_id END) AS "location_id!",
+                minutes
+            FROM location_travel_times
+            WHERE from_location_id = $1 OR to_location_id = $1
+            ORDER BY minutes, 1"#,
+            location_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(travel_times)
+    }
+
+    /// Sets the travel time between the location and the other location
+    pub async fn put(
+        self,
+        location_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<TravelTime, OmniError> {
+        let (from_location_id, to_location_id) = ordered(location_id, self.location_id);
+        query!(
+            r#"INSERT INTO location_travel_times
+            (id, from_location_id, to_location_id, minutes)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (from_location_id, to_location_id)
+            DO UPDATE SET minutes = EXCLUDED.minutes"#,
+            Uuid::now_v7(),
+            from_location_id,
+            to_location_id,
+            self.minutes
+        )
+        .execute(pool)
+        .await?;
+
+        Ok(self)
+    }
+}
+
+impl TravelTimes {
+    pub async fn get<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<TravelTimes, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let travel_times = query!(
+            r#"SELECT from_location_id, to_location_id, minutes
+            FROM location_travel_times
+            JOIN locations ON locations.id = from_location_id
+            WHERE locations.tournament_id = $1"#,
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(TravelTimes(
+            travel_times
+                .into_iter()
+                .map(|t| ((t.from_location_id, t.to_location_id), t.minutes))
+                .collect(),
+        ))
+    }
+
+    /// Returns the travel time in minutes
+    pub fn between(&self, a: Uuid, b: Uuid) -> i32 {
+        match a == b {
+            true => 0,
+            false => self.0.get(&ordered(a, b)).copied().unwrap_or(0),
+        }
+    }
+}
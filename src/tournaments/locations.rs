@@ -72,6 +72,19 @@ impl Location {
         }
     }
 
+    /// Returns the location, as long as it belongs to the tournament
+    pub async fn get_by_id_in_tournament(
+        id: Uuid,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Location, OmniError> {
+        let location = Location::get_by_id(id, pool).await?;
+        match location.tournament_id == tournament_id {
+            true => Ok(location),
+            false => Err(OmniError::ResourceNotFoundError),
+        }
+    }
+
     pub async fn patch(
         self,
         new_location: LocationPatch,
@@ -113,9 +126,16 @@ impl Location {
     }
 
     pub async fn get_rooms(&self, pool: &Pool<Postgres>) -> Result<Vec<Room>, OmniError> {
-        match query_as!(Room, "SELECT * FROM rooms WHERE location_id = $1", self.id)
-            .fetch_all(pool)
-            .await
+        match query_as!(
+            Room,
+            r#"SELECT id, name, location_id, capacity, building, floor,
+            has_projector, is_step_free, is_online_capable,
+            room_is_occupied(id) AS "is_occupied!"
+            FROM rooms WHERE location_id = $1"#,
+            self.id
+        )
+        .fetch_all(pool)
+        .await
         {
             Ok(rooms) => Ok(rooms),
             Err(e) => {
@@ -1,27 +1,53 @@
-﻿use debates::Debate;
+﻿use axum::http::StatusCode;
+use chrono::TimeDelta;
+use debate_formats::{DebateFormat, DebateFormatPreset};
+use debate_results::VerdictTiePolicy;
+use debate_timers::SpeechRules;
+use debates::Debate;
 use locations::Location;
 use phases::{Phase, PhaseStatus};
 use plans::TournamentPlan;
 use rounds::Round;
-use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as, Pool, Postgres};
+use serde::{Deserialize, Deserializer, Serialize};
+use sqlx::{query, query_as, Executor, Pool, Postgres};
+use tab::TieBreakOrder;
 use teams::Team;
 use tracing::error;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::omni_error::OmniError;
+use crate::{
+    omni_error::OmniError,
+    users::{permissions::Permission, TournamentUser},
+};
 
+pub(crate) mod accessibility_requirements;
 pub(crate) mod affiliations;
 pub(crate) mod attendees;
+pub(crate) mod bracket_links;
+pub(crate) mod debate_formats;
+pub(crate) mod debate_judges;
+pub(crate) mod debate_results;
+pub(crate) mod debate_teams;
+pub(crate) mod debate_timers;
 pub(crate) mod debates;
+pub(crate) mod draws;
+pub(crate) mod location_travel_times;
 pub(crate) mod locations;
+pub(crate) mod motion_balance;
+pub(crate) mod motion_bank;
 pub(crate) mod motions;
 pub(crate) mod phases;
 pub mod plans;
 pub mod roles;
+pub(crate) mod room_bookings;
+pub(crate) mod room_closures;
 pub(crate) mod rooms;
 pub(crate) mod rounds;
+pub(crate) mod rule_overrides;
+pub(crate) mod schedules;
+pub(crate) mod side_draws;
+pub(crate) mod tab;
 pub(crate) mod teams;
 pub mod verdicts;
 
@@ -34,11 +60,17 @@ static DEFAULT_DEBATE_PREPARATION_TIME: i32 = 15;
 static DEFAULT_BEEP_ON_SPEECH_END: bool = true;
 static DEFAULT_BEEP_ON_PROTECTED_TIME: bool = true;
 static DEFAULT_VISUALIZE_PROTECTED_TIME: bool = false;
+static DEFAULT_SPEAKER_SCORE_MIN: f64 = 60.0;
+static DEFAULT_SPEAKER_SCORE_MAX: f64 = 80.0;
+static DEFAULT_SPEAKER_SCORE_STEP: f64 = 0.5;
+static DEFAULT_ALLOW_LOW_POINT_WINS: bool = false;
 
 /// Represents a tournament.
 ///
-/// TO-DO: Rules defined here will be applied to debates started by Marshals.
-#[derive(Serialize, Deserialize, ToSchema)]
+/// Speech rules defined here are applied to debate timers operated by Marshals.
+/// Speech times and protected time only apply to the Oxford format,
+/// used until the tournament chooses a debate format.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct Tournament {
     #[serde(skip_deserializing)]
@@ -60,7 +92,7 @@ pub struct Tournament {
     /// Time of a separate ad vocem speech in seconds
     ad_vocem_time: Option<i32>,
     /// In minutes. Indicates how long is the debate expected to last.
-    /// TO-DO: A debate scheduled at a particular room will block the room for this time.
+    /// A debate held in a room blocks the room for this time.
     debate_time_slot: Option<i32>,
     /// In minutes. Indicates how much time
     /// should the teams have to prepare, once the sides are drawn
@@ -71,6 +103,29 @@ pub struct Tournament {
     beep_on_protected_time: Option<bool>,
     // Indicates whether to represent protected time visually or not
     visualize_protected_time: Option<bool>,
+    /// Decides the outcome of debates in which the Judges' verdicts are split evenly.
+    #[serde(default)]
+    pub verdict_tie_policy: VerdictTiePolicy,
+    /// Order of tie-breaks used to rank teams on the tab.
+    #[serde(default)]
+    pub tab_tie_breaks: TieBreakOrder,
+    /// Lowest speaker score a Judge can give
+    pub speaker_score_min: Option<f64>,
+    /// Highest speaker score a Judge can give
+    pub speaker_score_max: Option<f64>,
+    /// Speaker scores must be a multiple of the step above speaker_score_min
+    pub speaker_score_step: Option<f64>,
+    /// Indicates whether a team can win a debate
+    /// without a higher total of speaker scores than its opponent
+    pub allow_low_point_wins: Option<bool>,
+    /// Format of debates within the tournament, unless a debate uses another one.
+    /// Can be chosen once the format is added to the tournament.
+    #[serde(skip_deserializing)]
+    pub debate_format_id: Option<Uuid>,
+    /// Makes random side draws of debates reproducible.
+    /// If empty, every side draw uses a new random seed.
+    /// Visible only to the tournament Organizers.
+    pub side_draw_seed: Option<i64>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -92,7 +147,7 @@ pub struct TournamentPatch {
     /// Time of a separate ad vocem speech in seconds
     ad_vocem_time: Option<i32>,
     /// In minutes. Indicates how long is the debate expected to last.
-    /// TO-DO: A debate scheduled at a particular room will block the room for this time.
+    /// A debate held in a room blocks the room for this time.
     debate_time_slot: Option<i32>,
     /// In minutes. Indicates how much time
     /// should the teams have to prepare, once the sides are drawn
@@ -103,6 +158,78 @@ pub struct TournamentPatch {
     beep_on_protected_time: Option<bool>,
     // Indicates whether to represent protected time visually or not
     visualize_protected_time: Option<bool>,
+    /// Decides the outcome of debates in which the Judges' verdicts are split evenly.
+    verdict_tie_policy: Option<VerdictTiePolicy>,
+    /// Order of tie-breaks used to rank teams on the tab.
+    tab_tie_breaks: Option<TieBreakOrder>,
+    /// Lowest speaker score a Judge can give
+    speaker_score_min: Option<f64>,
+    /// Highest speaker score a Judge can give
+    speaker_score_max: Option<f64>,
+    /// Speaker scores must be a multiple of the step above speaker_score_min
+    speaker_score_step: Option<f64>,
+    /// Indicates whether a team can win a debate
+    /// without a higher total of speaker scores than its opponent
+    allow_low_point_wins: Option<bool>,
+    /// Must be one of the debate formats of the tournament
+    debate_format_id: Option<Uuid>,
+    /// Makes random side draws of debates reproducible.
+    /// Null clears the seed, so that sides are drawn at random again.
+    #[serde(default, deserialize_with = "deserialize_clearable")]
+    #[schema(value_type = Option<i64>)]
+    side_draw_seed: Option<Option<i64>>,
+}
+
+struct TournamentRecord {
+    id: Uuid,
+    full_name: String,
+    shortened_name: String,
+    speech_time: Option<i32>,
+    end_protected_time: Option<i32>,
+    start_protected_time: Option<i32>,
+    ad_vocem_time: Option<i32>,
+    debate_time_slot: Option<i32>,
+    debate_preparation_time: Option<i32>,
+    beep_on_speech_end: Option<bool>,
+    beep_on_protected_time: Option<bool>,
+    visualize_protected_time: Option<bool>,
+    verdict_tie_policy: String,
+    tab_tie_breaks: Vec<String>,
+    speaker_score_min: Option<f64>,
+    speaker_score_max: Option<f64>,
+    speaker_score_step: Option<f64>,
+    allow_low_point_wins: Option<bool>,
+    debate_format_id: Option<Uuid>,
+    side_draw_seed: Option<i64>,
+}
+
+impl TryFrom<TournamentRecord> for Tournament {
+    type Error = OmniError;
+
+    fn try_from(record: TournamentRecord) -> Result<Self, Self::Error> {
+        Ok(Tournament {
+            id: record.id,
+            full_name: record.full_name,
+            shortened_name: record.shortened_name,
+            speech_time: record.speech_time,
+            end_protected_time: record.end_protected_time,
+            start_protected_time: record.start_protected_time,
+            ad_vocem_time: record.ad_vocem_time,
+            debate_time_slot: record.debate_time_slot,
+            debate_preparation_time: record.debate_preparation_time,
+            beep_on_speech_end: record.beep_on_speech_end,
+            beep_on_protected_time: record.beep_on_protected_time,
+            visualize_protected_time: record.visualize_protected_time,
+            verdict_tie_policy: record.verdict_tie_policy.into(),
+            tab_tie_breaks: TieBreakOrder::try_from(record.tab_tie_breaks)?,
+            speaker_score_min: record.speaker_score_min,
+            speaker_score_max: record.speaker_score_max,
+            speaker_score_step: record.speaker_score_step,
+            allow_low_point_wins: record.allow_low_point_wins,
+            debate_format_id: record.debate_format_id,
+            side_draw_seed: record.side_draw_seed,
+        })
+    }
 }
 
 impl Tournament {
@@ -114,8 +241,20 @@ impl Tournament {
         if tournament.shortened_name.is_empty() {
             shortened_name = shorten(&tournament.full_name);
         }
+        tournament.tab_tie_breaks.validate()?;
+        validate_speaker_score_range(
+            tournament
+                .speaker_score_min
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_MIN),
+            tournament
+                .speaker_score_max
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_MAX),
+            tournament
+                .speaker_score_step
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_STEP),
+        )?;
         match query_as!(
-            Tournament,
+            TournamentRecord,
             r#"INSERT INTO tournaments
             (
                 id,
@@ -129,9 +268,19 @@ impl Tournament {
                 debate_preparation_time,
                 beep_on_speech_end,
                 beep_on_protected_time,
-                visualize_protected_time
+                visualize_protected_time,
+                verdict_tie_policy,
+                tab_tie_breaks,
+                speaker_score_min,
+                speaker_score_max,
+                speaker_score_step,
+                allow_low_point_wins,
+                side_draw_seed
             )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) RETURNING
+        VALUES (
+            $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18,
+            $19
+        ) RETURNING
                 id,
                 full_name,
                 shortened_name,
@@ -143,7 +292,15 @@ impl Tournament {
                 debate_preparation_time,
                 beep_on_speech_end,
                 beep_on_protected_time,
-                visualize_protected_time"#,
+                visualize_protected_time,
+                verdict_tie_policy,
+                tab_tie_breaks,
+                speaker_score_min,
+                speaker_score_max,
+                speaker_score_step,
+                allow_low_point_wins,
+                debate_format_id,
+                side_draw_seed"#,
             tournament.id,
             tournament.full_name,
             shortened_name,
@@ -169,7 +326,22 @@ impl Tournament {
                 .unwrap_or(DEFAULT_BEEP_ON_PROTECTED_TIME),
             tournament
                 .visualize_protected_time
-                .unwrap_or(DEFAULT_VISUALIZE_PROTECTED_TIME)
+                .unwrap_or(DEFAULT_VISUALIZE_PROTECTED_TIME),
+            tournament.verdict_tie_policy.to_string(),
+            &tournament.tab_tie_breaks.to_strings(),
+            tournament
+                .speaker_score_min
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_MIN),
+            tournament
+                .speaker_score_max
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_MAX),
+            tournament
+                .speaker_score_step
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_STEP),
+            tournament
+                .allow_low_point_wins
+                .unwrap_or(DEFAULT_ALLOW_LOW_POINT_WINS),
+            tournament.side_draw_seed
         )
         .fetch_one(pool)
         .await
@@ -180,24 +352,28 @@ impl Tournament {
     }
 
     pub async fn get_all(pool: &Pool<Postgres>) -> Result<Vec<Tournament>, OmniError> {
-        match query_as!(Tournament, "SELECT * FROM tournaments")
+        match query_as!(TournamentRecord, "SELECT * FROM tournaments")
             .fetch_all(pool)
             .await
         {
-            Ok(tournaments) => Ok(tournaments),
+            Ok(records) => records.into_iter().map(Tournament::try_from).collect(),
             Err(e) => Err(e)?,
         }
     }
 
-    pub async fn get_by_id(
-        id: Uuid,
-        pool: &Pool<Postgres>,
-    ) -> Result<Tournament, OmniError> {
-        match query_as!(Tournament, "SELECT * FROM tournaments WHERE id = $1", id)
-            .fetch_one(pool)
-            .await
+    pub async fn get_by_id<'e, E>(id: Uuid, executor: E) -> Result<Tournament, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query_as!(
+            TournamentRecord,
+            "SELECT * FROM tournaments WHERE id = $1",
+            id
+        )
+        .fetch_one(executor)
+        .await
         {
-            Ok(tournament) => Ok(tournament),
+            Ok(record) => Tournament::try_from(record),
             Err(e) => {
                 error!("Error getting a tournament with id {id}: {e}");
                 Err(e)?
@@ -216,16 +392,45 @@ impl Tournament {
             id: self.id,
             full_name: name.clone(),
             shortened_name: shortened.clone(),
-            speech_time: patch.speech_time,
-            end_protected_time: patch.end_protected_time,
-            start_protected_time: patch.start_protected_time,
-            ad_vocem_time: patch.ad_vocem_time,
-            debate_time_slot: patch.debate_time_slot,
-            debate_preparation_time: patch.debate_preparation_time,
-            beep_on_speech_end: patch.beep_on_speech_end,
-            beep_on_protected_time: patch.beep_on_protected_time,
-            visualize_protected_time: patch.visualize_protected_time,
+            speech_time: patch.speech_time.or(self.speech_time),
+            end_protected_time: patch.end_protected_time.or(self.end_protected_time),
+            start_protected_time: patch
+                .start_protected_time
+                .or(self.start_protected_time),
+            ad_vocem_time: patch.ad_vocem_time.or(self.ad_vocem_time),
+            debate_time_slot: patch.debate_time_slot.or(self.debate_time_slot),
+            debate_preparation_time: patch
+                .debate_preparation_time
+                .or(self.debate_preparation_time),
+            beep_on_speech_end: patch.beep_on_speech_end.or(self.beep_on_speech_end),
+            beep_on_protected_time: patch
+                .beep_on_protected_time
+                .or(self.beep_on_protected_time),
+            visualize_protected_time: patch
+                .visualize_protected_time
+                .or(self.visualize_protected_time),
+            verdict_tie_policy: patch
+                .verdict_tie_policy
+                .unwrap_or(self.verdict_tie_policy),
+            tab_tie_breaks: patch.tab_tie_breaks.unwrap_or(self.tab_tie_breaks),
+            speaker_score_min: patch.speaker_score_min.or(self.speaker_score_min),
+            speaker_score_max: patch.speaker_score_max.or(self.speaker_score_max),
+            speaker_score_step: patch.speaker_score_step.or(self.speaker_score_step),
+            allow_low_point_wins: patch
+                .allow_low_point_wins
+                .or(self.allow_low_point_wins),
+            debate_format_id: patch.debate_format_id.or(self.debate_format_id),
+            side_draw_seed: patch.side_draw_seed.unwrap_or(self.side_draw_seed),
         };
+        tournament.tab_tie_breaks.validate()?;
+        let (min, max, step) = tournament.get_speaker_score_range();
+        validate_speaker_score_range(min, max, step)?;
+        DebateFormat::validate_assignment(
+            tournament.debate_format_id,
+            tournament.id,
+            pool,
+        )
+        .await?;
         match query!(
             r#"UPDATE tournaments SET
             full_name = $1,
@@ -238,8 +443,16 @@ impl Tournament {
             debate_preparation_time = $8,
             beep_on_speech_end = $9,
             beep_on_protected_time = $10,
-            visualize_protected_time = $11
-            WHERE id = $12"#,
+            visualize_protected_time = $11,
+            verdict_tie_policy = $12,
+            tab_tie_breaks = $13,
+            speaker_score_min = $14,
+            speaker_score_max = $15,
+            speaker_score_step = $16,
+            allow_low_point_wins = $17,
+            debate_format_id = $18,
+            side_draw_seed = $19
+            WHERE id = $20"#,
             tournament.full_name,
             tournament.shortened_name,
             tournament.speech_time,
@@ -251,6 +464,14 @@ impl Tournament {
             tournament.beep_on_speech_end,
             tournament.beep_on_protected_time,
             tournament.visualize_protected_time,
+            tournament.verdict_tie_policy.to_string(),
+            &tournament.tab_tie_breaks.to_strings(),
+            tournament.speaker_score_min,
+            tournament.speaker_score_max,
+            tournament.speaker_score_step,
+            tournament.allow_low_point_wins,
+            tournament.debate_format_id,
+            tournament.side_draw_seed,
             tournament.id,
         )
         .execute(pool)
@@ -275,17 +496,7 @@ impl Tournament {
         &self,
         pool: &Pool<Postgres>,
     ) -> Result<Vec<Debate>, OmniError> {
-        match query_as!(
-            Debate,
-            "SELECT * FROM debates WHERE tournament_id = $1",
-            &self.id
-        )
-        .fetch_all(pool)
-        .await
-        {
-            Ok(debates) => Ok(debates),
-            Err(e) => Err(e)?,
-        }
+        Debate::get_all(self.id, pool).await
     }
 
     pub async fn get_plan(
@@ -373,6 +584,112 @@ impl Tournament {
         }
         Ok(phases)
     }
+
+    /// Returns the Oxford format following speech rules of the tournament,
+    /// falling back to defaults for unset values
+    pub fn get_oxford_format(&self) -> DebateFormat {
+        DebateFormat::oxford(
+            self.id,
+            self.speech_time.unwrap_or(DEFAULT_SPEECH_TIME),
+            self.start_protected_time
+                .unwrap_or(DEFAULT_START_PROTECTED_TIME),
+            self.end_protected_time
+                .unwrap_or(DEFAULT_END_PROTECTED_TIME),
+            self.ad_vocem_time.unwrap_or(DEFAULT_AD_VOCEM_TIME),
+        )
+    }
+
+    /// Returns a preset debate format ready to be added to the tournament
+    pub fn get_preset_debate_format(&self, preset: DebateFormatPreset) -> DebateFormat {
+        match preset {
+            DebateFormatPreset::Oxford => self.get_oxford_format(),
+            DebateFormatPreset::Wsdc => DebateFormat::wsdc(self.id),
+            DebateFormatPreset::Australs => DebateFormat::australs(self.id),
+            DebateFormatPreset::BritishParliamentary => {
+                DebateFormat::british_parliamentary(self.id)
+            }
+        }
+    }
+
+    /// Returns speeches of a debate following the given format,
+    /// with beep settings of the tournament falling back to defaults for unset values
+    pub fn get_speech_rules(&self, format: DebateFormat) -> SpeechRules {
+        SpeechRules {
+            speeches: format.speeches,
+            ad_vocem_time: format.ad_vocem_time,
+            beep_on_speech_end: self
+                .beep_on_speech_end
+                .unwrap_or(DEFAULT_BEEP_ON_SPEECH_END),
+            beep_on_protected_time: self
+                .beep_on_protected_time
+                .unwrap_or(DEFAULT_BEEP_ON_PROTECTED_TIME),
+            visualize_protected_time: self
+                .visualize_protected_time
+                .unwrap_or(DEFAULT_VISUALIZE_PROTECTED_TIME),
+        }
+    }
+
+    /// Hides the side draw seed, unless the user is permitted to modify the tournament
+    pub fn hide_side_draw_seed(&mut self, tournament_user: &TournamentUser) {
+        if !tournament_user.has_permission(Permission::WriteTournament) {
+            self.side_draw_seed = None;
+        }
+    }
+
+    /// Returns how long the teams can prepare once the motion is released
+    pub fn get_debate_preparation_time(&self) -> TimeDelta {
+        TimeDelta::minutes(
+            self.debate_preparation_time
+                .unwrap_or(DEFAULT_DEBATE_PREPARATION_TIME)
+                .into(),
+        )
+    }
+
+    /// Returns how long a debate is expected to last
+    pub fn get_debate_time_slot(&self) -> TimeDelta {
+        TimeDelta::minutes(
+            self.debate_time_slot
+                .unwrap_or(DEFAULT_DEBATE_TIME_SLOT)
+                .into(),
+        )
+    }
+
+    /// Returns the lowest and the highest speaker score along with the step between scores
+    pub fn get_speaker_score_range(&self) -> (f64, f64, f64) {
+        (
+            self.speaker_score_min.unwrap_or(DEFAULT_SPEAKER_SCORE_MIN),
+            self.speaker_score_max.unwrap_or(DEFAULT_SPEAKER_SCORE_MAX),
+            self.speaker_score_step
+                .unwrap_or(DEFAULT_SPEAKER_SCORE_STEP),
+        )
+    }
+
+    pub fn allows_low_point_wins(&self) -> bool {
+        self.allow_low_point_wins
+            .unwrap_or(DEFAULT_ALLOW_LOW_POINT_WINS)
+    }
+}
+
+/// Tells a field set to null, which is read as Some(None),
+/// apart from a missing field, which is read as None
+fn deserialize_clearable<'de, D, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+fn validate_speaker_score_range(min: f64, max: f64, step: f64) -> Result<(), OmniError> {
+    if min > max || step <= 0.0 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: "speaker_score_min must not exceed speaker_score_max and speaker_score_step must be positive".to_owned(),
+        });
+    }
+    Ok(())
 }
 
 pub fn shorten(name: &str) -> String {
@@ -0,0 +1,340 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use sqlx::{query, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_results::{DebateResult, DebateResultStatus},
+        debates::Debate,
+        motions::Motion,
+        rounds::Round,
+        Tournament,
+    },
+};
+
+#[derive(Serialize, ToSchema, Clone, Debug, Default, PartialEq)]
+/// Side outcomes of a set of debates with a complete result
+pub struct SideBalance {
+    /// Number of debates with a complete result
+    pub debates: i64,
+    pub proposition_wins: i64,
+    pub opposition_wins: i64,
+    /// Number of debates with evenly split verdicts and no winner
+    pub ties: i64,
+    /// Number of verdicts favouring the Proposition
+    pub proposition_ballots: i64,
+    /// Number of verdicts favouring the Opposition
+    pub opposition_ballots: i64,
+    /// Share of debates with a winner won by the Proposition, from 0 to 1.
+    /// Empty if no debate has a winner.
+    pub proposition_win_rate: Option<f64>,
+    /// Share of debates with a winner won by the Opposition, from 0 to 1.
+    /// Empty if no debate has a winner.
+    pub opposition_win_rate: Option<f64>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// Side outcomes of debates on a motion within a single phase
+pub struct PhaseSideBalance {
+    pub phase_id: Uuid,
+    pub balance: SideBalance,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// Side outcomes of debates on a single motion
+pub struct MotionBalance {
+    pub motion_id: Uuid,
+    pub motion: String,
+    /// All debates on the motion
+    pub overall: SideBalance,
+    /// Debates on the motion split by the phase they took place in
+    pub phases: Vec<PhaseSideBalance>,
+    /// Debates on the motion in which every verdict favoured the winner
+    pub unanimous: SideBalance,
+    /// Debates on the motion with a split panel, including tied debates
+    pub split: SideBalance,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Balance of motions used within a tournament
+pub struct MotionBalanceReport {
+    pub motions: Vec<MotionBalance>,
+    /// Side outcomes of all debates of the tournament, regardless of their motions
+    pub side_bias: SideBalance,
+}
+
+impl SideBalance {
+    fn add(&mut self, result: &DebateResult) {
+        self.debates += 1;
+        self.proposition_ballots += result.proposition_ballots;
+        self.opposition_ballots += result.opposition_ballots;
+        match result.proposition_won {
+            Some(true) => self.proposition_wins += 1,
+            Some(false) => self.opposition_wins += 1,
+            None => self.ties += 1,
+        }
+        let decided = self.proposition_wins + self.opposition_wins;
+        if decided > 0 {
+            self.proposition_win_rate =
+                Some(self.proposition_wins as f64 / decided as f64);
+            self.opposition_win_rate = Some(self.opposition_wins as f64 / decided as f64);
+        }
+    }
+}
+
+impl MotionBalanceReport {
+    /// Computes the balance of motions used within a tournament.
+    /// Unless `include_unreleased` is set, only motions already released
+    /// are reported, while the side bias still covers every debate.
+    pub async fn get(
+        tournament_id: Uuid,
+        include_unreleased: bool,
+        pool: &Pool<Postgres>,
+    ) -> Result<MotionBalanceReport, OmniError> {
+        let mut transaction = pool.begin().await?;
+        query("SET TRANSACTION READ ONLY")
+            .execute(&mut *transaction)
+            .await?;
+        let _tournament = Tournament::get_by_id(tournament_id, &mut *transaction).await?;
+        let motions = match include_unreleased {
+            true => Motion::get_all(tournament_id, &mut *transaction).await?,
+            false => Motion::get_released(tournament_id, &mut *transaction).await?,
+        };
+        let rounds = Round::get_all(tournament_id, &mut *transaction).await?;
+        let debates = Debate::get_all(tournament_id, &mut *transaction).await?;
+        let results = DebateResult::get_all(tournament_id, &mut transaction).await?;
+        transaction.commit().await?;
+
+        Ok(compute_motion_balance(
+            &motions, &rounds, &debates, &results,
+        ))
+    }
+}
+
+/// The motion of a debate is the one assigned to the debate itself,
+/// or to its round if the debate has none.
+/// Debates without a complete result are ignored, and so are four-team debates,
+/// which have no Proposition or Opposition.
+pub fn compute_motion_balance(
+    motions: &[Motion],
+    rounds: &[Round],
+    debates: &[Debate],
+    results: &[DebateResult],
+) -> MotionBalanceReport {
+    let rounds: HashMap<Uuid, &Round> =
+        rounds.iter().map(|round| (round.id, round)).collect();
+    let results: HashMap<Uuid, &DebateResult> = results
+        .iter()
+        .filter(|result| {
+            result.status != DebateResultStatus::Incomplete
+                && result.placements.is_empty()
+        })
+        .map(|result| (result.debate_id, result))
+        .collect();
+
+    let mut side_bias = SideBalance::default();
+    let mut balances: HashMap<Uuid, (SideBalance, BTreeMap<Uuid, SideBalance>)> =
+        HashMap::new();
+    let mut unanimous: HashMap<Uuid, SideBalance> = HashMap::new();
+    let mut split: HashMap<Uuid, SideBalance> = HashMap::new();
+    for debate in debates {
+        let round = rounds.get(&debate.round_id);
+        let motion_id = debate
+            .motion_id
+            .or_else(|| round.and_then(|round| round.motion_id));
+        let result = results.get(&debate.id);
+        if let Some(result) = result {
+            side_bias.add(result);
+        }
+        let Some(motion_id) = motion_id else {
+            continue;
+        };
+        let (overall, phases) = balances.entry(motion_id).or_default();
+        let Some(result) = result else {
+            continue;
+        };
+        overall.add(result);
+        if let Some(round) = round {
+            phases.entry(round.phase_id).or_default().add(result);
+        }
+        match result.is_unanimous {
+            true => unanimous.entry(motion_id).or_default().add(result),
+            false => split.entry(motion_id).or_default().add(result),
+        }
+    }
+
+    let mut motions: Vec<&Motion> = motions
+        .iter()
+        .filter(|motion| balances.contains_key(&motion.id))
+        .collect();
+    motions.sort_by_key(|motion| motion.id);
+    let motions = motions
+        .into_iter()
+        .map(|motion| {
+            let (overall, phases) = balances.remove(&motion.id).unwrap_or_default();
+            MotionBalance {
+                motion_id: motion.id,
+                motion: motion.motion.clone(),
+                overall,
+                phases: phases
+                    .into_iter()
+                    .map(|(phase_id, balance)| PhaseSideBalance { phase_id, balance })
+                    .collect(),
+                unanimous: unanimous.remove(&motion.id).unwrap_or_default(),
+                split: split.remove(&motion.id).unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    MotionBalanceReport { motions, side_bias }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::compute_motion_balance;
+    use crate::tournaments::{
+        debate_results::{DebateResult, DebateResultStatus},
+        debates::{Debate, DebateStatus},
+        motions::Motion,
+        rounds::{Round, RoundStatus},
+    };
+
+    fn motion(tournament_id: Uuid) -> Motion {
+        Motion {
+            id: Uuid::now_v7(),
+            motion: "This House would abolish the UN Security Council.".to_owned(),
+            adinfo: None,
+            tournament_id,
+        }
+    }
+
+    fn round(phase_id: Uuid, motion_id: Option<Uuid>) -> Round {
+        Round {
+            id: Uuid::now_v7(),
+            name: "Round".to_owned(),
+            phase_id,
+            planned_start_time: None,
+            planned_end_time: None,
+            motion_id,
+            previous_round_id: None,
+            status: RoundStatus::Finished,
+            motion_release_time: None,
+        }
+    }
+
+    fn debate(tournament_id: Uuid, round_id: Uuid, motion_id: Option<Uuid>) -> Debate {
+        Debate {
+            id: Uuid::now_v7(),
+            motion_id,
+            marshal_user_id: None,
+            tournament_id,
+            round_id,
+            debate_format_id: None,
+            room_id: None,
+            status: DebateStatus::Scheduled,
+            preparation_end_time: None,
+        }
+    }
+
+    fn result(debate_id: Uuid, ballots: (i64, i64)) -> DebateResult {
+        let proposition_won = match ballots.0.cmp(&ballots.1) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Equal => None,
+        };
+        DebateResult {
+            debate_id,
+            status: match proposition_won {
+                Some(_) => DebateResultStatus::Decided,
+                None => DebateResultStatus::Tied,
+            },
+            proposition_won,
+            winner_team_id: None,
+            proposition_team_id: None,
+            opposition_team_id: None,
+            panel_size: ballots.0 + ballots.1,
+            proposition_ballots: ballots.0,
+            opposition_ballots: ballots.1,
+            is_unanimous: proposition_won.is_some() && (ballots.0 == 0 || ballots.1 == 0),
+            placements: vec![],
+        }
+    }
+
+    #[test]
+    fn debates_should_inherit_the_motion_of_their_round() {
+        let tournament_id = Uuid::now_v7();
+        let (phase_a, phase_b) = (Uuid::now_v7(), Uuid::now_v7());
+        let motion = motion(tournament_id);
+        let rounds = vec![
+            round(phase_a, Some(motion.id)),
+            round(phase_b, Some(motion.id)),
+        ];
+        let debates = vec![
+            debate(tournament_id, rounds[0].id, None),
+            debate(tournament_id, rounds[0].id, None),
+            debate(tournament_id, rounds[1].id, None),
+        ];
+        let results = vec![
+            result(debates[0].id, (3, 0)),
+            result(debates[1].id, (1, 2)),
+            result(debates[2].id, (2, 1)),
+        ];
+
+        let report = compute_motion_balance(
+            std::slice::from_ref(&motion),
+            &rounds,
+            &debates,
+            &results,
+        );
+
+        let balance = &report.motions[0];
+        assert_eq!(balance.overall.debates, 3);
+        assert_eq!(balance.overall.proposition_wins, 2);
+        assert_eq!(balance.overall.proposition_ballots, 6);
+        assert_eq!(balance.phases.len(), 2);
+        let phase_a = balance
+            .phases
+            .iter()
+            .find(|phase| phase.phase_id == phase_a)
+            .unwrap();
+        assert_eq!(phase_a.balance.proposition_win_rate, Some(0.5));
+        assert_eq!(balance.unanimous.proposition_wins, 1);
+        assert_eq!(balance.split.debates, 2);
+        assert_eq!(balance.split.opposition_wins, 1);
+    }
+
+    #[test]
+    fn side_bias_should_cover_debates_without_a_reported_motion() {
+        let tournament_id = Uuid::now_v7();
+        let phase_id = Uuid::now_v7();
+        let hidden_motion = motion(tournament_id);
+        let rounds = vec![round(phase_id, None)];
+        let debates = vec![
+            debate(tournament_id, rounds[0].id, Some(hidden_motion.id)),
+            debate(tournament_id, rounds[0].id, None),
+            debate(tournament_id, rounds[0].id, None),
+        ];
+        let results = vec![
+            result(debates[0].id, (0, 1)),
+            result(debates[1].id, (1, 1)),
+            DebateResult {
+                status: DebateResultStatus::Incomplete,
+                proposition_won: None,
+                ..result(debates[2].id, (1, 0))
+            },
+        ];
+
+        let report = compute_motion_balance(&[], &rounds, &debates, &results);
+
+        assert!(report.motions.is_empty());
+        assert_eq!(report.side_bias.debates, 2);
+        assert_eq!(report.side_bias.ties, 1);
+        assert_eq!(report.side_bias.opposition_win_rate, Some(1.0));
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{query, Error, Pool, Postgres};
+use tracing::error;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use crate::omni_error::OmniError;
+
+use super::motions::Motion;
+
+/// A motion kept outside of any tournament, so that it can be reused
+/// by adjudication cores across seasons.
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BankMotion {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    /// The main motion content, e.g. "This House would abolish the UN Security Council."
+    /// Must be unique within the motion bank.
+    pub motion: String,
+    /// Infoslide i.e. additional information. It may be required
+    /// to understand a complex motion.
+    pub adinfo: Option<String>,
+    /// Topic tags, e.g. "economics" or "international relations".
+    /// Tags are stored trimmed and lowercase.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Language the motion is written in, e.g. "en" or "pl".
+    pub language: Option<String>,
+    pub difficulty: Option<MotionDifficulty>,
+    /// Tournament the motion was originally set at, if known.
+    pub source_tournament_id: Option<Uuid>,
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BankMotionPatch {
+    motion: Option<String>,
+    adinfo: Option<String>,
+    tags: Option<Vec<String>>,
+    language: Option<String>,
+    difficulty: Option<MotionDifficulty>,
+    pub source_tournament_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Debug)]
+pub enum MotionDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// Filters applied when searching the motion bank.
+/// All of the provided filters must match.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MotionBankQuery {
+    /// Full-text search over the motion and its infoslide.
+    /// Supports quoted phrases, "or" and "-" to exclude words.
+    pub query: Option<String>,
+    /// Comma-separated list of tags. Motions must have all of them.
+    pub tags: Option<String>,
+    pub language: Option<String>,
+    pub difficulty: Option<MotionDifficulty>,
+}
+
+impl BankMotion {
+    pub async fn post(
+        motion: BankMotion,
+        pool: &Pool<Postgres>,
+    ) -> Result<BankMotion, OmniError> {
+        let motion = BankMotion {
+            tags: normalize_tags(motion.tags),
+            ..motion
+        };
+        match query!(
+            r#"INSERT INTO bank_motions
+            (id, motion, adinfo, tags, language, difficulty, source_tournament_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            motion.id,
+            motion.motion,
+            motion.adinfo,
+            &motion.tags,
+            motion.language,
+            motion.difficulty.map(|difficulty| difficulty.to_string()),
+            motion.source_tournament_id
+        )
+        .execute(pool)
+        .await
+        {
+            Ok(_) => Ok(motion),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<BankMotion, OmniError> {
+        match query!(
+            r#"SELECT id, motion, adinfo, tags, language, difficulty, source_tournament_id
+            FROM bank_motions WHERE id = $1"#,
+            id
+        )
+        .fetch_one(pool)
+        .await
+        {
+            Ok(record) => Ok(BankMotion {
+                id: record.id,
+                motion: record.motion,
+                adinfo: record.adinfo,
+                tags: record.tags,
+                language: record.language,
+                difficulty: record
+                    .difficulty
+                    .map(MotionDifficulty::try_from)
+                    .transpose()?,
+                source_tournament_id: record.source_tournament_id,
+            }),
+            Err(e) => match e {
+                Error::RowNotFound => Err(OmniError::ResourceNotFoundError),
+                _ => {
+                    error!("Failed to get a bank motion with id {id}: {e}");
+                    Err(e)?
+                }
+            },
+        }
+    }
+
+    /// Returns bank motions matching the filters,
+    /// the most relevant to the search query first
+    pub async fn search(
+        filters: MotionBankQuery,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<BankMotion>, OmniError> {
+        let search_query = filters.query.filter(|query| !query.trim().is_empty());
+        let tags = filters
+            .tags
+            .map(|tags| normalize_tags(tags.split(',').map(str::to_owned).collect()));
+        let records = query!(
+            r#"SELECT id, motion, adinfo, tags, language, difficulty, source_tournament_id
+            FROM bank_motions
+            WHERE ($1::TEXT IS NULL OR search_vector @@ websearch_to_tsquery('simple', $1))
+                AND ($2::TEXT[] IS NULL OR tags @> $2)
+                AND ($3::TEXT IS NULL OR language = $3)
+                AND ($4::TEXT IS NULL OR difficulty = $4)
+            ORDER BY
+                ts_rank(search_vector, websearch_to_tsquery('simple', COALESCE($1, ''))) DESC,
+                motion"#,
+            search_query,
+            tags.as_deref(),
+            filters.language,
+            filters.difficulty.map(|difficulty| difficulty.to_string())
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut motions = Vec::with_capacity(records.len());
+        for record in records {
+            motions.push(BankMotion {
+                id: record.id,
+                motion: record.motion,
+                adinfo: record.adinfo,
+                tags: record.tags,
+                language: record.language,
+                difficulty: record
+                    .difficulty
+                    .map(MotionDifficulty::try_from)
+                    .transpose()?,
+                source_tournament_id: record.source_tournament_id,
+            });
+        }
+        Ok(motions)
+    }
+
+    pub async fn patch(
+        self,
+        patch: BankMotionPatch,
+        pool: &Pool<Postgres>,
+    ) -> Result<BankMotion, OmniError> {
+        let motion = BankMotion {
+            id: self.id,
+            motion: patch.motion.unwrap_or(self.motion),
+            adinfo: patch.adinfo.or(self.adinfo),
+            tags: patch.tags.map(normalize_tags).unwrap_or(self.tags),
+            language: patch.language.or(self.language),
+            difficulty: patch.difficulty.or(self.difficulty),
+            source_tournament_id: patch
+                .source_tournament_id
+                .or(self.source_tournament_id),
+        };
+        match query!(
+            r#"UPDATE bank_motions SET motion = $1, adinfo = $2, tags = $3, language = $4,
+            difficulty = $5, source_tournament_id = $6 WHERE id = $7"#,
+            motion.motion,
+            motion.adinfo,
+            &motion.tags,
+            motion.language,
+            motion.difficulty.map(|difficulty| difficulty.to_string()),
+            motion.source_tournament_id,
+            motion.id,
+        )
+        .execute(pool)
+        .await
+        {
+            Ok(_) => Ok(motion),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        match query!("DELETE FROM bank_motions WHERE id = $1", self.id)
+            .execute(pool)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                error!("Error deleting a bank motion with id {}: {e}", self.id);
+                Err(e)?
+            }
+        }
+    }
+
+    /// Adds a copy of the bank motion to the motions of a tournament.
+    /// The copy is independent, so it can be adapted without affecting the bank.
+    pub async fn copy_to_tournament(
+        self,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Motion, OmniError> {
+        let motion = Motion {
+            id: Uuid::now_v7(),
+            motion: self.motion,
+            adinfo: self.adinfo,
+            tournament_id,
+        };
+        Motion::post(tournament_id, motion, pool).await
+    }
+}
+
+fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut tags: Vec<String> = tags
+        .into_iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+impl fmt::Display for MotionDifficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MotionDifficulty::Easy => write!(f, "Easy"),
+            MotionDifficulty::Medium => write!(f, "Medium"),
+            MotionDifficulty::Hard => write!(f, "Hard"),
+        }
+    }
+}
+
+impl TryFrom<String> for MotionDifficulty {
+    type Error = OmniError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Easy" => Ok(MotionDifficulty::Easy),
+            "Medium" => Ok(MotionDifficulty::Medium),
+            "Hard" => Ok(MotionDifficulty::Hard),
+            _ => {
+                error!("Unknown motion difficulty: {value}");
+                Err(OmniError::InternalServerError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_tags;
+
+    #[test]
+    fn tags_are_trimmed_lowercased_and_deduplicated() {
+        let tags = vec![
+            " Economics".to_owned(),
+            "economics ".to_owned(),
+            "".to_owned(),
+            "IR".to_owned(),
+        ];
+        assert_eq!(normalize_tags(tags), vec!["economics", "ir"]);
+    }
+}
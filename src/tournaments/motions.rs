@@ -1,6 +1,7 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
 use serde_inline_default::serde_inline_default;
-use sqlx::{query, query_as, Error, Pool, Postgres};
+use sqlx::{query, query_as, Error, Executor, Pool, Postgres};
 use tracing::error;
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -14,10 +15,15 @@ pub struct Motion {
     #[serde(default = "Uuid::now_v7")]
     pub id: Uuid,
     /// The main motion content, e.g. "This House would abolish the UN Security Council."
+    /// Must be unique within a scope of a tournament it belongs to.
     pub motion: String,
     /// Infoslide i.e. additional information. It may be required
     /// to understand a complex motion.
     pub adinfo: Option<String>,
+    /// Parent tournament ID. Taken from the request path.
+    #[serde(skip_deserializing)]
+    #[serde(default)]
+    pub tournament_id: Uuid,
 }
 
 #[serde_inline_default]
@@ -30,21 +36,23 @@ pub struct MotionPatch {
 
 impl Motion {
     pub async fn post(
+        tournament_id: Uuid,
         motion: Motion,
         connection_pool: &Pool<Postgres>,
     ) -> Result<Motion, OmniError> {
         match query_as!(
             Motion,
-            r#"INSERT INTO motions(id, motion, adinfo)
-        VALUES ($1, $2, $3) RETURNING id, motion, adinfo"#,
+            r#"INSERT INTO motions(id, motion, adinfo, tournament_id)
+        VALUES ($1, $2, $3, $4) RETURNING id, motion, adinfo, tournament_id"#,
             motion.id,
             motion.motion,
-            motion.adinfo
+            motion.adinfo,
+            tournament_id
         )
         .fetch_one(connection_pool)
         .await
         {
-            Ok(_) => Ok(motion),
+            Ok(motion) => Ok(motion),
             Err(e) => Err(e)?,
         }
     }
@@ -77,6 +85,7 @@ impl Motion {
             id: self.id,
             motion: patch.motion.unwrap_or(self.motion),
             adinfo: patch.adinfo.or(self.adinfo),
+            tournament_id: self.tournament_id,
         };
         match query!(
             "UPDATE motions SET motion = $1, adinfo = $2 WHERE id = $3",
@@ -105,13 +114,93 @@ impl Motion {
         }
     }
 
-    pub async fn get_all(pool: &Pool<Postgres>) -> Result<Vec<Motion>, OmniError> {
-        match query_as!(Motion, "SELECT * FROM motions",)
-            .fetch_all(pool)
-            .await
+    pub async fn get_all<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Motion>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query_as!(
+            Motion,
+            "SELECT * FROM motions WHERE tournament_id = $1",
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await
+        {
+            Ok(motions) => Ok(motions),
+            Err(e) => Err(e)?,
+        }
+    }
+
+    /// Returns motions of a tournament already released in any of its rounds
+    pub async fn get_released<'e, E>(
+        tournament_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Motion>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        match query_as!(
+            Motion,
+            r#"SELECT * FROM motions m
+            WHERE m.tournament_id = $1 AND EXISTS (
+                SELECT 1 FROM rounds r
+                WHERE r.motion_id = m.id AND r.motion_release_time <= NOW()
+            )"#,
+            tournament_id
+        )
+        .fetch_all(executor)
+        .await
         {
             Ok(motions) => Ok(motions),
             Err(e) => Err(e)?,
         }
     }
+
+    /// Indicates whether the motion has been released in any round
+    pub async fn is_released(&self, pool: &Pool<Postgres>) -> Result<bool, OmniError> {
+        let is_released = query!(
+            r#"SELECT EXISTS (
+                SELECT 1 FROM rounds
+                WHERE motion_id = $1 AND motion_release_time <= NOW()
+            )"#,
+            self.id
+        )
+        .fetch_one(pool)
+        .await?
+        .exists
+        .unwrap_or(false);
+        Ok(is_released)
+    }
+
+    /// Ensures that a motion assigned to a round or a debate
+    /// comes from the same tournament
+    pub async fn validate_assignment(
+        motion_id: Option<Uuid>,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        let Some(motion_id) = motion_id else {
+            return Ok(());
+        };
+        let motion_is_from_tournament = query!(
+            "SELECT EXISTS(SELECT 1 FROM motions WHERE id = $1 AND tournament_id = $2)",
+            motion_id,
+            tournament_id
+        )
+        .fetch_one(pool)
+        .await?
+        .exists
+        .unwrap_or(false);
+
+        match motion_is_from_tournament {
+            true => Ok(()),
+            false => Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The motion must be from the same tournament".to_owned(),
+            }),
+        }
+    }
 }
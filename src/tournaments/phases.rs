@@ -278,6 +278,7 @@ impl Phase {
                         motion_id: row.motion_id,
                         previous_round_id: row.previous_round_id,
                         status: RoundStatus::try_from(row.status)?,
+                        motion_release_time: row.motion_release_time,
                     };
                     rounds.push(round);
                 }
@@ -407,8 +408,9 @@ impl Phase {
         pool: &Pool<Postgres>,
     ) -> Result<bool, OmniError> {
         match query!(
-            "SELECT EXISTS (SELECT 1 FROM phases WHERE previous_phase_id = $1)",
-            self.previous_phase_id
+            "SELECT EXISTS (SELECT 1 FROM phases WHERE previous_phase_id = $1 AND id != $2)",
+            self.previous_phase_id,
+            self.id
         )
         .fetch_one(pool)
         .await
@@ -442,7 +444,7 @@ impl Phase {
                 return Ok(true);
             }
             phase_ids.push(found_phase.id);
-            next_phase = found_phase.get_previous_phase(pool).await;
+            next_phase = found_phase.get_next_phase(pool).await;
         }
         Ok(false)
     }
@@ -454,7 +456,7 @@ impl Phase {
         if self.previous_phase_id.is_some() {
             Ok(false)
         } else {
-            match query!("SELECT EXISTS (SELECT 1 FROM phases WHERE previous_phase_id is NULL AND tournament_id = $1)", self.tournament_id).fetch_one(pool).await {
+            match query!("SELECT EXISTS (SELECT 1 FROM phases WHERE previous_phase_id is NULL AND tournament_id = $1 AND id != $2)", self.tournament_id, self.id).fetch_one(pool).await {
                 Ok(result) => Ok(result.exists.unwrap()),
                 Err(e) => Err(e)?
             }
@@ -6,7 +6,8 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::tournaments::{
-    debates::Debate,
+    debate_formats::{DebateFormat, Side},
+    debates::{Debate, DebateStatus},
     phases::{Phase, PhaseStatus},
     rounds::{Round, RoundStatus},
 };
@@ -18,6 +19,8 @@ use crate::omni_error::OmniError;
 #[serde(deny_unknown_fields)]
 /// TournamentPlans can be used to plan a tournament setting up
 /// group phase rounds, groups count and advancing teams.
+/// Only the winner of a finals debate advances to the next round,
+/// so tournaments following a four-team format cannot be planned.
 pub struct TournamentPlan {
     #[serde(skip_deserializing)]
     #[serde(default = "Uuid::now_v7")]
@@ -233,6 +236,15 @@ impl TournamentPlan {
     ) -> Result<(), OmniError> {
         let (total_teams, group_phase_rounds, groups_count, _advancing_teams) =
             self.validated_values()?;
+        let teams_per_debate =
+            DebateFormat::get_teams_per_debate(self.tournament_id, &mut **transaction)
+                .await? as i32;
+        validate_teams_per_debate(
+            total_teams,
+            groups_count,
+            _advancing_teams,
+            teams_per_debate,
+        )?;
 
         // Phase
         let mut previous_phase_id: Option<Uuid> = None;
@@ -273,6 +285,7 @@ impl TournamentPlan {
                             motion_id: None,
                             previous_round_id,
                             status: RoundStatus::Planned,
+                            motion_release_time: None,
                         },
                     )
                     .await?;
@@ -288,6 +301,10 @@ impl TournamentPlan {
                                 marshal_user_id: None,
                                 tournament_id: self.tournament_id,
                                 round_id: curr_round_id,
+                                debate_format_id: None,
+                                room_id: None,
+                                status: DebateStatus::Scheduled,
+                                preparation_end_time: None,
                             },
                         )
                         .await?;
@@ -297,9 +314,11 @@ impl TournamentPlan {
                 }
             // Finals phase
             } else {
-                let mut remaining_teams = _advancing_teams / 2;
+                let mut remaining_teams = _advancing_teams;
                 // Rounds
-                for round_index in 1..=calculate_final_phase_rounds(_advancing_teams) {
+                for round_index in
+                    1..=calculate_final_phase_rounds(_advancing_teams, teams_per_debate)
+                {
                     let curr_round_id = Uuid::now_v7();
                     Round::post_with_transaction(
                         transaction,
@@ -312,12 +331,13 @@ impl TournamentPlan {
                             motion_id: None,
                             previous_round_id,
                             status: RoundStatus::Planned,
+                            motion_release_time: None,
                         },
                     )
                     .await?;
 
                     // Debates
-                    for _ in 1..=remaining_teams {
+                    for _ in 1..=remaining_teams / teams_per_debate {
                         Debate::post_with_transaction(
                             transaction,
                             self.tournament_id,
@@ -327,6 +347,10 @@ impl TournamentPlan {
                                 marshal_user_id: None,
                                 tournament_id: self.tournament_id,
                                 round_id: curr_round_id,
+                                debate_format_id: None,
+                                room_id: None,
+                                status: DebateStatus::Scheduled,
+                                preparation_end_time: None,
                             },
                         )
                         .await?;
@@ -469,14 +493,64 @@ fn validate(
     Ok(())
 }
 
-fn calculate_final_phase_rounds(advancing_teams: i32) -> i32 {
+/// Half of the teams of every finals round advance to the next one,
+/// until only a single debate remains
+fn calculate_final_phase_rounds(advancing_teams: i32, teams_per_debate: i32) -> i32 {
     let mut teams = advancing_teams;
     let mut final_phase_rounds = 0;
-    if teams != 0 {
-        while (teams & 1) == 0 {
-            final_phase_rounds += 1;
-            teams >>= 1;
-        }
+    while teams >= teams_per_debate {
+        final_phase_rounds += 1;
+        teams /= 2;
     }
     final_phase_rounds
 }
+
+/// Groups and finals must have enough teams to fill debates of the tournament's format.
+/// Only the winner of a debate advances through the bracket,
+/// so finals of four-team debates cannot be planned.
+fn validate_teams_per_debate(
+    total_teams: i32,
+    groups_count: i32,
+    advancing_teams: i32,
+    teams_per_debate: i32,
+) -> Result<(), OmniError> {
+    if teams_per_debate != Side::TWO_TEAM.len() as i32 {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: "Finals can only be planned for two-team debates".to_owned(),
+        });
+    }
+
+    if advancing_teams < teams_per_debate {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: format!(
+                "At least {teams_per_debate} teams must advance to fill a finals debate"
+            ),
+        });
+    }
+
+    if total_teams / groups_count < teams_per_debate {
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::BAD_REQUEST,
+            message: format!(
+                "Every group must have at least {teams_per_debate} teams to fill a debate"
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::calculate_final_phase_rounds;
+
+    #[test]
+    fn finals_should_end_with_a_single_debate() {
+        assert_eq!(calculate_final_phase_rounds(16, 2), 4);
+        assert_eq!(calculate_final_phase_rounds(2, 2), 1);
+        assert_eq!(calculate_final_phase_rounds(16, 4), 3);
+        assert_eq!(calculate_final_phase_rounds(4, 4), 1);
+    }
+}
@@ -50,6 +50,7 @@ impl Role {
                 P::ReadLocations,
                 P::ReadRooms,
                 P::SubmitVerdict,
+                P::OperateDebateTimers,
                 P::ReadPhases,
                 P::ReadRounds,
             ],
@@ -0,0 +1,407 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use axum::http::StatusCode;
+use chrono::TimeDelta;
+use serde::Serialize;
+use sqlx::{query, query_as, Executor, PgConnection, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debates::Debate, location_travel_times::TravelTimes, locations::Location,
+        room_closures::RoomClosure, rooms::Room, rounds::Round,
+    },
+};
+
+/// A debate holding a room for its time slot.
+/// The slot starts with the planned start time of the round
+/// and lasts for debate_time_slot minutes, following rule overrides.
+/// Debates of the same round always overlap, even if the round is not planned yet.
+/// Cancelled debates do not hold their rooms.
+pub struct RoomBooking;
+
+#[derive(Serialize, ToSchema)]
+/// A room along with the debates held in it
+pub struct RoomOccupancy {
+    #[serde(flatten)]
+    pub room: Room,
+    /// Debate being prepared, held or ruled on in the room
+    pub current_debate: Option<Debate>,
+    /// The earliest debate in the room that has not started yet
+    pub next_debate: Option<Debate>,
+    /// Closure the room is under right now
+    pub current_closure: Option<RoomClosure>,
+}
+
+/// Location a team or Judge of a debate was at for their debate
+/// of the previous round
+pub struct PreviousLocation {
+    pub location_id: Uuid,
+    /// Time between the end of the previous debate and the start of this one
+    pub break_time: Option<TimeDelta>,
+}
+
+impl PreviousLocation {
+    /// Rounds without planned times are assumed to leave enough time to travel
+    pub fn can_reach(&self, location_id: Uuid, travel_times: &TravelTimes) -> bool {
+        match self.break_time {
+            Some(break_time) => {
+                let travel_time = travel_times.between(self.location_id, location_id);
+                TimeDelta::minutes(travel_time.into()) <= break_time
+            }
+            None => true,
+        }
+    }
+}
+
+impl RoomBooking {
+    /// Rejects the given debates if any of them is held in a room
+    /// booked for another debate or closed at the same time.
+    /// Rooms of the debates stay locked until the end of the transaction,
+    /// so that concurrent bookings of a room are checked one after another.
+    /// Unlike FOR UPDATE, the lock does not wait for other transactions
+    /// inserting debates held in the room, which would deadlock concurrent bookings.
+    pub async fn validate(
+        debate_ids: &[Uuid],
+        connection: &mut PgConnection,
+    ) -> Result<(), OmniError> {
+        query!(
+            r#"SELECT id FROM rooms
+            WHERE id IN (SELECT room_id FROM debates WHERE id = ANY($1))
+            ORDER BY id
+            FOR NO KEY UPDATE"#,
+            debate_ids
+        )
+        .fetch_all(&mut *connection)
+        .await?;
+        let record = query!(
+            r#"SELECT
+            EXISTS(
+                SELECT 1 FROM room_bookings a
+                JOIN room_bookings b ON b.room_id = a.room_id AND b.debate_id <> a.debate_id
+                WHERE a.debate_id = ANY($1)
+                AND a.status <> 'Cancelled' AND b.status <> 'Cancelled'
+                AND (
+                    a.round_id = b.round_id
+                    OR (a.start_time < b.end_time AND b.start_time < a.end_time)
+                )
+            ) AS "is_double_booked!",
+            EXISTS(
+                SELECT 1 FROM room_bookings a
+                JOIN room_closures c ON c.room_id = a.room_id
+                WHERE a.debate_id = ANY($1)
+                AND a.status NOT IN ('Concluded', 'Cancelled')
+                AND c.start_time < COALESCE(a.end_time, now())
+                AND COALESCE(a.start_time, now()) < c.end_time
+            ) AS "is_closed!""#,
+            debate_ids
+        )
+        .fetch_one(&mut *connection)
+        .await?;
+
+        if record.is_double_booked {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The room is already booked for another debate at this time"
+                    .to_owned(),
+            });
+        }
+        match record.is_closed {
+            true => Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The room is closed at this time".to_owned(),
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Locks a room until the end of the transaction,
+    /// so that it is not booked while being closed
+    pub async fn lock_room(
+        room_id: Uuid,
+        connection: &mut PgConnection,
+    ) -> Result<(), OmniError> {
+        query!(
+            "SELECT id FROM rooms WHERE id = $1 FOR NO KEY UPDATE",
+            room_id
+        )
+        .fetch_optional(connection)
+        .await?;
+        Ok(())
+    }
+
+    /// Returns rooms of the tournament that are neither booked nor closed
+    /// for the whole time slot of the debate, ordered by their location and name.
+    /// Rooms too small for the debate or lacking anything its teams
+    /// and Judges need are left out.
+    pub async fn get_free_rooms<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<Room>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let rooms = query_as!(
+            Room,
+            r#"SELECT
+                rooms.id AS "id!",
+                rooms.name AS "name!",
+                rooms.location_id AS "location_id!",
+                rooms.capacity,
+                rooms.building,
+                rooms.floor,
+                rooms.has_projector AS "has_projector!",
+                rooms.is_step_free AS "is_step_free!",
+                rooms.is_online_capable AS "is_online_capable!",
+                room_is_occupied(rooms.id) AS "is_occupied!"
+            FROM rooms
+            JOIN locations ON locations.id = rooms.location_id
+            JOIN room_bookings a ON a.debate_id = $1
+            JOIN debate_room_needs needs ON needs.debate_id = $1
+            WHERE locations.tournament_id = a.tournament_id
+            AND (rooms.capacity IS NULL OR rooms.capacity >= needs.participants)
+            AND (rooms.is_step_free OR NOT needs.needs_step_free_access)
+            AND (rooms.has_projector OR NOT needs.needs_projector)
+            AND (rooms.is_online_capable OR NOT needs.needs_online_room)
+            AND NOT EXISTS(
+                SELECT 1 FROM room_closures c
+                WHERE c.room_id = rooms.id
+                AND c.start_time < COALESCE(a.end_time, now())
+                AND COALESCE(a.start_time, now()) < c.end_time
+            )
+            AND NOT EXISTS(
+                SELECT 1 FROM room_bookings b
+                WHERE b.room_id = rooms.id AND b.debate_id <> a.debate_id
+                AND b.status <> 'Cancelled'
+                AND (
+                    a.round_id = b.round_id
+                    OR (a.start_time < b.end_time AND b.start_time < a.end_time)
+                )
+            )
+            ORDER BY locations.name, rooms.name"#,
+            debate_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(rooms)
+    }
+
+    /// Returns locations of the debates of the previous round
+    /// the teams and Judges of the debate took part in, one per participant
+    async fn get_previous_locations<'e, E>(
+        debate_id: Uuid,
+        executor: E,
+    ) -> Result<Vec<PreviousLocation>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let previous_locations = query!(
+            r#"SELECT
+                rooms.location_id AS "location_id!",
+                prev.end_time AS previous_end_time,
+                this.start_time
+            FROM room_bookings this
+            JOIN rounds ON rounds.id = this.round_id
+            JOIN debate_participants this_p ON this_p.debate_id = this.debate_id
+            JOIN debate_participants prev_p ON prev_p.participant_id = this_p.participant_id
+            JOIN room_bookings prev ON prev.debate_id = prev_p.debate_id
+            JOIN rooms ON rooms.id = prev.room_id
+            WHERE this.debate_id = $1
+            AND prev.round_id = rounds.previous_round_id
+            AND prev.status <> 'Cancelled'"#,
+            debate_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(previous_locations
+            .into_iter()
+            .map(|record| PreviousLocation {
+                location_id: record.location_id,
+                break_time: record.start_time.zip(record.previous_end_time).map(
+                    |(start_time, previous_end_time)| start_time - previous_end_time,
+                ),
+            })
+            .collect())
+    }
+
+    /// Returns locations of the debates of the previous round
+    /// the Judges sat on, by the ID of the Judge
+    pub async fn get_previous_locations_of_judges<'e, E>(
+        round: &Round,
+        executor: E,
+    ) -> Result<HashMap<Uuid, PreviousLocation>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let Some(previous_round_id) = round.previous_round_id else {
+            return Ok(HashMap::new());
+        };
+        let previous_locations = query!(
+            r#"SELECT
+                a.judge_user_id,
+                rooms.location_id AS "location_id!",
+                prev.end_time AS previous_end_time
+            FROM debate_judge_assignments a
+            JOIN room_bookings prev ON prev.debate_id = a.debate_id
+            JOIN rooms ON rooms.id = prev.room_id
+            WHERE prev.round_id = $1 AND prev.status <> 'Cancelled'"#,
+            previous_round_id
+        )
+        .fetch_all(executor)
+        .await?;
+
+        Ok(previous_locations
+            .into_iter()
+            .map(|record| {
+                let previous_location = PreviousLocation {
+                    location_id: record.location_id,
+                    break_time: round
+                        .planned_start_time
+                        .zip(record.previous_end_time)
+                        .map(|(start_time, previous_end_time)| {
+                            start_time - previous_end_time
+                        }),
+                };
+                (record.judge_user_id, previous_location)
+            })
+            .collect())
+    }
+
+    /// Assigns free rooms to every debate of the round that is not held in one yet.
+    /// Debates with the most demanding needs choose their rooms first.
+    /// Teams and Judges are kept in the location of their debate
+    /// of the previous round where possible, and are never sent to a location
+    /// they cannot reach in the time between the rounds.
+    /// Fails without assigning any room if there are not enough free rooms,
+    /// or if travel times leave no free room for a debate.
+    pub async fn allocate_rooms(
+        round: &Round,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<Debate>, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let tournament_id = query!(
+            "SELECT tournament_id FROM phases WHERE id = $1",
+            round.phase_id
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .tournament_id;
+        let travel_times = TravelTimes::get(tournament_id, &mut *transaction).await?;
+        let debate_ids = query!(
+            r#"SELECT debates.id FROM debates
+            JOIN debate_room_needs needs ON needs.debate_id = debates.id
+            WHERE debates.round_id = $1 AND debates.room_id IS NULL
+            AND debates.status NOT IN ('Concluded', 'Cancelled')
+            ORDER BY
+                needs.needs_step_free_access::INTEGER
+                + needs.needs_projector::INTEGER
+                + needs.needs_online_room::INTEGER DESC,
+                needs.participants DESC,
+                debates.id"#,
+            round.id
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+        for debate in debate_ids {
+            let free_rooms =
+                RoomBooking::get_free_rooms(debate.id, &mut *transaction).await?;
+            let previous_locations =
+                RoomBooking::get_previous_locations(debate.id, &mut *transaction).await?;
+            let room = free_rooms
+                .iter()
+                .filter(|room| {
+                    previous_locations.iter().all(|previous| {
+                        previous.can_reach(room.location_id, &travel_times)
+                    })
+                })
+                .min_by_key(|room| {
+                    Reverse(
+                        previous_locations
+                            .iter()
+                            .filter(|previous| previous.location_id == room.location_id)
+                            .count(),
+                    )
+                });
+            let Some(room) = room else {
+                let message = match free_rooms.is_empty() {
+                    true => {
+                        "There are not enough free rooms for every debate of the round"
+                    }
+                    false => {
+                        "No free room can be reached in time by every team and Judge \
+                        of a debate, given the travel times between locations"
+                    }
+                };
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::CONFLICT,
+                    message: message.to_owned(),
+                });
+            };
+            query!(
+                "UPDATE debates SET room_id = $1 WHERE id = $2",
+                room.id,
+                debate.id
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+        let debates = round.get_debates(&mut *transaction).await?;
+        transaction.commit().await?;
+
+        Ok(debates)
+    }
+
+    /// Returns every room of the location with its current and next debate
+    pub async fn get_occupancy(
+        location: &Location,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<RoomOccupancy>, OmniError> {
+        let mut occupancy = vec![];
+        for room in location.get_rooms(pool).await? {
+            let current_debate_id = query!(
+                r#"SELECT debate_id AS "debate_id!" FROM room_bookings
+                WHERE room_id = $1
+                AND status IN ('Preparation', 'InProgress', 'AwaitingVerdicts')
+                ORDER BY start_time NULLS LAST
+                LIMIT 1"#,
+                room.id
+            )
+            .fetch_optional(pool)
+            .await?
+            .map(|record| record.debate_id);
+            let next_debate_id = query!(
+                r#"SELECT debate_id AS "debate_id!" FROM room_bookings
+                WHERE room_id = $1 AND status IN ('Scheduled', 'SidesDrawn')
+                ORDER BY start_time NULLS LAST, debate_id
+                LIMIT 1"#,
+                room.id
+            )
+            .fetch_optional(pool)
+            .await?
+            .map(|record| record.debate_id);
+
+            let current_debate = match current_debate_id {
+                Some(id) => Some(Debate::get_by_id(id, pool).await?),
+                None => None,
+            };
+            let next_debate = match next_debate_id {
+                Some(id) => Some(Debate::get_by_id(id, pool).await?),
+                None => None,
+            };
+            let current_closure = RoomClosure::get_current(room.id, pool).await?;
+            occupancy.push(RoomOccupancy {
+                room,
+                current_debate,
+                next_debate,
+                current_closure,
+            });
+        }
+        occupancy.sort_by(|a, b| a.room.name.cmp(&b.room.name));
+
+        Ok(occupancy)
+    }
+}
@@ -0,0 +1,137 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Executor, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{omni_error::OmniError, tournaments::room_bookings::RoomBooking};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+/// Takes a room out of use for a period of time, e.g. for maintenance.
+/// A closed room is occupied and no debate can be held in it.
+pub struct RoomClosure {
+    #[serde(skip_deserializing)]
+    #[serde(default = "Uuid::now_v7")]
+    pub id: Uuid,
+    #[serde(skip_deserializing)]
+    pub room_id: Uuid,
+    pub reason: String,
+    pub start_time: DateTime<Utc>,
+    /// Must occur after start_time
+    pub end_time: DateTime<Utc>,
+}
+
+impl RoomClosure {
+    /// Closes the room. Debates booked in the room during the closure
+    /// must be moved to other rooms first.
+    pub async fn post(
+        closure: RoomClosure,
+        pool: &Pool<Postgres>,
+    ) -> Result<RoomClosure, OmniError> {
+        if closure.start_time >= closure.end_time {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: "A closure must end after it starts".to_owned(),
+            });
+        }
+
+        let mut transaction = pool.begin().await?;
+        RoomBooking::lock_room(closure.room_id, &mut transaction).await?;
+        let room_is_booked = query!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM room_bookings
+                WHERE room_id = $1
+                AND status NOT IN ('Concluded', 'Cancelled')
+                AND $2 < COALESCE(end_time, now())
+                AND COALESCE(start_time, now()) < $3
+            )"#,
+            closure.room_id,
+            closure.start_time,
+            closure.end_time
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .exists
+        .unwrap_or(false);
+        if room_is_booked {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The room is booked for a debate during this closure".to_owned(),
+            });
+        }
+
+        query!(
+            r#"INSERT INTO room_closures(id, room_id, reason, start_time, end_time)
+            VALUES ($1, $2, $3, $4, $5)"#,
+            closure.id,
+            closure.room_id,
+            closure.reason,
+            closure.start_time,
+            closure.end_time
+        )
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        Ok(closure)
+    }
+
+    pub async fn get_by_id(
+        id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<RoomClosure, OmniError> {
+        let closure =
+            query_as!(RoomClosure, "SELECT * FROM room_closures WHERE id = $1", id)
+                .fetch_one(pool)
+                .await?;
+
+        Ok(closure)
+    }
+
+    /// Returns closures of the room, ordered by their start time
+    pub async fn get_all_for_room(
+        room_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<RoomClosure>, OmniError> {
+        let closures = query_as!(
+            RoomClosure,
+            "SELECT * FROM room_closures WHERE room_id = $1 ORDER BY start_time",
+            room_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(closures)
+    }
+
+    /// Returns the closure the room is under right now, if any
+    pub async fn get_current<'e, E>(
+        room_id: Uuid,
+        executor: E,
+    ) -> Result<Option<RoomClosure>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let closure = query_as!(
+            RoomClosure,
+            r#"SELECT * FROM room_closures
+            WHERE room_id = $1 AND start_time <= now() AND now() < end_time
+            ORDER BY end_time DESC
+            LIMIT 1"#,
+            room_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(closure)
+    }
+
+    pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        query!("DELETE FROM room_closures WHERE id = $1", self.id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
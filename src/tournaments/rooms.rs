@@ -1,4 +1,5 @@
-﻿use serde::{Deserialize, Serialize};
+﻿use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
 use sqlx::{query, query_as, Pool, Postgres};
 use utoipa::ToSchema;
 use uuid::Uuid;
@@ -15,17 +16,38 @@ pub struct Room {
     pub id: Uuid,
     /// Must be unique within a location.
     pub name: String,
-    pub remarks: Option<String>,
     pub location_id: Uuid,
+    /// Number of people the room can hold. Unknown if empty.
+    /// Rooms are only allocated to debates they can hold,
+    /// counting the speakers of the teams and the panel of Judges.
+    pub capacity: Option<i32>,
+    pub building: Option<String>,
+    /// Floor number, with 0 for the ground floor
+    pub floor: Option<i32>,
+    #[serde(default)]
+    pub has_projector: bool,
+    /// The room can be reached without stairs
+    #[serde(default)]
+    pub is_step_free: bool,
+    /// The room is equipped to hold debates with remote participants
+    #[serde(default)]
+    pub is_online_capable: bool,
+    /// Whether a debate is being prepared, held or ruled on in the room,
+    /// or the room is closed. Derived from the debates and closures of the room.
+    #[serde(skip_deserializing)]
     pub is_occupied: bool,
 }
 
 #[derive(ToSchema, Deserialize)]
 pub struct RoomPatch {
     pub name: Option<String>,
-    pub remarks: Option<String>,
     pub location_id: Option<Uuid>,
-    pub is_occupied: Option<bool>,
+    pub capacity: Option<i32>,
+    pub building: Option<String>,
+    pub floor: Option<i32>,
+    pub has_projector: Option<bool>,
+    pub is_step_free: Option<bool>,
+    pub is_online_capable: Option<bool>,
 }
 
 impl Room {
@@ -35,18 +57,28 @@ impl Room {
     ) -> Result<Room, OmniError> {
         match query_as!(
             Room,
-            r#"INSERT INTO rooms(id, name, remarks, location_id, is_occupied)
-            VALUES ($1, $2, $3, $4, $5) RETURNING id, name, remarks, location_id, is_occupied"#,
+            r#"INSERT INTO rooms(
+                id, name, location_id, capacity, building, floor,
+                has_projector, is_step_free, is_online_capable
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, name, location_id, capacity, building, floor,
+            has_projector, is_step_free, is_online_capable,
+            room_is_occupied(id) AS "is_occupied!""#,
             room.id,
             room.name,
-            room.remarks,
             room.location_id,
-            room.is_occupied,
+            room.capacity,
+            room.building,
+            room.floor,
+            room.has_projector,
+            room.is_step_free,
+            room.is_online_capable,
         )
         .fetch_one(connection_pool)
         .await
         {
-            Ok(_) => Ok(room),
+            Ok(room) => Ok(room),
             Err(e) => Err(e)?,
         }
     }
@@ -55,9 +87,16 @@ impl Room {
         id: Uuid,
         connection_pool: &Pool<Postgres>,
     ) -> Result<Room, OmniError> {
-        match query_as!(Room, "SELECT * FROM rooms WHERE id = $1", id)
-            .fetch_one(connection_pool)
-            .await
+        match query_as!(
+            Room,
+            r#"SELECT id, name, location_id, capacity, building, floor,
+            has_projector, is_step_free, is_online_capable,
+            room_is_occupied(id) AS "is_occupied!"
+            FROM rooms WHERE id = $1"#,
+            id
+        )
+        .fetch_one(connection_pool)
+        .await
         {
             Ok(room) => Ok(room),
             Err(e) => Err(e)?,
@@ -72,19 +111,30 @@ impl Room {
         let patch = Room {
             id: self.id,
             name: new_room.name.unwrap_or(self.name),
-            remarks: new_room.remarks.or(self.remarks),
             location_id: new_room.location_id.unwrap_or(self.location_id),
-            is_occupied: new_room.is_occupied.unwrap_or(self.is_occupied),
+            capacity: new_room.capacity.or(self.capacity),
+            building: new_room.building.or(self.building),
+            floor: new_room.floor.or(self.floor),
+            has_projector: new_room.has_projector.unwrap_or(self.has_projector),
+            is_step_free: new_room.is_step_free.unwrap_or(self.is_step_free),
+            is_online_capable: new_room
+                .is_online_capable
+                .unwrap_or(self.is_online_capable),
+            is_occupied: self.is_occupied,
         };
         match query!(
             r#"UPDATE rooms set name = $1,
-            remarks = $2, location_id = $3,
-            is_occupied = $4
-            WHERE id = $5"#,
+            location_id = $2, capacity = $3, building = $4, floor = $5,
+            has_projector = $6, is_step_free = $7, is_online_capable = $8
+            WHERE id = $9"#,
             patch.name,
-            patch.remarks,
             patch.location_id,
-            patch.is_occupied,
+            patch.capacity,
+            patch.building,
+            patch.floor,
+            patch.has_projector,
+            patch.is_step_free,
+            patch.is_online_capable,
             self.id,
         )
         .execute(connection_pool)
@@ -122,4 +172,36 @@ impl Room {
             Err(e) => Err(e)?,
         }
     }
+
+    /// Ensures a room assigned to a debate is in a location of the same tournament
+    pub async fn validate_assignment(
+        room_id: Option<Uuid>,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        let Some(room_id) = room_id else {
+            return Ok(());
+        };
+        let room_is_from_tournament = query!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM rooms JOIN locations ON locations.id = rooms.location_id
+                WHERE rooms.id = $1 AND locations.tournament_id = $2
+            )"#,
+            room_id,
+            tournament_id
+        )
+        .fetch_one(pool)
+        .await?
+        .exists
+        .unwrap_or(false);
+
+        match room_is_from_tournament {
+            true => Ok(()),
+            false => Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The room must be in a location of the same tournament"
+                    .to_owned(),
+            }),
+        }
+    }
 }
@@ -1,10 +1,10 @@
 ﻿use std::fmt;
 
 use axum::http::StatusCode;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 use serde_inline_default::serde_inline_default;
-use sqlx::{query, query_as, Executor, Pool, Postgres, Transaction};
+use sqlx::{query, Executor, Pool, Postgres, Transaction};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -12,9 +12,12 @@ use crate::{
     omni_error::OmniError,
     tournaments::{
         debates::{Debate, DebatePatch},
+        motions::Motion,
         phases::{Phase, PhaseStatus},
+        room_bookings::RoomBooking,
         Tournament,
     },
+    users::{permissions::Permission, TournamentUser},
 };
 
 #[serde_inline_default]
@@ -44,7 +47,12 @@ pub struct Round {
     pub previous_round_id: Option<Uuid>,
     /// Indicates whether the Round is Planned, Ongoing, or Finished.
     /// Can only be set to Ongoing, if the parent phase is Ongoing.
+    /// Can only be set to Finished once all of its debates are concluded or cancelled.
     pub status: RoundStatus,
+    /// Time at which the motion is announced. Until then, the motion
+    /// of the round and its debates is visible only to Organizers.
+    /// The teams have debate_preparation_time minutes to prepare once it is released.
+    pub motion_release_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Deserialize, ToSchema, Clone)]
@@ -59,6 +67,7 @@ pub struct RoundPatch {
     pub motion_id: Option<Uuid>,
     pub previous_round_id: Option<Uuid>,
     pub status: Option<RoundStatus>,
+    pub motion_release_time: Option<DateTime<Utc>>,
 }
 
 #[derive(Serialize, Deserialize, ToSchema, PartialEq, Clone)]
@@ -86,7 +95,8 @@ impl Round {
                 rounds.planned_end_time,
                 rounds.motion_id,
                 rounds.previous_round_id,
-                rounds.status
+                rounds.status,
+                rounds.motion_release_time
             FROM rounds
             JOIN phases ON phases.id = rounds.phase_id
             WHERE phases.tournament_id = $1
@@ -107,6 +117,7 @@ impl Round {
                 motion_id: record.motion_id,
                 previous_round_id: record.previous_round_id,
                 status: RoundStatus::try_from(record.status)?,
+                motion_release_time: record.motion_release_time,
             });
         }
 
@@ -126,8 +137,8 @@ impl Round {
     ) -> Result<Round, OmniError> {
         let record = query!(
             r#"INSERT INTO rounds
-            (id, name, phase_id, planned_start_time, planned_end_time, motion_id, previous_round_id, status)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            (id, name, phase_id, planned_start_time, planned_end_time, motion_id, previous_round_id, status, motion_release_time)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             RETURNING
                 id,
                 name,
@@ -136,7 +147,8 @@ impl Round {
                 planned_end_time,
                 motion_id,
                 previous_round_id,
-                status
+                status,
+                motion_release_time
             "#,
             round.id,
             round.name,
@@ -146,6 +158,7 @@ impl Round {
             round.motion_id,
             round.previous_round_id,
             round.status.to_string(),
+            round.motion_release_time,
         )
         .fetch_one(&mut **transaction)
         .await?;
@@ -159,6 +172,7 @@ impl Round {
             motion_id: record.motion_id,
             previous_round_id: record.previous_round_id,
             status: RoundStatus::try_from(record.status)?,
+            motion_release_time: record.motion_release_time,
         };
 
         Ok(round)
@@ -174,7 +188,8 @@ impl Round {
                 planned_end_time,
                 motion_id,
                 previous_round_id,
-                status
+                status,
+                motion_release_time
             FROM rounds WHERE id = $1"#,
             id
         )
@@ -190,6 +205,7 @@ impl Round {
             motion_id: record.motion_id,
             previous_round_id: record.previous_round_id,
             status: RoundStatus::try_from(record.status)?,
+            motion_release_time: record.motion_release_time,
         };
 
         Ok(round)
@@ -204,6 +220,13 @@ impl Round {
         let round = self
             .patch_with_transaction(&mut transaction, new_round)
             .await?;
+        let debate_ids: Vec<Uuid> = round
+            .get_debates(&mut *transaction)
+            .await?
+            .iter()
+            .map(|debate| debate.id)
+            .collect();
+        RoomBooking::validate(&debate_ids, &mut transaction).await?;
         transaction.commit().await?;
         Ok(round)
     }
@@ -221,8 +244,9 @@ impl Round {
                 planned_end_time = $4,
                 motion_id = $5,
                 previous_round_id = $6,
-                status = $7
-            WHERE id = $8
+                status = $7,
+                motion_release_time = $8
+            WHERE id = $9
             RETURNING
                 id,
                 name,
@@ -231,7 +255,8 @@ impl Round {
                 planned_end_time,
                 motion_id,
                 previous_round_id,
-                status
+                status,
+                motion_release_time
             "#,
             new_round.name,
             new_round.phase_id,
@@ -240,6 +265,7 @@ impl Round {
             new_round.motion_id,
             new_round.previous_round_id,
             new_round.status.to_string(),
+            new_round.motion_release_time,
             self.id,
         )
         .fetch_one(&mut **transaction)
@@ -254,6 +280,7 @@ impl Round {
             motion_id: record.motion_id,
             previous_round_id: record.previous_round_id,
             status: RoundStatus::try_from(record.status)?,
+            motion_release_time: record.motion_release_time,
         };
 
         Ok(round)
@@ -280,6 +307,8 @@ impl Round {
                 marshal_user_id: debate.marshal_user_id,
                 tournament_id: Some(debate.tournament_id),
                 round_id: Some(self.id),
+                debate_format_id: debate.debate_format_id,
+                room_id: debate.room_id,
             };
             debate
                 .patch_with_transaction(transaction, new_debate)
@@ -317,12 +346,7 @@ impl Round {
     where
         E: Executor<'e, Database = Postgres>,
     {
-        let debates =
-            query_as!(Debate, "SELECT * FROM debates WHERE round_id = $1", self.id)
-                .fetch_all(executor)
-                .await?;
-
-        Ok(debates)
+        Debate::get_all_of_round(self.id, executor).await
     }
 
     async fn get_parent_tournament(
@@ -362,6 +386,38 @@ impl Round {
         return Round::get_by_id(self.previous_round_id.unwrap(), pool).await;
     }
 
+    /// Indicates whether the motion of the round has been announced
+    pub fn motion_is_released(&self) -> bool {
+        self.motion_release_time
+            .is_some_and(|release_time| release_time <= Utc::now())
+    }
+
+    /// Hides the motion until it is released,
+    /// unless the user is permitted to modify motions
+    pub fn hide_unreleased_motion(&mut self, tournament_user: &TournamentUser) {
+        if !tournament_user.has_permission(Permission::WriteMotions)
+            && !self.motion_is_released()
+        {
+            self.motion_id = None;
+        }
+    }
+
+    /// Moves the planned start of the round to the end of the preparation
+    /// window, which begins once the motion is released.
+    /// The planned duration of the round is preserved.
+    pub fn start_preparation_window(&mut self, preparation_time: TimeDelta) {
+        let Some(release_time) = self.motion_release_time else {
+            return;
+        };
+        let start_time = release_time + preparation_time;
+        if let (Some(planned_start), Some(planned_end)) =
+            (self.planned_start_time, self.planned_end_time)
+        {
+            self.planned_end_time = Some(start_time + (planned_end - planned_start));
+        }
+        self.planned_start_time = Some(start_time);
+    }
+
     pub async fn validate(&self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
         if self.planned_start_time > self.planned_end_time {
             return Err(OmniError::ExplicitError {
@@ -369,6 +425,16 @@ impl Round {
                 message: "End time cannot occur before start time".to_owned(),
             });
         }
+        if self.motion_release_time.is_some()
+            && self.planned_start_time.is_some()
+            && self.motion_release_time > self.planned_start_time
+        {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: "The motion cannot be released after the round starts"
+                    .to_owned(),
+            });
+        }
         if self
             .previous_round_is_already_declared_as_previous_round_elsewhere(pool)
             .await?
@@ -392,6 +458,8 @@ impl Round {
         }
 
         let parent_phase = Phase::get_by_id(self.phase_id, pool).await?;
+        Motion::validate_assignment(self.motion_id, parent_phase.tournament_id, pool)
+            .await?;
 
         if self
             .previous_round_is_not_from_the_same_or_previous_phase(&parent_phase, pool)
@@ -414,6 +482,25 @@ impl Round {
         {
             return Err(OmniError::ExplicitError { status: StatusCode::CONFLICT, message: "A round status can only be set to ongoing, if the parent phase is ongoing".to_owned() });
         }
+        self.validate_status(pool).await
+    }
+
+    /// Checks whether all debates of the round are over, if it is to be finished
+    pub async fn validate_status(&self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
+        if self.status == RoundStatus::Finished
+            && self
+                .get_debates(pool)
+                .await?
+                .iter()
+                .any(|debate| !debate.status.is_over())
+        {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message:
+                    "A round can only be finished once all of its debates are concluded"
+                        .to_owned(),
+            });
+        }
         Ok(())
     }
 
@@ -530,9 +617,10 @@ impl RoundPatch {
             phase_id: self.phase_id.unwrap_or(round.phase_id),
             planned_start_time: self.planned_start_time.or(round.planned_start_time),
             planned_end_time: self.planned_end_time.or(round.planned_end_time),
-            motion_id: self.motion_id.or(self.motion_id),
+            motion_id: self.motion_id.or(round.motion_id),
             previous_round_id: self.previous_round_id.or(round.previous_round_id),
             status: self.status.unwrap_or(round.status),
+            motion_release_time: self.motion_release_time.or(round.motion_release_time),
         }
     }
 }
@@ -0,0 +1,521 @@
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_formats::{DebateFormat, SpeechDefinition},
+        debates::Debate,
+        rounds::Round,
+        Tournament, DEFAULT_AD_VOCEM_TIME, DEFAULT_BEEP_ON_PROTECTED_TIME,
+        DEFAULT_BEEP_ON_SPEECH_END, DEFAULT_DEBATE_PREPARATION_TIME,
+        DEFAULT_DEBATE_TIME_SLOT, DEFAULT_END_PROTECTED_TIME, DEFAULT_SPEECH_TIME,
+        DEFAULT_START_PROTECTED_TIME, DEFAULT_VISUALIZE_PROTECTED_TIME,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Replaces the speech rules of the tournament within a phase, a round or a single debate,
+/// e.g. to give finals longer speeches. Unset values are inherited.
+/// Rules are applied in order: tournament -> phase -> round -> debate.
+/// Speech times and protected time replace those of every speech of the debate format
+/// except for reply speeches, which keep their own length.
+pub struct RuleOverrides {
+    /// Speech time per speaker during a debate in seconds.
+    pub speech_time: Option<i32>,
+    /// Protected time at the end of a speech in seconds
+    pub end_protected_time: Option<i32>,
+    /// Protected time at the start of a speech in seconds
+    pub start_protected_time: Option<i32>,
+    /// Time of a separate ad vocem speech in seconds
+    pub ad_vocem_time: Option<i32>,
+    /// In minutes. Indicates how long is the debate expected to last.
+    pub debate_time_slot: Option<i32>,
+    /// In minutes. Indicates how much time
+    /// should the teams have to prepare, once the sides are drawn
+    pub debate_preparation_time: Option<i32>,
+    pub beep_on_speech_end: Option<bool>,
+    pub beep_on_protected_time: Option<bool>,
+    pub visualize_protected_time: Option<bool>,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Rules a debate is conducted by, once all overrides are applied
+/// to the settings of the tournament
+pub struct EffectiveRules {
+    /// In seconds
+    pub speech_time: i32,
+    /// In seconds
+    pub end_protected_time: i32,
+    /// In seconds
+    pub start_protected_time: i32,
+    /// In seconds
+    pub ad_vocem_time: i32,
+    /// In minutes
+    pub debate_time_slot: i32,
+    /// In minutes
+    pub debate_preparation_time: i32,
+    pub beep_on_speech_end: bool,
+    pub beep_on_protected_time: bool,
+    pub visualize_protected_time: bool,
+    /// Speeches of the debate in order, as defined by its format
+    pub speeches: Vec<SpeechDefinition>,
+}
+
+/// Level of the tournament the overrides apply to
+#[derive(Clone, Copy)]
+pub enum RuleScope {
+    Phase(Uuid),
+    Round(Uuid),
+    Debate(Uuid),
+}
+
+impl RuleScope {
+    fn ids(self) -> (Option<Uuid>, Option<Uuid>, Option<Uuid>) {
+        match self {
+            RuleScope::Phase(id) => (Some(id), None, None),
+            RuleScope::Round(id) => (None, Some(id), None),
+            RuleScope::Debate(id) => (None, None, Some(id)),
+        }
+    }
+}
+
+impl RuleOverrides {
+    /// Returns overrides of the given phase, round or debate.
+    /// If there are none, all values are empty.
+    pub async fn get(
+        scope: RuleScope,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        let (phase_id, round_id, debate_id) = scope.ids();
+        let overrides = query_as!(
+            RuleOverrides,
+            r#"SELECT speech_time, end_protected_time, start_protected_time, ad_vocem_time,
+            debate_time_slot, debate_preparation_time, beep_on_speech_end,
+            beep_on_protected_time, visualize_protected_time
+            FROM rule_overrides
+            WHERE phase_id = $1 OR round_id = $2 OR debate_id = $3"#,
+            phase_id,
+            round_id,
+            debate_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(overrides.unwrap_or_default())
+    }
+
+    /// Replaces overrides of the given phase, round or debate
+    pub async fn put(
+        self,
+        tournament_id: Uuid,
+        scope: RuleScope,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        let inherited = RuleOverrides::get_inherited(tournament_id, scope, pool).await?;
+        self.validate(&inherited)?;
+        let (phase_id, round_id, debate_id) = scope.ids();
+        let mut transaction = pool.begin().await?;
+        query!(
+            "DELETE FROM rule_overrides WHERE phase_id = $1 OR round_id = $2 OR debate_id = $3",
+            phase_id,
+            round_id,
+            debate_id
+        )
+        .execute(&mut *transaction)
+        .await?;
+        query!(
+            r#"INSERT INTO rule_overrides
+            (
+                id, phase_id, round_id, debate_id, speech_time, end_protected_time,
+                start_protected_time, ad_vocem_time, debate_time_slot, debate_preparation_time,
+                beep_on_speech_end, beep_on_protected_time, visualize_protected_time
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)"#,
+            Uuid::now_v7(),
+            phase_id,
+            round_id,
+            debate_id,
+            self.speech_time,
+            self.end_protected_time,
+            self.start_protected_time,
+            self.ad_vocem_time,
+            self.debate_time_slot,
+            self.debate_preparation_time,
+            self.beep_on_speech_end,
+            self.beep_on_protected_time,
+            self.visualize_protected_time
+        )
+        .execute(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        Ok(self)
+    }
+
+    /// Resolves the rules the given phase, round or debate inherits:
+    /// tournament -> phase -> round
+    async fn get_inherited(
+        tournament_id: Uuid,
+        scope: RuleScope,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        let mut tournament = Tournament::get_by_id(tournament_id, pool).await?;
+        let (phase_id, round_id) = match scope {
+            RuleScope::Phase(_) => (None, None),
+            RuleScope::Round(id) => {
+                let round = Round::get_by_id(id, pool).await?;
+                (Some(round.phase_id), None)
+            }
+            RuleScope::Debate(id) => {
+                let debate = Debate::get_by_id(id, pool).await?;
+                let round = Round::get_by_id(debate.round_id, pool).await?;
+                (Some(round.phase_id), Some(round.id))
+            }
+        };
+        RuleOverrides::apply_in_order(&mut tournament, phase_id, round_id, None, pool)
+            .await?;
+        Ok(RuleOverrides {
+            speech_time: tournament.speech_time,
+            end_protected_time: tournament.end_protected_time,
+            start_protected_time: tournament.start_protected_time,
+            ..Default::default()
+        })
+    }
+
+    /// Checks the overrides on their own and, once applied on top
+    /// of the inherited rules, that protected time fits within the speech
+    fn validate(&self, inherited: &RuleOverrides) -> Result<(), OmniError> {
+        let invalid = |message: &str| {
+            Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: message.to_owned(),
+            })
+        };
+        let positive = [
+            ("speech_time", self.speech_time),
+            ("debate_time_slot", self.debate_time_slot),
+        ];
+        if let Some((name, _)) = positive
+            .iter()
+            .find(|(_, value)| value.is_some_and(|value| value <= 0))
+        {
+            return invalid(&format!("{name} must be positive"));
+        }
+        let non_negative = [
+            ("start_protected_time", self.start_protected_time),
+            ("end_protected_time", self.end_protected_time),
+            ("ad_vocem_time", self.ad_vocem_time),
+            ("debate_preparation_time", self.debate_preparation_time),
+        ];
+        if let Some((name, _)) = non_negative
+            .iter()
+            .find(|(_, value)| value.is_some_and(|value| value < 0))
+        {
+            return invalid(&format!("{name} must not be negative"));
+        }
+        let overrides_speech_rules = self.speech_time.is_some()
+            || self.start_protected_time.is_some()
+            || self.end_protected_time.is_some();
+        let effective = self.clone().or(inherited.clone());
+        let protected_time = effective
+            .start_protected_time
+            .unwrap_or(DEFAULT_START_PROTECTED_TIME)
+            + effective
+                .end_protected_time
+                .unwrap_or(DEFAULT_END_PROTECTED_TIME);
+        if overrides_speech_rules
+            && protected_time > effective.speech_time.unwrap_or(DEFAULT_SPEECH_TIME)
+        {
+            return invalid("Protected time must fit within the speech");
+        }
+        Ok(())
+    }
+
+    /// Applies overrides of the phase and the round on top of the tournament rules
+    pub async fn apply_for_round(
+        tournament: &mut Tournament,
+        round: &Round,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        RuleOverrides::apply_in_order(
+            tournament,
+            Some(round.phase_id),
+            Some(round.id),
+            None,
+            pool,
+        )
+        .await
+    }
+
+    /// Applies overrides of the phase, the round and the debate
+    /// on top of the tournament rules.
+    /// Returns the overrides combined, so that they can be applied to the debate format.
+    pub async fn apply_for_debate(
+        tournament: &mut Tournament,
+        debate: &Debate,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        let round = Round::get_by_id(debate.round_id, pool).await?;
+        RuleOverrides::apply_in_order(
+            tournament,
+            Some(round.phase_id),
+            Some(round.id),
+            Some(debate.id),
+            pool,
+        )
+        .await
+    }
+
+    async fn apply_in_order(
+        tournament: &mut Tournament,
+        phase_id: Option<Uuid>,
+        round_id: Option<Uuid>,
+        debate_id: Option<Uuid>,
+        pool: &Pool<Postgres>,
+    ) -> Result<RuleOverrides, OmniError> {
+        let overrides = query_as!(
+            RuleOverrides,
+            r#"SELECT speech_time, end_protected_time, start_protected_time, ad_vocem_time,
+            debate_time_slot, debate_preparation_time, beep_on_speech_end,
+            beep_on_protected_time, visualize_protected_time
+            FROM rule_overrides
+            WHERE phase_id = $1 OR round_id = $2 OR debate_id = $3
+            ORDER BY CASE
+                WHEN phase_id IS NOT NULL THEN 0
+                WHEN round_id IS NOT NULL THEN 1
+                ELSE 2
+            END"#,
+            phase_id,
+            round_id,
+            debate_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let combined = overrides
+            .into_iter()
+            .fold(RuleOverrides::default(), |inherited, level| {
+                level.or(inherited)
+            });
+        combined.clone().apply_to(tournament);
+        Ok(combined)
+    }
+
+    /// Takes values inherited from a broader scope wherever these overrides are unset
+    fn or(self, inherited: RuleOverrides) -> RuleOverrides {
+        RuleOverrides {
+            speech_time: self.speech_time.or(inherited.speech_time),
+            end_protected_time: self.end_protected_time.or(inherited.end_protected_time),
+            start_protected_time: self
+                .start_protected_time
+                .or(inherited.start_protected_time),
+            ad_vocem_time: self.ad_vocem_time.or(inherited.ad_vocem_time),
+            debate_time_slot: self.debate_time_slot.or(inherited.debate_time_slot),
+            debate_preparation_time: self
+                .debate_preparation_time
+                .or(inherited.debate_preparation_time),
+            beep_on_speech_end: self.beep_on_speech_end.or(inherited.beep_on_speech_end),
+            beep_on_protected_time: self
+                .beep_on_protected_time
+                .or(inherited.beep_on_protected_time),
+            visualize_protected_time: self
+                .visualize_protected_time
+                .or(inherited.visualize_protected_time),
+        }
+    }
+
+    /// Replaces speech times and protected time of the speeches of a format,
+    /// leaving reply speeches as they are.
+    /// Protected time is cut down to fit within a shortened speech.
+    pub fn apply_to_format(&self, format: &mut DebateFormat) {
+        for speech in format.speeches.iter_mut().filter(|speech| !speech.is_reply) {
+            speech.duration = self.speech_time.unwrap_or(speech.duration);
+            speech.start_protected_time = self
+                .start_protected_time
+                .unwrap_or(speech.start_protected_time)
+                .min(speech.duration);
+            speech.end_protected_time = self
+                .end_protected_time
+                .unwrap_or(speech.end_protected_time)
+                .min(speech.duration - speech.start_protected_time);
+        }
+        format.ad_vocem_time = self.ad_vocem_time.unwrap_or(format.ad_vocem_time);
+    }
+
+    fn apply_to(self, tournament: &mut Tournament) {
+        tournament.speech_time = self.speech_time.or(tournament.speech_time);
+        tournament.end_protected_time =
+            self.end_protected_time.or(tournament.end_protected_time);
+        tournament.start_protected_time = self
+            .start_protected_time
+            .or(tournament.start_protected_time);
+        tournament.ad_vocem_time = self.ad_vocem_time.or(tournament.ad_vocem_time);
+        tournament.debate_time_slot =
+            self.debate_time_slot.or(tournament.debate_time_slot);
+        tournament.debate_preparation_time = self
+            .debate_preparation_time
+            .or(tournament.debate_preparation_time);
+        tournament.beep_on_speech_end =
+            self.beep_on_speech_end.or(tournament.beep_on_speech_end);
+        tournament.beep_on_protected_time = self
+            .beep_on_protected_time
+            .or(tournament.beep_on_protected_time);
+        tournament.visualize_protected_time = self
+            .visualize_protected_time
+            .or(tournament.visualize_protected_time);
+    }
+}
+
+impl EffectiveRules {
+    /// Resolves the rules of a debate: tournament -> phase -> round -> debate
+    pub async fn get_for_debate(
+        debate: &Debate,
+        pool: &Pool<Postgres>,
+    ) -> Result<EffectiveRules, OmniError> {
+        let mut tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+        let overrides =
+            RuleOverrides::apply_for_debate(&mut tournament, debate, pool).await?;
+        let mut format = DebateFormat::get_for_debate(debate, &tournament, pool).await?;
+        overrides.apply_to_format(&mut format);
+
+        Ok(EffectiveRules {
+            speech_time: tournament.speech_time.unwrap_or(DEFAULT_SPEECH_TIME),
+            end_protected_time: tournament
+                .end_protected_time
+                .unwrap_or(DEFAULT_END_PROTECTED_TIME),
+            start_protected_time: tournament
+                .start_protected_time
+                .unwrap_or(DEFAULT_START_PROTECTED_TIME),
+            ad_vocem_time: tournament.ad_vocem_time.unwrap_or(DEFAULT_AD_VOCEM_TIME),
+            debate_time_slot: tournament
+                .debate_time_slot
+                .unwrap_or(DEFAULT_DEBATE_TIME_SLOT),
+            debate_preparation_time: tournament
+                .debate_preparation_time
+                .unwrap_or(DEFAULT_DEBATE_PREPARATION_TIME),
+            beep_on_speech_end: tournament
+                .beep_on_speech_end
+                .unwrap_or(DEFAULT_BEEP_ON_SPEECH_END),
+            beep_on_protected_time: tournament
+                .beep_on_protected_time
+                .unwrap_or(DEFAULT_BEEP_ON_PROTECTED_TIME),
+            visualize_protected_time: tournament
+                .visualize_protected_time
+                .unwrap_or(DEFAULT_VISUALIZE_PROTECTED_TIME),
+            speeches: format.speeches,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::RuleOverrides;
+    use crate::tournaments::debate_formats::DebateFormat;
+
+    #[test]
+    fn overrides_should_be_applied_to_speeches_other_than_replies() {
+        let mut format = DebateFormat::wsdc(Uuid::now_v7());
+        let overrides = RuleOverrides {
+            speech_time: Some(300),
+            end_protected_time: Some(0),
+            ..Default::default()
+        };
+
+        overrides.apply_to_format(&mut format);
+
+        let (replies, speeches): (Vec<_>, Vec<_>) =
+            format.speeches.iter().partition(|speech| speech.is_reply);
+        assert!(speeches.iter().all(|speech| speech.duration == 300
+            && speech.start_protected_time == 60
+            && speech.end_protected_time == 0));
+        assert!(replies.iter().all(|speech| speech.duration == 240));
+    }
+
+    #[test]
+    fn protected_time_should_fit_within_shortened_speeches() {
+        let mut format = DebateFormat::australs(Uuid::now_v7());
+        let overrides = RuleOverrides {
+            speech_time: Some(300),
+            ..Default::default()
+        };
+
+        overrides.apply_to_format(&mut format);
+
+        let speech = &format.speeches[0];
+        assert_eq!(speech.duration, 300);
+        assert_eq!(speech.start_protected_time, 300);
+        assert_eq!(speech.end_protected_time, 0);
+    }
+
+    #[test]
+    fn overrides_should_be_rejected_unless_times_are_valid() {
+        let invalid = [
+            RuleOverrides {
+                speech_time: Some(0),
+                ..Default::default()
+            },
+            RuleOverrides {
+                debate_time_slot: Some(-5),
+                ..Default::default()
+            },
+            RuleOverrides {
+                ad_vocem_time: Some(-1),
+                ..Default::default()
+            },
+            RuleOverrides {
+                speech_time: Some(60),
+                start_protected_time: Some(30),
+                end_protected_time: Some(31),
+                ..Default::default()
+            },
+        ];
+
+        assert!(invalid
+            .iter()
+            .all(|overrides| overrides.validate(&RuleOverrides::default()).is_err()));
+        assert!(RuleOverrides {
+            speech_time: Some(60),
+            debate_preparation_time: Some(0),
+            ..Default::default()
+        }
+        .validate(&RuleOverrides::default())
+        .is_ok());
+    }
+
+    #[test]
+    fn protected_time_should_fit_within_the_inherited_speech() {
+        let inherited = RuleOverrides {
+            speech_time: Some(60),
+            ..Default::default()
+        };
+
+        assert!(RuleOverrides {
+            start_protected_time: Some(45),
+            ..Default::default()
+        }
+        .validate(&inherited)
+        .is_err());
+        assert!(RuleOverrides {
+            start_protected_time: Some(30),
+            ..Default::default()
+        }
+        .validate(&inherited)
+        .is_ok());
+        assert!(RuleOverrides {
+            debate_time_slot: Some(60),
+            ..Default::default()
+        }
+        .validate(&RuleOverrides {
+            speech_time: Some(10),
+            ..Default::default()
+        })
+        .is_ok());
+    }
+}
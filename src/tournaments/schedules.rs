@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
+use serde::Deserialize;
+use sqlx::{Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        room_bookings::RoomBooking,
+        rounds::{Round, RoundStatus},
+        rule_overrides::RuleOverrides,
+        Tournament,
+    },
+};
+
+#[derive(Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+/// Time frame the rounds of a tournament are laid out in.
+/// Rounds follow each other as set by previous_round_id.
+/// Each round takes up debate_preparation_time and debate_time_slot minutes,
+/// following rule overrides. The round is planned to start once the preparation is over.
+/// Times of day are in UTC.
+pub struct Schedule {
+    /// The first round starts no earlier than this
+    pub start_time: DateTime<Utc>,
+    /// Rounds are held every day between day_start and day_end.
+    /// A round that does not fit into a day is moved to the next one.
+    pub day_start: NaiveTime,
+    pub day_end: NaiveTime,
+    /// In minutes. Time between the end of a round and the start of the next one.
+    #[serde(default)]
+    pub minutes_between_rounds: i32,
+    /// Daily breaks (e.g. lunch), during which no round is held
+    #[serde(default)]
+    pub breaks: Vec<ScheduleBreak>,
+    /// If set, the schedule is only returned, without changing the rounds
+    #[serde(default)]
+    pub preview: bool,
+}
+
+#[derive(Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ScheduleBreak {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+#[derive(Deserialize, ToSchema, Clone)]
+#[serde(deny_unknown_fields)]
+/// Moves every round following a round by the same amount of time,
+/// e.g. once the tournament runs late
+pub struct ScheduleShift {
+    /// Rounds following this round are moved. The round itself stays in place.
+    pub after_round_id: Uuid,
+    /// In minutes. Negative values move the rounds earlier,
+    /// as long as no round starts before the round it follows ends.
+    pub minutes: i32,
+    /// If set, the moved rounds are only returned, without changing them
+    #[serde(default)]
+    pub preview: bool,
+}
+
+impl Schedule {
+    /// Returns rounds of the tournament in order, with their planned times laid out.
+    /// Rounds that are already Ongoing or Finished keep their times,
+    /// and the rounds following them are planned after them.
+    pub async fn lay_out(
+        &self,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<Round>, OmniError> {
+        self.validate()?;
+        let tournament = Tournament::get_by_id(tournament_id, pool).await?;
+        let time_between_rounds = TimeDelta::minutes(self.minutes_between_rounds.into());
+
+        let mut earliest_start = self.start_time;
+        let mut rounds = vec![];
+        for mut round in get_rounds_in_order(tournament_id, pool).await? {
+            if round.status != RoundStatus::Planned {
+                if let Some(end_time) = round.planned_end_time {
+                    earliest_start = earliest_start.max(end_time + time_between_rounds);
+                }
+                rounds.push(round);
+                continue;
+            }
+
+            let mut round_rules = tournament.clone();
+            RuleOverrides::apply_for_round(&mut round_rules, &round, pool).await?;
+            let preparation_time = round_rules.get_debate_preparation_time();
+            let length = preparation_time + round_rules.get_debate_time_slot();
+            let Some(preparation_start) = self.fit(earliest_start, length) else {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::BAD_REQUEST,
+                    message: format!("Round {} does not fit into a day", round.name),
+                });
+            };
+
+            if round.motion_release_time.is_some() && !round.motion_is_released() {
+                round.motion_release_time = Some(preparation_start);
+            }
+            round.planned_start_time = Some(preparation_start + preparation_time);
+            round.planned_end_time = Some(preparation_start + length);
+            earliest_start = preparation_start + length + time_between_rounds;
+            rounds.push(round);
+        }
+
+        Ok(rounds)
+    }
+
+    fn validate(&self) -> Result<(), OmniError> {
+        if self.day_start >= self.day_end {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: "The day must end after it starts".to_owned(),
+            });
+        }
+        if self.minutes_between_rounds < 0 {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: "Time between rounds cannot be negative".to_owned(),
+            });
+        }
+        match self.breaks.iter().all(|b| b.start < b.end) {
+            true => Ok(()),
+            false => Err(OmniError::ExplicitError {
+                status: StatusCode::BAD_REQUEST,
+                message: "A break must end after it starts".to_owned(),
+            }),
+        }
+    }
+
+    /// Returns the earliest start of a block of time of the given length,
+    /// such that it ends the same day and does not overlap any break.
+    /// Returns None if the block does not fit into any day.
+    fn fit(
+        &self,
+        earliest_start: DateTime<Utc>,
+        length: TimeDelta,
+    ) -> Option<DateTime<Utc>> {
+        let at = |day: NaiveDate, time: NaiveTime| day.and_time(time).and_utc();
+        let mut start = earliest_start;
+        let mut days_skipped = 0;
+        loop {
+            let day = start.date_naive();
+            start = start.max(at(day, self.day_start));
+            let end = start + length;
+            if end > at(day, self.day_end) {
+                // Once a whole day has been tried, no other day will do
+                days_skipped += 1;
+                if days_skipped > 1 {
+                    return None;
+                }
+                start = at(day.succ_opt()?, self.day_start);
+                continue;
+            }
+            let overlapping_break = self
+                .breaks
+                .iter()
+                .find(|b| start < at(day, b.end) && at(day, b.start) < end);
+            match overlapping_break {
+                Some(b) => start = at(day, b.end),
+                None => return Some(start),
+            }
+        }
+    }
+
+    /// Saves planned times of the rounds. Fails if any room
+    /// would end up double booked or used while it is closed.
+    pub async fn apply(
+        rounds: Vec<Round>,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<Round>, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let mut patched_rounds = Vec::with_capacity(rounds.len());
+        let mut debate_ids = vec![];
+        for round in rounds {
+            let patched_round = round
+                .clone()
+                .patch_with_transaction(&mut transaction, round)
+                .await?;
+            for debate in patched_round.get_debates(&mut *transaction).await? {
+                debate_ids.push(debate.id);
+            }
+            patched_rounds.push(patched_round);
+        }
+        RoomBooking::validate(&debate_ids, &mut transaction).await?;
+        transaction.commit().await?;
+
+        Ok(patched_rounds)
+    }
+}
+
+impl ScheduleShift {
+    /// Returns the Planned rounds following the given one, moved by the given time.
+    /// Rounds that are already Ongoing or Finished stay in place.
+    /// Motions that are not released yet are moved along with their rounds.
+    /// Fails if a round would start before the round it follows ends.
+    pub async fn shift(
+        &self,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<Round>, OmniError> {
+        let rounds = get_rounds_in_order(tournament_id, pool).await?;
+        let Some(position) = rounds
+            .iter()
+            .position(|round| round.id == self.after_round_id)
+        else {
+            return Err(OmniError::ResourceNotFoundError);
+        };
+
+        let delay = TimeDelta::minutes(self.minutes.into());
+        let mut previous_round = &rounds[position];
+        let mut previous_end_time = previous_round.planned_end_time;
+        let mut shifted_rounds = vec![];
+        for round in &rounds[position + 1..] {
+            if round.status != RoundStatus::Planned {
+                previous_round = round;
+                previous_end_time = round.planned_end_time;
+                continue;
+            }
+
+            let mut shifted_round = round.clone();
+            if !shifted_round.motion_is_released() {
+                shifted_round.motion_release_time =
+                    shifted_round.motion_release_time.map(|time| time + delay);
+            }
+            shifted_round.planned_start_time =
+                shifted_round.planned_start_time.map(|time| time + delay);
+            shifted_round.planned_end_time =
+                shifted_round.planned_end_time.map(|time| time + delay);
+            if let (Some(start_time), Some(end_time)) =
+                (shifted_round.planned_start_time, previous_end_time)
+            {
+                if start_time < end_time {
+                    return Err(OmniError::ExplicitError {
+                        status: StatusCode::BAD_REQUEST,
+                        message: format!(
+                            "Round {} would start before round {} ends",
+                            round.name, previous_round.name
+                        ),
+                    });
+                }
+            }
+
+            previous_round = round;
+            previous_end_time = shifted_round.planned_end_time;
+            shifted_rounds.push(shifted_round);
+        }
+
+        Ok(shifted_rounds)
+    }
+}
+
+/// Returns rounds of the tournament, starting with the first one
+/// and following previous_round_id
+async fn get_rounds_in_order(
+    tournament_id: Uuid,
+    pool: &Pool<Postgres>,
+) -> Result<Vec<Round>, OmniError> {
+    put_in_order(Round::get_all(tournament_id, pool).await?)
+}
+
+/// Fails if the rounds do not form a single line,
+/// i.e. two rounds follow the same round or some round cannot be reached
+fn put_in_order(all_rounds: Vec<Round>) -> Result<Vec<Round>, OmniError> {
+    let mut rounds_by_previous: HashMap<Option<Uuid>, Round> = HashMap::new();
+    for round in all_rounds {
+        if let Some(other_round) = rounds_by_previous.get(&round.previous_round_id) {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: format!(
+                    "Rounds {} and {} cannot both follow the same round",
+                    other_round.name, round.name
+                ),
+            });
+        }
+        rounds_by_previous.insert(round.previous_round_id, round);
+    }
+
+    let mut rounds = vec![];
+    let mut previous_round_id = None;
+    while let Some(round) = rounds_by_previous.remove(&previous_round_id) {
+        previous_round_id = Some(round.id);
+        rounds.push(round);
+    }
+
+    if !rounds_by_previous.is_empty() {
+        let mut unreachable_rounds: Vec<_> = rounds_by_previous
+            .into_values()
+            .map(|round| round.name)
+            .collect();
+        unreachable_rounds.sort();
+        return Err(OmniError::ExplicitError {
+            status: StatusCode::CONFLICT,
+            message: format!(
+                "Rounds {} do not follow the first round of the tournament",
+                unreachable_rounds.join(", ")
+            ),
+        });
+    }
+
+    Ok(rounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+    use uuid::Uuid;
+
+    use crate::tournaments::rounds::{Round, RoundStatus};
+
+    use super::{put_in_order, Schedule, ScheduleBreak};
+
+    fn time(time: &str) -> NaiveTime {
+        time.parse().unwrap()
+    }
+
+    fn date_time(date_time: &str) -> DateTime<Utc> {
+        date_time.parse().unwrap()
+    }
+
+    fn get_schedule() -> Schedule {
+        Schedule {
+            start_time: date_time("2026-05-16T09:00:00Z"),
+            day_start: time("09:00:00"),
+            day_end: time("18:00:00"),
+            minutes_between_rounds: 0,
+            breaks: vec![ScheduleBreak {
+                start: time("12:30:00"),
+                end: time("13:30:00"),
+            }],
+            preview: false,
+        }
+    }
+
+    #[test]
+    fn rounds_should_not_overlap_breaks() {
+        let schedule = get_schedule();
+        let start = schedule.fit(date_time("2026-05-16T11:00:00Z"), TimeDelta::hours(2));
+        assert_eq!(start, Some(date_time("2026-05-16T13:30:00Z")));
+    }
+
+    #[test]
+    fn rounds_should_be_moved_to_the_next_day() {
+        let schedule = get_schedule();
+        let start = schedule.fit(date_time("2026-05-16T16:00:00Z"), TimeDelta::hours(3));
+        assert_eq!(start, Some(date_time("2026-05-17T09:00:00Z")));
+    }
+
+    #[test]
+    fn rounds_longer_than_the_time_between_breaks_should_not_fit() {
+        let schedule = get_schedule();
+        let start = schedule.fit(date_time("2026-05-16T09:00:00Z"), TimeDelta::hours(5));
+        assert_eq!(start, None);
+    }
+
+    fn get_round(name: &str, previous_round: Option<&Round>) -> Round {
+        Round {
+            id: Uuid::now_v7(),
+            name: name.to_owned(),
+            phase_id: Uuid::now_v7(),
+            planned_start_time: None,
+            planned_end_time: None,
+            motion_id: None,
+            previous_round_id: previous_round.map(|round| round.id),
+            status: RoundStatus::Planned,
+            motion_release_time: None,
+        }
+    }
+
+    fn get_names(rounds: Vec<Round>) -> Vec<String> {
+        rounds.into_iter().map(|round| round.name).collect()
+    }
+
+    #[test]
+    fn rounds_should_be_put_in_order() {
+        let first = get_round("first", None);
+        let second = get_round("second", Some(&first));
+        let third = get_round("third", Some(&second));
+        let rounds = put_in_order(vec![third, first, second]).unwrap();
+        assert_eq!(get_names(rounds), ["first", "second", "third"]);
+    }
+
+    #[test]
+    fn rounds_following_the_same_round_should_be_rejected() {
+        let first = get_round("first", None);
+        let second = get_round("second", Some(&first));
+        let other_second = get_round("other second", Some(&first));
+        assert!(put_in_order(vec![first, second, other_second]).is_err());
+    }
+
+    #[test]
+    fn unreachable_rounds_should_be_rejected() {
+        let first = get_round("first", None);
+        let mut looped = get_round("looped", None);
+        let other_looped = get_round("other looped", Some(&looped));
+        looped.previous_round_id = Some(other_looped.id);
+        assert!(put_in_order(vec![first, looped, other_looped]).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::Serialize;
+use sha2::Sha256;
+use sqlx::{query, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_formats::{DebateFormat, Side},
+        debate_teams::DebateTeamAssignment,
+        debates::{Debate, DebateStatus},
+        Tournament,
+    },
+};
+
+#[derive(Serialize, ToSchema)]
+/// Record of a random draw of sides (a coin toss) in a debate.
+/// Shuffling the teams of the debate, ordered by their IDs,
+/// with the same seed yields the same sides.
+pub struct SideDraw {
+    pub id: Uuid,
+    pub debate_id: Uuid,
+    /// Seed of the random generator that drew the sides
+    pub seed: i64,
+    pub drawn_at: DateTime<Utc>,
+    /// ID of the user who triggered the draw
+    pub drawn_by_user_id: Uuid,
+    /// Sides given to the teams, in the order of positions of the debate format
+    pub sides: Vec<DrawnSide>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct DrawnSide {
+    pub team_id: Uuid,
+    pub side: Side,
+}
+
+impl SideDraw {
+    /// Randomly assigns the teams of a debate to the positions of its format
+    /// and records the draw. If the tournament has a side_draw_seed,
+    /// the seed of the draw is derived from it, so the draw can be reproduced.
+    /// The debate stays locked during the draw, so that its status and teams
+    /// cannot change before the sides are stored.
+    pub async fn draw(
+        debate: Debate,
+        user_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<SideDraw, OmniError> {
+        let mut transaction = pool.begin().await?;
+        let debate = Debate::lock_with_transaction(debate.id, &mut transaction).await?;
+        if !matches!(
+            debate.status,
+            DebateStatus::Scheduled | DebateStatus::SidesDrawn
+        ) {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "Sides can only be drawn before the preparation starts"
+                    .to_owned(),
+            });
+        }
+        let tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+        let format = DebateFormat::get_for_debate(&debate, &tournament, pool).await?;
+        let mut assignments =
+            DebateTeamAssignment::get_by_debate_id(debate.id, &mut *transaction).await?;
+        if assignments.len() != format.teams_per_debate() {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: format!(
+                    "Sides can only be drawn once {} teams are assigned to the debate",
+                    format.teams_per_debate()
+                ),
+            });
+        }
+
+        let seed = match tournament.side_draw_seed {
+            Some(tournament_seed) => derive_seed(tournament_seed, debate.id),
+            None => rand::random::<u32>() as i64,
+        };
+        assignments.sort_by_key(|assignment| assignment.team_id);
+        shuffle(&mut assignments, seed);
+
+        let mut sides = Vec::with_capacity(assignments.len());
+        for (assignment, side) in assignments.iter().zip(format.positions()) {
+            assignment
+                .assign_side_with_transaction(*side, &mut transaction)
+                .await?;
+            sides.push(DrawnSide {
+                team_id: assignment.team_id,
+                side: *side,
+            });
+        }
+        let side_draw = SideDraw {
+            id: Uuid::now_v7(),
+            debate_id: debate.id,
+            seed,
+            drawn_at: Utc::now(),
+            drawn_by_user_id: user_id,
+            sides,
+        };
+        query!(
+            r#"INSERT INTO side_draws
+            (id, debate_id, seed, drawn_at, drawn_by_user_id, team_ids, sides)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+            side_draw.id,
+            side_draw.debate_id,
+            side_draw.seed,
+            side_draw.drawn_at,
+            side_draw.drawn_by_user_id,
+            &side_draw
+                .sides
+                .iter()
+                .map(|drawn| drawn.team_id)
+                .collect::<Vec<Uuid>>(),
+            &side_draw
+                .sides
+                .iter()
+                .map(|drawn| drawn.side.to_string())
+                .collect::<Vec<String>>(),
+        )
+        .execute(&mut *transaction)
+        .await?;
+        Debate::mark_sides_drawn(debate.id, &mut *transaction).await?;
+        transaction.commit().await?;
+
+        Ok(side_draw)
+    }
+
+    /// Returns all draws of a debate, starting with the earliest
+    pub async fn get_all_of_debate(
+        debate_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<SideDraw>, OmniError> {
+        let records = query!(
+            "SELECT * FROM side_draws WHERE debate_id = $1 ORDER BY drawn_at",
+            debate_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut side_draws = Vec::with_capacity(records.len());
+        for record in records {
+            let mut sides = Vec::with_capacity(record.sides.len());
+            for (team_id, side) in record.team_ids.into_iter().zip(record.sides) {
+                sides.push(DrawnSide {
+                    team_id,
+                    side: Side::try_from(side)?,
+                });
+            }
+            side_draws.push(SideDraw {
+                id: record.id,
+                debate_id: record.debate_id,
+                seed: record.seed,
+                drawn_at: record.drawn_at,
+                drawn_by_user_id: record.drawn_by_user_id,
+                sides,
+            });
+        }
+
+        Ok(side_draws)
+    }
+}
+
+/// Derives the seed of a debate from the seed of the tournament with HMAC-SHA256,
+/// so that every debate is drawn independently and the seeds of draws,
+/// which are shown to everyone, do not reveal the seed of the tournament
+fn derive_seed(tournament_seed: i64, debate_id: Uuid) -> i64 {
+    let mut mac = Hmac::<Sha256>::new_from_slice(&tournament_seed.to_be_bytes())
+        .expect("HMAC takes keys of any length");
+    mac.update(debate_id.as_bytes());
+    let digest = mac.finalize().into_bytes();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) as i64
+}
+
+/// Shuffles with ChaCha20, so that draws can be reproduced
+/// regardless of the version of the rand crate
+fn shuffle<T>(items: &mut [T], seed: i64) {
+    let mut rng = ChaCha20Rng::seed_from_u64(seed as u64);
+    items.shuffle(&mut rng);
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{derive_seed, shuffle};
+
+    #[test]
+    fn draws_should_be_reproducible_from_the_seed() {
+        let teams: Vec<u32> = (0..4).collect();
+        let mut first = teams.clone();
+        let mut second = teams.clone();
+        shuffle(&mut first, 42);
+        shuffle(&mut second, 42);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn debates_should_be_drawn_with_different_seeds() {
+        let first = derive_seed(7, Uuid::now_v7());
+        let second = derive_seed(7, Uuid::now_v7());
+        assert_ne!(first, second);
+        assert!(first >= 0 && first <= u32::MAX as i64);
+    }
+
+    #[test]
+    fn seeds_of_draws_should_not_reveal_the_seed_of_the_tournament() {
+        let debate_id = Uuid::now_v7();
+        let seed = derive_seed(7, debate_id);
+
+        assert_eq!(seed, derive_seed(7, debate_id));
+        assert_ne!(seed ^ derive_seed(8, debate_id), 7 ^ 8);
+    }
+}
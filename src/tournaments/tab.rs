@@ -0,0 +1,715 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::http::StatusCode;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sqlx::{query, query_as, Pool, Postgres};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        debate_results::{DebateResult, DebateResultStatus},
+        debates::Debate,
+        rounds::Round,
+        Tournament,
+    },
+};
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Criterion used to order teams on the tab
+pub enum TieBreak {
+    /// Number of debates won. A four-team debate is won by the team placed first.
+    Wins,
+    /// Points scored for places: 3, 2, 1 and 0 in four-team debates,
+    /// 1 for a win in two-team debates
+    Points,
+    /// Number of verdicts in favour of the team
+    Ballots,
+    /// Total of speaker scores of the team
+    SpeakerPoints,
+    /// Total number of wins of the teams faced
+    OppositionStrength,
+    /// Number of wins against the other teams still tied
+    HeadToHead,
+    /// Order drawn at random, fixed for the tournament.
+    /// It is drawn with ChaCha20, so it does not change with the version of the rand crate.
+    Random,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(transparent)]
+/// Order in which tie-breaks are applied when ranking teams.
+/// Teams tied on every listed criterion share a rank.
+pub struct TieBreakOrder(pub Vec<TieBreak>);
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A single team's standing
+pub struct TeamTabEntry {
+    /// Position on the tab, starting from 1. Tied teams share a rank.
+    pub rank: i32,
+    pub team_id: Uuid,
+    /// Number of debates with a complete result the team took part in
+    pub debates: i64,
+    pub wins: i64,
+    pub points: i64,
+    pub ballots_won: i64,
+    /// Total of speaker points of the team. Empty until speaker scores are submitted.
+    pub speaker_points: Option<f64>,
+    /// Total number of wins of the teams faced
+    pub opposition_strength: i64,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Ranked list of teams of a tournament or one of its phases
+pub struct TeamTab {
+    /// Tie-breaks used to rank the teams, in the order of application
+    pub tie_breaks: Vec<TieBreak>,
+    pub teams: Vec<TeamTabEntry>,
+}
+
+#[derive(Serialize, ToSchema, Clone, Debug, PartialEq)]
+/// A single speaker's standing
+pub struct SpeakerTabEntry {
+    /// Position on the tab, starting from 1. Tied speakers share a rank.
+    pub rank: i32,
+    pub attendee_id: Uuid,
+    pub team_id: Uuid,
+    /// Number of debates the speaker was scored in
+    pub debates: i64,
+    /// Sum of the speaker's scores. The score of each debate
+    /// is the average of scores given by the Judges on its panel.
+    pub total_score: f64,
+    pub average_score: f64,
+}
+
+#[derive(Serialize, ToSchema)]
+/// Ranked list of speakers of a tournament or one of its phases
+pub struct SpeakerTab {
+    pub speakers: Vec<SpeakerTabEntry>,
+}
+
+/// Score of a speaker in a single debate, averaged over the panel
+pub struct DebateSpeakerScore {
+    pub debate_id: Uuid,
+    pub attendee_id: Uuid,
+    pub team_id: Uuid,
+    pub score: f64,
+}
+
+/// Everything a tab is computed from, limited to debates with a complete result
+struct TabData {
+    tournament: Tournament,
+    team_ids: Vec<Uuid>,
+    results: Vec<DebateResult>,
+    speaker_scores: Vec<DebateSpeakerScore>,
+}
+
+#[derive(Default)]
+struct TeamRecord {
+    debates: i64,
+    wins: i64,
+    points: i64,
+    ballots_won: i64,
+    opponents: Vec<Uuid>,
+    beaten_teams: Vec<Uuid>,
+}
+
+impl TeamTab {
+    /// Ranks teams by results of debates of the whole tournament,
+    /// or of a single phase if `phase_id` is given.
+    /// The tab of a phase lists only teams that debated in it.
+    pub async fn get(
+        tournament_id: Uuid,
+        phase_id: Option<Uuid>,
+        pool: &Pool<Postgres>,
+    ) -> Result<TeamTab, OmniError> {
+        let data = TabData::get(tournament_id, phase_id, pool).await?;
+
+        let (high, low) = tournament_id.as_u64_pair();
+        let tie_breaks = data.tournament.tab_tie_breaks.0;
+        let teams = rank_teams(
+            &data.team_ids,
+            &data.results,
+            &data.speaker_scores,
+            &tie_breaks,
+            high ^ low,
+        );
+
+        Ok(TeamTab { tie_breaks, teams })
+    }
+}
+
+impl SpeakerTab {
+    /// Ranks speakers by their scores in debates of the whole tournament,
+    /// or of a single phase if `phase_id` is given
+    pub async fn get(
+        tournament_id: Uuid,
+        phase_id: Option<Uuid>,
+        pool: &Pool<Postgres>,
+    ) -> Result<SpeakerTab, OmniError> {
+        let data = TabData::get(tournament_id, phase_id, pool).await?;
+        Ok(SpeakerTab {
+            speakers: rank_speakers(&data.speaker_scores),
+        })
+    }
+}
+
+impl TabData {
+    /// The tournament, verdicts, speaker scores, debates and team assignments
+    /// are read within a single read-only transaction
+    async fn get(
+        tournament_id: Uuid,
+        phase_id: Option<Uuid>,
+        pool: &Pool<Postgres>,
+    ) -> Result<TabData, OmniError> {
+        let mut transaction = pool.begin().await?;
+        query("SET TRANSACTION READ ONLY")
+            .execute(&mut *transaction)
+            .await?;
+        let tournament = Tournament::get_by_id(tournament_id, &mut *transaction).await?;
+        let mut team_ids: Vec<Uuid> = query!(
+            "SELECT id FROM teams WHERE tournament_id = $1",
+            tournament_id
+        )
+        .fetch_all(&mut *transaction)
+        .await?
+        .into_iter()
+        .map(|record| record.id)
+        .collect();
+        let rounds = Round::get_all(tournament_id, &mut *transaction).await?;
+        let debates = Debate::get_all(tournament_id, &mut *transaction).await?;
+        let mut results = DebateResult::get_all(tournament_id, &mut transaction).await?;
+        let mut speaker_scores = query_as!(
+            DebateSpeakerScore,
+            r#"
+            SELECT v.debate_id, s.attendee_id, a.team_id, AVG(s.score) AS "score!"
+            FROM speaker_scores s
+            JOIN verdicts v ON v.id = s.verdict_id
+            JOIN debates d ON d.id = v.debate_id
+            JOIN attendees a ON a.id = s.attendee_id
+            WHERE d.tournament_id = $1
+            GROUP BY v.debate_id, s.attendee_id, a.team_id
+            "#,
+            tournament_id
+        )
+        .fetch_all(&mut *transaction)
+        .await?;
+        transaction.commit().await?;
+
+        if let Some(phase_id) = phase_id {
+            let round_ids: HashSet<Uuid> = rounds
+                .iter()
+                .filter(|round| round.phase_id == phase_id)
+                .map(|round| round.id)
+                .collect();
+            let debate_ids: HashSet<Uuid> = debates
+                .iter()
+                .filter(|debate| round_ids.contains(&debate.round_id))
+                .map(|debate| debate.id)
+                .collect();
+            results.retain(|result| debate_ids.contains(&result.debate_id));
+            let debating_teams: HashSet<Uuid> =
+                results.iter().flat_map(DebateResult::team_ids).collect();
+            team_ids.retain(|team_id| debating_teams.contains(team_id));
+        }
+        let counted_debates: HashSet<Uuid> = results
+            .iter()
+            .filter(|result| result.status != DebateResultStatus::Incomplete)
+            .map(|result| result.debate_id)
+            .collect();
+        speaker_scores.retain(|score| counted_debates.contains(&score.debate_id));
+
+        Ok(TabData {
+            tournament,
+            team_ids,
+            results,
+            speaker_scores,
+        })
+    }
+}
+
+/// Ranks teams by applying tie-breaks one after another.
+/// Each tie-break only reorders teams left tied by the previous ones;
+/// head-to-head wins are counted only against the other tied teams.
+/// In four-team debates, a team beats every team placed below it.
+/// Debates without a complete result are ignored.
+pub fn rank_teams(
+    team_ids: &[Uuid],
+    results: &[DebateResult],
+    speaker_scores: &[DebateSpeakerScore],
+    tie_breaks: &[TieBreak],
+    random_seed: u64,
+) -> Vec<TeamTabEntry> {
+    let mut records: HashMap<Uuid, TeamRecord> = team_ids
+        .iter()
+        .map(|team_id| (*team_id, TeamRecord::default()))
+        .collect();
+    for result in results {
+        if result.status == DebateResultStatus::Incomplete {
+            continue;
+        }
+        for placement in &result.placements {
+            let Some(record) = placement
+                .team_id
+                .and_then(|team_id| records.get_mut(&team_id))
+            else {
+                continue;
+            };
+            record.debates += 1;
+            record.points += placement.points as i64;
+            if placement.rank == 1 {
+                record.wins += 1;
+            }
+            for other in &result.placements {
+                let Some(other_team_id) = other.team_id else {
+                    continue;
+                };
+                if other.side == placement.side {
+                    continue;
+                }
+                record.opponents.push(other_team_id);
+                if other.rank > placement.rank {
+                    record.beaten_teams.push(other_team_id);
+                }
+            }
+        }
+        let (Some(proposition), Some(opposition)) =
+            (result.proposition_team_id, result.opposition_team_id)
+        else {
+            continue;
+        };
+        for (team_id, opponent_id, ballots, is_proposition) in [
+            (proposition, opposition, result.proposition_ballots, true),
+            (opposition, proposition, result.opposition_ballots, false),
+        ] {
+            if let Some(record) = records.get_mut(&team_id) {
+                record.debates += 1;
+                record.ballots_won += ballots;
+                record.opponents.push(opponent_id);
+                if result.proposition_won == Some(is_proposition) {
+                    record.wins += 1;
+                    record.points += 1;
+                    record.beaten_teams.push(opponent_id);
+                }
+            }
+        }
+    }
+
+    let mut speaker_points: HashMap<Uuid, f64> = HashMap::new();
+    for speaker_score in speaker_scores {
+        *speaker_points.entry(speaker_score.team_id).or_default() += speaker_score.score;
+    }
+
+    let wins: HashMap<Uuid, i64> = records
+        .iter()
+        .map(|(team_id, record)| (*team_id, record.wins))
+        .collect();
+    let opposition_strength: HashMap<Uuid, i64> = records
+        .iter()
+        .map(|(team_id, record)| {
+            let strength = record
+                .opponents
+                .iter()
+                .map(|opponent_id| wins.get(opponent_id).copied().unwrap_or_default())
+                .sum();
+            (*team_id, strength)
+        })
+        .collect();
+    let mut random_order: Vec<Uuid> = team_ids.to_vec();
+    random_order.sort();
+    random_order.shuffle(&mut ChaCha20Rng::seed_from_u64(random_seed));
+    let random_order: HashMap<Uuid, i64> = random_order
+        .into_iter()
+        .enumerate()
+        .map(|(index, team_id)| (team_id, -(index as i64)))
+        .collect();
+
+    let mut sorted_team_ids = team_ids.to_vec();
+    sorted_team_ids.sort();
+    let mut groups = vec![sorted_team_ids];
+    for tie_break in tie_breaks {
+        groups = groups
+            .into_iter()
+            .flat_map(|group| {
+                if group.len() < 2 {
+                    return vec![group];
+                }
+                let score = |team_id: &Uuid| -> f64 {
+                    let record = &records[team_id];
+                    match tie_break {
+                        TieBreak::Wins => record.wins as f64,
+                        TieBreak::Points => record.points as f64,
+                        TieBreak::Ballots => record.ballots_won as f64,
+                        TieBreak::SpeakerPoints => {
+                            speaker_points.get(team_id).copied().unwrap_or_default()
+                        }
+                        TieBreak::OppositionStrength => {
+                            opposition_strength[team_id] as f64
+                        }
+                        TieBreak::HeadToHead => record
+                            .beaten_teams
+                            .iter()
+                            .filter(|beaten| group.contains(beaten))
+                            .count()
+                            as f64,
+                        TieBreak::Random => random_order[team_id] as f64,
+                    }
+                };
+                let mut scored: Vec<(f64, Uuid)> = group
+                    .iter()
+                    .map(|team_id| (score(team_id), *team_id))
+                    .collect();
+                scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+                scored
+                    .chunk_by(|a, b| a.0 == b.0)
+                    .map(|chunk| chunk.iter().map(|(_, team_id)| *team_id).collect())
+                    .collect()
+            })
+            .collect();
+    }
+
+    let mut entries = Vec::with_capacity(team_ids.len());
+    for group in groups {
+        let rank = entries.len() as i32 + 1;
+        for team_id in group {
+            let record = &records[&team_id];
+            entries.push(TeamTabEntry {
+                rank,
+                team_id,
+                debates: record.debates,
+                wins: record.wins,
+                points: record.points,
+                ballots_won: record.ballots_won,
+                speaker_points: speaker_points.get(&team_id).copied(),
+                opposition_strength: opposition_strength[&team_id],
+            });
+        }
+    }
+    entries
+}
+
+/// Ranks speakers by the total of their scores, then by the average score.
+/// Speakers tied on both share a rank.
+pub fn rank_speakers(speaker_scores: &[DebateSpeakerScore]) -> Vec<SpeakerTabEntry> {
+    let mut entries: HashMap<Uuid, SpeakerTabEntry> = HashMap::new();
+    for speaker_score in speaker_scores {
+        let entry = entries
+            .entry(speaker_score.attendee_id)
+            .or_insert(SpeakerTabEntry {
+                rank: 0,
+                attendee_id: speaker_score.attendee_id,
+                team_id: speaker_score.team_id,
+                debates: 0,
+                total_score: 0.0,
+                average_score: 0.0,
+            });
+        entry.debates += 1;
+        entry.total_score += speaker_score.score;
+    }
+
+    let mut entries: Vec<SpeakerTabEntry> = entries
+        .into_values()
+        .map(|mut entry| {
+            entry.average_score = entry.total_score / entry.debates as f64;
+            entry
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        b.total_score
+            .total_cmp(&a.total_score)
+            .then(b.average_score.total_cmp(&a.average_score))
+            .then(a.attendee_id.cmp(&b.attendee_id))
+    });
+    for index in 0..entries.len() {
+        entries[index].rank = match index {
+            0 => 1,
+            _ if entries[index].total_score == entries[index - 1].total_score
+                && entries[index].average_score == entries[index - 1].average_score =>
+            {
+                entries[index - 1].rank
+            }
+            _ => index as i32 + 1,
+        };
+    }
+    entries
+}
+
+impl TieBreakOrder {
+    /// Rejects orders listing the same tie-break more than once
+    pub fn validate(&self) -> Result<(), OmniError> {
+        let mut seen = HashSet::new();
+        for tie_break in &self.0 {
+            if !seen.insert(tie_break) {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::BAD_REQUEST,
+                    message: format!("Tie-break {tie_break:?} is listed more than once"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_strings(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|tie_break| format!("{tie_break:?}"))
+            .collect()
+    }
+}
+
+impl Default for TieBreakOrder {
+    fn default() -> Self {
+        TieBreakOrder(vec![
+            TieBreak::Wins,
+            TieBreak::Ballots,
+            TieBreak::HeadToHead,
+            TieBreak::Random,
+        ])
+    }
+}
+
+impl TryFrom<Vec<String>> for TieBreakOrder {
+    type Error = OmniError;
+
+    fn try_from(values: Vec<String>) -> Result<Self, Self::Error> {
+        let tie_breaks = values
+            .iter()
+            .map(|value| match value.as_str() {
+                "Wins" => Ok(TieBreak::Wins),
+                "Points" => Ok(TieBreak::Points),
+                "Ballots" => Ok(TieBreak::Ballots),
+                "SpeakerPoints" => Ok(TieBreak::SpeakerPoints),
+                "OppositionStrength" => Ok(TieBreak::OppositionStrength),
+                "HeadToHead" => Ok(TieBreak::HeadToHead),
+                "Random" => Ok(TieBreak::Random),
+                _ => Err(OmniError::ExplicitError {
+                    status: StatusCode::BAD_REQUEST,
+                    message: format!("Unknown tie-break {value}"),
+                }),
+            })
+            .collect::<Result<Vec<TieBreak>, OmniError>>()?;
+        Ok(TieBreakOrder(tie_breaks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{rank_speakers, rank_teams, DebateSpeakerScore, TieBreak, TieBreakOrder};
+    use crate::tournaments::{
+        debate_formats::Side,
+        debate_results::{DebateResult, DebateResultStatus, TeamPlacement},
+    };
+
+    fn teams(count: usize) -> Vec<Uuid> {
+        (0..count).map(|_| Uuid::now_v7()).collect()
+    }
+
+    fn decided(proposition: Uuid, opposition: Uuid, ballots: (i64, i64)) -> DebateResult {
+        let proposition_won = ballots.0 > ballots.1;
+        DebateResult {
+            debate_id: Uuid::now_v7(),
+            status: DebateResultStatus::Decided,
+            proposition_won: Some(proposition_won),
+            winner_team_id: Some(match proposition_won {
+                true => proposition,
+                false => opposition,
+            }),
+            proposition_team_id: Some(proposition),
+            opposition_team_id: Some(opposition),
+            panel_size: ballots.0 + ballots.1,
+            proposition_ballots: ballots.0,
+            opposition_ballots: ballots.1,
+            is_unanimous: ballots.0 == 0 || ballots.1 == 0,
+            placements: vec![],
+        }
+    }
+
+    /// A four-team debate with teams listed from the first place to the last
+    fn ranked(teams: [Uuid; 4]) -> DebateResult {
+        let placements: Vec<TeamPlacement> = Side::FOUR_TEAM
+            .into_iter()
+            .zip(teams)
+            .zip(1..)
+            .map(|((side, team_id), rank)| TeamPlacement {
+                side,
+                team_id: Some(team_id),
+                rank,
+                points: 4 - rank,
+            })
+            .collect();
+        DebateResult {
+            debate_id: Uuid::now_v7(),
+            status: DebateResultStatus::Decided,
+            proposition_won: None,
+            winner_team_id: Some(teams[0]),
+            proposition_team_id: None,
+            opposition_team_id: None,
+            panel_size: 1,
+            proposition_ballots: 0,
+            opposition_ballots: 0,
+            is_unanimous: true,
+            placements,
+        }
+    }
+
+    #[test]
+    fn teams_should_be_ranked_by_wins_then_ballots() {
+        let t = teams(4);
+        let results = vec![
+            decided(t[0], t[1], (3, 0)),
+            decided(t[2], t[3], (2, 1)),
+            decided(t[0], t[2], (3, 0)),
+            decided(t[1], t[3], (3, 0)),
+        ];
+
+        let tab = rank_teams(&t, &results, &[], &[TieBreak::Wins, TieBreak::Ballots], 0);
+
+        let order: Vec<Uuid> = tab.iter().map(|entry| entry.team_id).collect();
+        assert_eq!(order, vec![t[0], t[1], t[2], t[3]]);
+        assert_eq!(tab[1].wins, 1);
+        assert_eq!(tab[1].ballots_won, 3);
+        assert_eq!(tab[2].ballots_won, 2);
+        assert_eq!(tab[1].rank, 2);
+        assert_eq!(tab[2].rank, 3);
+    }
+
+    #[test]
+    fn four_team_debates_should_score_points_by_place() {
+        let t = teams(4);
+        let results = vec![
+            ranked([t[0], t[1], t[2], t[3]]),
+            ranked([t[1], t[3], t[0], t[2]]),
+        ];
+
+        let tab = rank_teams(&t, &results, &[], &[TieBreak::Points], 0);
+
+        let points: Vec<(Uuid, i64)> = tab
+            .iter()
+            .map(|entry| (entry.team_id, entry.points))
+            .collect();
+        assert_eq!(points, vec![(t[1], 5), (t[0], 4), (t[3], 2), (t[2], 1)]);
+        assert_eq!(tab[0].wins, 1);
+        assert_eq!(tab[0].debates, 2);
+    }
+
+    #[test]
+    fn head_to_head_should_only_count_tied_teams() {
+        let t = teams(3);
+        // Each team beats one other team
+        let results = vec![
+            decided(t[0], t[1], (1, 0)),
+            decided(t[1], t[2], (1, 0)),
+            decided(t[2], t[0], (1, 0)),
+        ];
+
+        let tab = rank_teams(
+            &t,
+            &results,
+            &[],
+            &[TieBreak::Wins, TieBreak::HeadToHead],
+            0,
+        );
+
+        assert!(tab.iter().all(|entry| entry.rank == 1));
+    }
+
+    #[test]
+    fn head_to_head_should_separate_two_tied_teams() {
+        let t = teams(2);
+        let results = vec![decided(t[1], t[0], (1, 0))];
+
+        let tab = rank_teams(&t, &results, &[], &[TieBreak::HeadToHead], 0);
+
+        assert_eq!(tab[0].team_id, t[1]);
+        assert_eq!(tab[1].rank, 2);
+    }
+
+    #[test]
+    fn random_tie_break_should_depend_only_on_the_seed() {
+        let t = teams(8);
+        let first = rank_teams(&t, &[], &[], &[TieBreak::Random], 42);
+        let second = rank_teams(&t, &[], &[], &[TieBreak::Random], 42);
+
+        assert_eq!(first, second);
+        let ranks: Vec<i32> = first.iter().map(|entry| entry.rank).collect();
+        assert_eq!(ranks, (1..=8).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn speaker_points_should_break_ties_between_teams() {
+        let t = teams(2);
+        let speaker_scores = vec![
+            DebateSpeakerScore {
+                debate_id: Uuid::now_v7(),
+                attendee_id: Uuid::now_v7(),
+                team_id: t[0],
+                score: 70.0,
+            },
+            DebateSpeakerScore {
+                debate_id: Uuid::now_v7(),
+                attendee_id: Uuid::now_v7(),
+                team_id: t[1],
+                score: 72.5,
+            },
+        ];
+
+        let tab = rank_teams(&t, &[], &speaker_scores, &[TieBreak::SpeakerPoints], 0);
+
+        assert_eq!(tab[0].team_id, t[1]);
+        assert_eq!(tab[0].speaker_points, Some(72.5));
+        assert_eq!(tab[1].rank, 2);
+    }
+
+    #[test]
+    fn speakers_should_be_ranked_by_total_then_average() {
+        let team_id = Uuid::now_v7();
+        let speakers = teams(3);
+        let score = |attendee_id: Uuid, score: f64| DebateSpeakerScore {
+            debate_id: Uuid::now_v7(),
+            attendee_id,
+            team_id,
+            score,
+        };
+        let speaker_scores = vec![
+            score(speakers[0], 70.0),
+            score(speakers[0], 70.0),
+            score(speakers[1], 75.0),
+            score(speakers[1], 65.0),
+            score(speakers[2], 71.0),
+        ];
+
+        let tab = rank_speakers(&speaker_scores);
+
+        assert_eq!(tab.len(), 3);
+        assert_eq!(tab[0].total_score, 140.0);
+        assert_eq!(tab[0].rank, 1);
+        assert_eq!(tab[1].rank, 1);
+        assert_eq!(tab[1].average_score, 70.0);
+        assert_eq!(tab[2].attendee_id, speakers[2]);
+        assert_eq!(tab[2].rank, 3);
+    }
+
+    #[test]
+    fn duplicate_tie_breaks_should_be_rejected() {
+        let order =
+            TieBreakOrder(vec![TieBreak::Wins, TieBreak::Ballots, TieBreak::Wins]);
+        assert!(order.validate().is_err());
+        assert!(TieBreakOrder::default().validate().is_ok());
+    }
+
+    #[test]
+    fn unknown_tie_breaks_should_be_rejected() {
+        let known = vec!["Wins".to_owned(), "Random".to_owned()];
+        let unknown = vec!["Wins".to_owned(), "Coin toss".to_owned()];
+        assert_eq!(
+            TieBreakOrder::try_from(known).unwrap(),
+            TieBreakOrder(vec![TieBreak::Wins, TieBreak::Random])
+        );
+        assert!(TieBreakOrder::try_from(unknown).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 ﻿use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as, Pool, Postgres};
+use sqlx::{query, query_as, Executor, Pool, Postgres};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
@@ -46,12 +46,12 @@ impl Team {
         }
     }
 
-    pub async fn get_by_id(
-        id: Uuid,
-        connection_pool: &Pool<Postgres>,
-    ) -> Result<Team, OmniError> {
+    pub async fn get_by_id<'e, E>(id: Uuid, executor: E) -> Result<Team, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
         match query_as!(Team, "SELECT * FROM teams WHERE id = $1", id)
-            .fetch_one(connection_pool)
+            .fetch_one(executor)
             .await
         {
             Ok(team) => Ok(team),
@@ -1,16 +1,31 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
-use sqlx::{query, query_as, Pool, Postgres, Transaction};
+use sqlx::{query, Pool, Postgres, Transaction};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use crate::omni_error::OmniError;
+use crate::{
+    omni_error::OmniError,
+    tournaments::{
+        affiliations::Affiliation,
+        bracket_links::BracketLink,
+        debate_formats::{DebateFormat, Side},
+        debate_judges::DebateJudgeAssignment,
+        debates::{Debate, DebateStatus},
+        Tournament,
+    },
+    users::{permissions::Permission, TournamentUser},
+};
 
 #[derive(Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
-/// Verdict denotes a winner of a debate (i.e. Proposition or Opposition).
-/// Every Judge can make a verdict on a debate
-/// within a tournament they're assigned to.
-/// A debate can have multiple verdicts.
+/// Verdict denotes a winner of a two-team debate (i.e. Proposition or Opposition),
+/// or ranks all teams of a four-team debate.
+/// Every Judge on the panel of a debate can make a verdict on it,
+/// unless they're affiliated with one of the debating teams.
+/// A debate can have multiple verdicts, but only one from each Judge.
 /// It is advised to assign an odd number of Judges
 /// to a debate to avoid ties.
 pub struct Verdict {
@@ -19,7 +34,34 @@ pub struct Verdict {
     pub id: Uuid,
     pub debate_id: Uuid,
     pub judge_user_id: Uuid,
-    pub proposition_won: bool,
+    /// Side that won a two-team debate. Must be empty in four-team debates.
+    #[serde(default)]
+    pub proposition_won: Option<bool>,
+    /// Ranking ballot of a four-team debate, placing every position from 1 to 4.
+    /// Must be empty in two-team debates.
+    #[serde(default)]
+    pub rankings: Vec<TeamRanking>,
+    /// Scores given to the speakers of both teams.
+    /// If any speaker is scored, every speaker of the debate must be.
+    #[serde(default)]
+    pub speaker_scores: Vec<SpeakerScore>,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Score given by a Judge to a single speaker
+pub struct SpeakerScore {
+    pub attendee_id: Uuid,
+    pub score: f64,
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, Copy, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Place given by a Judge to the team taking a position in a four-team debate
+pub struct TeamRanking {
+    pub side: Side,
+    /// 1 for the best team
+    pub rank: i32,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -27,6 +69,10 @@ pub struct VerdictPatch {
     pub debate_id: Option<Uuid>,
     pub judge_user_id: Option<Uuid>,
     pub proposition_won: Option<bool>,
+    /// Replaces the whole ranking ballot
+    pub rankings: Option<Vec<TeamRanking>>,
+    /// Replaces all speaker scores of the verdict
+    pub speaker_scores: Option<Vec<SpeakerScore>>,
 }
 
 impl Verdict {
@@ -37,6 +83,12 @@ impl Verdict {
         let mut transaction = pool.begin().await?;
         match Verdict::append_post_to_transaction(&mut transaction, &verdict).await {
             Ok(verdict) => {
+                Debate::follow_verdicts(verdict.debate_id, &mut *transaction).await?;
+                BracketLink::advance_winner_with_transaction(
+                    verdict.debate_id,
+                    &mut transaction,
+                )
+                .await?;
                 transaction.commit().await?;
                 Ok(verdict)
             }
@@ -48,8 +100,7 @@ impl Verdict {
         transaction: &mut Transaction<'_, Postgres>,
         verdict: &Verdict,
     ) -> Result<Verdict, sqlx::Error> {
-        query_as!(
-            Verdict,
+        let record = query!(
             r#"INSERT INTO verdicts(id, judge_user_id, debate_id, proposition_won)
             VALUES ($1, $2, $3, $4) RETURNING id, judge_user_id, debate_id, proposition_won"#,
             verdict.id,
@@ -58,23 +109,130 @@ impl Verdict {
             verdict.proposition_won
         )
         .fetch_one(&mut **transaction)
-        .await
+        .await?;
+        insert_rankings(transaction, verdict.id, &verdict.rankings).await?;
+        insert_speaker_scores(transaction, verdict.id, &verdict.speaker_scores).await?;
+
+        Ok(Verdict {
+            id: record.id,
+            debate_id: record.debate_id,
+            judge_user_id: record.judge_user_id,
+            proposition_won: record.proposition_won,
+            rankings: verdict.rankings.clone(),
+            speaker_scores: verdict.speaker_scores.clone(),
+        })
     }
 
     pub async fn get_by_id(
         id: Uuid,
         pool: &Pool<Postgres>,
     ) -> Result<Verdict, OmniError> {
-        match query_as!(Verdict, "SELECT * FROM verdicts WHERE id = $1", id)
+        let record = match query!("SELECT * FROM verdicts WHERE id = $1", id)
             .fetch_one(pool)
             .await
         {
-            Ok(verdict) => Ok(verdict),
+            Ok(record) => record,
             Err(e) => match e {
-                sqlx::Error::RowNotFound => Err(OmniError::ResourceNotFoundError),
-                _ => Err(OmniError::InternalServerError),
+                sqlx::Error::RowNotFound => return Err(OmniError::ResourceNotFoundError),
+                _ => return Err(OmniError::InternalServerError),
             },
+        };
+        let speaker_scores = query!(
+            "SELECT attendee_id, score FROM speaker_scores WHERE verdict_id = $1 ORDER BY id",
+            id
+        )
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|score| SpeakerScore {
+            attendee_id: score.attendee_id,
+            score: score.score,
+        })
+        .collect();
+        let mut rankings = vec![];
+        for ranking in query!(
+            "SELECT side, rank FROM verdict_rankings WHERE verdict_id = $1 ORDER BY rank",
+            id
+        )
+        .fetch_all(pool)
+        .await?
+        {
+            rankings.push(TeamRanking {
+                side: Side::try_from(ranking.side)?,
+                rank: ranking.rank,
+            });
         }
+
+        Ok(Verdict {
+            id: record.id,
+            debate_id: record.debate_id,
+            judge_user_id: record.judge_user_id,
+            proposition_won: record.proposition_won,
+            rankings,
+            speaker_scores,
+        })
+    }
+
+    /// Returns all verdicts regarding a debate, along with their rankings and speaker scores
+    pub async fn get_all_of_debate(
+        debate_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<Vec<Verdict>, OmniError> {
+        let records = query!("SELECT * FROM verdicts WHERE debate_id = $1", debate_id)
+            .fetch_all(pool)
+            .await?;
+        let mut speaker_scores: HashMap<Uuid, Vec<SpeakerScore>> = HashMap::new();
+        for score in query!(
+            r#"SELECT s.verdict_id, s.attendee_id, s.score
+            FROM speaker_scores s
+            JOIN verdicts v ON v.id = s.verdict_id
+            WHERE v.debate_id = $1
+            ORDER BY s.id"#,
+            debate_id
+        )
+        .fetch_all(pool)
+        .await?
+        {
+            speaker_scores
+                .entry(score.verdict_id)
+                .or_default()
+                .push(SpeakerScore {
+                    attendee_id: score.attendee_id,
+                    score: score.score,
+                });
+        }
+        let mut rankings: HashMap<Uuid, Vec<TeamRanking>> = HashMap::new();
+        for ranking in query!(
+            r#"SELECT r.verdict_id, r.side, r.rank
+            FROM verdict_rankings r
+            JOIN verdicts v ON v.id = r.verdict_id
+            WHERE v.debate_id = $1
+            ORDER BY r.rank"#,
+            debate_id
+        )
+        .fetch_all(pool)
+        .await?
+        {
+            rankings
+                .entry(ranking.verdict_id)
+                .or_default()
+                .push(TeamRanking {
+                    side: Side::try_from(ranking.side)?,
+                    rank: ranking.rank,
+                });
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| Verdict {
+                id: record.id,
+                debate_id: record.debate_id,
+                judge_user_id: record.judge_user_id,
+                proposition_won: record.proposition_won,
+                rankings: rankings.remove(&record.id).unwrap_or_default(),
+                speaker_scores: speaker_scores.remove(&record.id).unwrap_or_default(),
+            })
+            .collect())
     }
 
     pub async fn patch(
@@ -85,6 +243,20 @@ impl Verdict {
         let mut transaction = pool.begin().await?;
         self.append_patch_to_transaction(&mut transaction, &new_verdict)
             .await?;
+        Debate::follow_verdicts(new_verdict.debate_id, &mut *transaction).await?;
+        BracketLink::advance_winner_with_transaction(
+            new_verdict.debate_id,
+            &mut transaction,
+        )
+        .await?;
+        if new_verdict.debate_id != self.debate_id {
+            Debate::follow_verdicts(self.debate_id, &mut *transaction).await?;
+            BracketLink::advance_winner_with_transaction(
+                self.debate_id,
+                &mut transaction,
+            )
+            .await?;
+        }
         transaction.commit().await?;
         Ok(new_verdict)
     }
@@ -103,14 +275,32 @@ impl Verdict {
         )
         .execute(&mut **transaction).await
          {
-            Ok(_) => Ok(()),
+            Ok(_) => (),
             Err(e) => Err(e)?,
         }
+        query!(
+            "DELETE FROM verdict_rankings WHERE verdict_id = $1",
+            new_verdict.id
+        )
+        .execute(&mut **transaction)
+        .await?;
+        insert_rankings(transaction, new_verdict.id, &new_verdict.rankings).await?;
+        query!(
+            "DELETE FROM speaker_scores WHERE verdict_id = $1",
+            new_verdict.id
+        )
+        .execute(&mut **transaction)
+        .await?;
+        insert_speaker_scores(transaction, new_verdict.id, &new_verdict.speaker_scores)
+            .await
     }
 
     pub async fn delete(self, pool: &Pool<Postgres>) -> Result<(), OmniError> {
         let mut transaction = pool.begin().await?;
         self.append_delete_to_transaction(&mut transaction).await?;
+        Debate::follow_verdicts(self.debate_id, &mut *transaction).await?;
+        BracketLink::advance_winner_with_transaction(self.debate_id, &mut transaction)
+            .await?;
         transaction.commit().await?;
         Ok(())
     }
@@ -125,10 +315,204 @@ impl Verdict {
         Ok(())
     }
 
+    /// Checks whether the verdict can be submitted by the given user.
+    /// Judges can only rule on their own behalf,
+    /// unless the user is permitted to submit verdicts (e.g. a Marshal).
+    /// Either way, the Judge must sit on the panel of the debate
+    /// and must not be affiliated with any of the debating teams.
+    pub async fn validate(
+        &self,
+        tournament_user: &TournamentUser,
+        tournament_id: Uuid,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        let debate = Debate::get_by_id(self.debate_id, pool).await?;
+        if debate.tournament_id != tournament_id {
+            return Err(OmniError::ResourceNotFoundError);
+        }
+        if debate.status == DebateStatus::Cancelled {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::CONFLICT,
+                message: "The debate has been cancelled".to_owned(),
+            });
+        }
+
+        if self.judge_user_id != tournament_user.user.id
+            && !tournament_user.has_permission(Permission::SubmitVerdict)
+        {
+            return Err(OmniError::VerdictOnBehalfError);
+        }
+
+        if !DebateJudgeAssignment::judge_is_on_panel(self.judge_user_id, debate.id, pool)
+            .await?
+        {
+            return Err(OmniError::JudgeNotOnPanelError);
+        }
+
+        if Affiliation::exists_between_judge_and_debate(
+            self.judge_user_id,
+            debate.id,
+            pool,
+        )
+        .await?
+        {
+            return Err(OmniError::AffiliatedJudgeError);
+        }
+
+        let tournament = Tournament::get_by_id(debate.tournament_id, pool).await?;
+        let format = DebateFormat::get_for_debate(&debate, &tournament, pool).await?;
+        self.validate_ballot(&format)?;
+        self.validate_speaker_scores(&debate, &tournament, pool)
+            .await
+    }
+
+    /// Two-team debates are decided by naming the winning side,
+    /// while four-team debates are decided by ranking every position exactly once
+    fn validate_ballot(&self, format: &DebateFormat) -> Result<(), OmniError> {
+        let invalid = |message: String| {
+            Err(OmniError::ExplicitError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                message,
+            })
+        };
+        let positions = format.positions();
+        if positions.len() == Side::TWO_TEAM.len() {
+            return match (self.proposition_won, self.rankings.is_empty()) {
+                (Some(_), true) => Ok(()),
+                (None, _) => invalid(
+                    "Verdicts of two-team debates must name the winning side".to_owned(),
+                ),
+                (Some(_), false) => {
+                    invalid("Only teams of four-team debates are ranked".to_owned())
+                }
+            };
+        }
+
+        if self.proposition_won.is_some() {
+            return invalid(
+                "Verdicts of four-team debates must rank the teams instead of naming a winning side"
+                    .to_owned(),
+            );
+        }
+        let ranked_sides: HashSet<Side> =
+            self.rankings.iter().map(|ranking| ranking.side).collect();
+        let ranks: HashSet<i32> =
+            self.rankings.iter().map(|ranking| ranking.rank).collect();
+        let teams = positions.len();
+        if self.rankings.len() != teams
+            || ranked_sides.len() != teams
+            || ranks.len() != teams
+            || !positions.iter().all(|side| ranked_sides.contains(side))
+            || !ranks.iter().all(|rank| (1..=teams as i32).contains(rank))
+        {
+            return invalid(format!(
+                "Every team of the debate must be ranked from 1 to {teams} exactly once"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Positions of the debate from the best to the worst, according to this verdict
+    fn placings(&self) -> Vec<Side> {
+        match self.proposition_won {
+            Some(proposition_won) => {
+                let winner = Side::from_is_proposition(proposition_won);
+                vec![winner, winner.other()]
+            }
+            None => {
+                let mut rankings = self.rankings.clone();
+                rankings.sort_by_key(|ranking| ranking.rank);
+                rankings.into_iter().map(|ranking| ranking.side).collect()
+            }
+        }
+    }
+
+    /// Checks speaker scores against the range configured for the tournament.
+    /// Every speaker of all teams must be scored exactly once
+    /// and better placed teams must have higher totals,
+    /// unless the tournament allows low-point wins.
+    async fn validate_speaker_scores(
+        &self,
+        debate: &Debate,
+        tournament: &Tournament,
+        pool: &Pool<Postgres>,
+    ) -> Result<(), OmniError> {
+        if self.speaker_scores.is_empty() {
+            return Ok(());
+        }
+
+        let mut speakers: HashMap<Uuid, Side> = HashMap::new();
+        for speaker in query!(
+            r#"
+            SELECT a.id, t.side AS "side!"
+            FROM attendees a
+            JOIN debate_teams_assignments t ON t.team_id = a.team_id
+            WHERE t.debate_id = $1
+                AND t.side IS NOT NULL
+                AND a.position IS NOT NULL
+            "#,
+            debate.id
+        )
+        .fetch_all(pool)
+        .await?
+        {
+            speakers.insert(speaker.id, Side::try_from(speaker.side)?);
+        }
+
+        let (min, max, step) = tournament.get_speaker_score_range();
+        let mut scored_speakers = HashSet::new();
+        let mut totals: HashMap<Side, f64> = HashMap::new();
+        for speaker_score in &self.speaker_scores {
+            let Some(side) = speakers.get(&speaker_score.attendee_id) else {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                    message: format!(
+                        "Attendee {} is not a speaker in this debate",
+                        speaker_score.attendee_id
+                    ),
+                });
+            };
+            if !scored_speakers.insert(speaker_score.attendee_id) {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                    message: format!(
+                        "Attendee {} is scored more than once",
+                        speaker_score.attendee_id
+                    ),
+                });
+            }
+            if !is_valid_score(speaker_score.score, min, max, step) {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::UNPROCESSABLE_ENTITY,
+                    message: format!(
+                        "Speaker scores must range from {min} to {max} in steps of {step}"
+                    ),
+                });
+            }
+            *totals.entry(*side).or_default() += speaker_score.score;
+        }
+        if scored_speakers.len() != speakers.len() {
+            return Err(OmniError::ExplicitError {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                message: "Every speaker of the debate must be scored".to_owned(),
+            });
+        }
+
+        let total = |side: &Side| totals.get(side).copied().unwrap_or_default();
+        let placed_by_totals = self
+            .placings()
+            .windows(2)
+            .all(|pair| total(&pair[0]) > total(&pair[1]));
+        if !placed_by_totals && !tournament.allows_low_point_wins() {
+            return Err(OmniError::LowPointWinError);
+        }
+
+        Ok(())
+    }
+
     pub async fn already_exists(&self, pool: &Pool<Postgres>) -> Result<bool, OmniError> {
-        match query_as!(
-            Verdict,
-            "SELECT * FROM verdicts WHERE judge_user_id = $1 AND debate_id = $2 AND id != $3",
+        match query!(
+            "SELECT id FROM verdicts WHERE judge_user_id = $1 AND debate_id = $2 AND id != $3",
             self.judge_user_id,
             self.debate_id,
             self.id
@@ -141,3 +525,71 @@ impl Verdict {
         }
     }
 }
+
+async fn insert_rankings(
+    transaction: &mut Transaction<'_, Postgres>,
+    verdict_id: Uuid,
+    rankings: &[TeamRanking],
+) -> Result<(), sqlx::Error> {
+    for ranking in rankings {
+        query!(
+            "INSERT INTO verdict_rankings(verdict_id, side, rank) VALUES ($1, $2, $3)",
+            verdict_id,
+            ranking.side.to_string(),
+            ranking.rank
+        )
+        .execute(&mut **transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+async fn insert_speaker_scores(
+    transaction: &mut Transaction<'_, Postgres>,
+    verdict_id: Uuid,
+    speaker_scores: &[SpeakerScore],
+) -> Result<(), sqlx::Error> {
+    for speaker_score in speaker_scores {
+        query!(
+            r#"INSERT INTO speaker_scores(id, verdict_id, attendee_id, score)
+            VALUES ($1, $2, $3, $4)"#,
+            Uuid::now_v7(),
+            verdict_id,
+            speaker_score.attendee_id,
+            speaker_score.score
+        )
+        .execute(&mut **transaction)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Checks whether a score lies within the range and is a whole number of steps above its minimum
+fn is_valid_score(score: f64, min: f64, max: f64, step: f64) -> bool {
+    const EPSILON: f64 = 1e-9;
+    let steps = (score - min) / step;
+    score >= min - EPSILON
+        && score <= max + EPSILON
+        && (steps - steps.round()).abs() < EPSILON
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_score;
+
+    #[test]
+    fn scores_should_be_within_the_range() {
+        assert!(is_valid_score(60.0, 60.0, 80.0, 0.5));
+        assert!(is_valid_score(80.0, 60.0, 80.0, 0.5));
+        assert!(!is_valid_score(59.5, 60.0, 80.0, 0.5));
+        assert!(!is_valid_score(80.5, 60.0, 80.0, 0.5));
+    }
+
+    #[test]
+    fn scores_should_be_whole_steps_above_the_minimum() {
+        assert!(is_valid_score(70.5, 60.0, 80.0, 0.5));
+        assert!(!is_valid_score(70.25, 60.0, 80.0, 0.5));
+        assert!(is_valid_score(75.3, 75.0, 76.0, 0.1));
+        assert!(!is_valid_score(71.0, 60.0, 80.0, 2.0));
+    }
+}
@@ -62,7 +62,7 @@ mod tests {
         let roles: Vec<Role> = vec![];
         let user = TournamentUser {
             user: infradmin,
-            roles: roles,
+            roles,
         };
         for permission in permissions {
             assert!(user.has_permission(permission))
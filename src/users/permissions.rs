@@ -33,9 +33,11 @@ use utoipa::ToSchema;
 /// | `ModifyUserRoles` | Assign or revoke roles for users in a tournament. |
 /// | `SubmitOwnVerdictVote` | Submit a ballot as a judge for a debate you are assigned to. |
 /// | `SubmitVerdict` | Finalise and publish the official verdict of a debate. |
+/// | `OperateDebateTimers` | Start, pause and advance the speech timer of a debate. |
 /// | `ReadLocations` | View venue/location data. |
 /// | `WriteLocations` | Create or modify venues/locations. |
 /// | `ReadRooms` | View room assignments. |
+/// | `ChangeRoomOccupationStatus` | Close rooms for a period of time, e.g. for maintenance. |
 /// | `WriteRooms` | Create or modify room assignments. |
 #[derive(Deserialize, Copy)]
 pub enum Permission {
@@ -61,6 +63,7 @@ pub enum Permission {
 
     SubmitOwnVerdictVote,
     SubmitVerdict,
+    OperateDebateTimers,
 
     WriteRoles,
 
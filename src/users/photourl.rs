@@ -109,11 +109,11 @@ mod tests {
     fn valid_extension_test() {
         for url in EXPECT_FALSE {
             let url = Url::parse(url).unwrap();
-            assert!(PhotoUrl::has_valid_extension(&url) == false);
+            assert!(!PhotoUrl::has_valid_extension(&url));
         }
         for url in EXPECT_TRUE {
             let url = Url::parse(url).unwrap();
-            assert!(PhotoUrl::has_valid_extension(&url) == true);
+            assert!(PhotoUrl::has_valid_extension(&url));
         }
     }
 
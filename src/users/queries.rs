@@ -4,7 +4,7 @@
 };
 use argon2::{password_hash::SaltString, Argon2, PasswordHasher};
 use rand::rngs::OsRng;
-use sqlx::{query, Pool, Postgres};
+use sqlx::{query, Executor, Pool, Postgres};
 use uuid::Uuid;
 
 use crate::{
@@ -14,10 +14,13 @@ use crate::{
 };
 
 impl User {
-    pub async fn get_by_id(id: Uuid, pool: &Pool<Postgres>) -> Result<User, OmniError> {
+    pub async fn get_by_id<'e, E>(id: Uuid, executor: E) -> Result<User, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
         let user =
             sqlx::query!("SELECT handle, picture_link FROM users WHERE id = $1", id)
-                .fetch_one(pool)
+                .fetch_one(executor)
                 .await?;
 
         Ok(User {
@@ -175,17 +178,20 @@ impl User {
     }
 
     // ---------- DATABASE HELPERS ----------
-    pub async fn get_roles(
+    pub async fn get_roles<'e, E>(
         &self,
         tournament: Uuid,
-        pool: &Pool<Postgres>,
-    ) -> Result<Vec<Role>, OmniError> {
+        executor: E,
+    ) -> Result<Vec<Role>, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
         let roles_result = sqlx::query!(
             "SELECT roles FROM roles WHERE user_id = $1 AND tournament_id = $2",
             self.id,
             tournament
         )
-        .fetch_optional(pool)
+        .fetch_optional(executor)
         .await?;
 
         if roles_result.is_none() {
@@ -203,13 +209,16 @@ impl User {
         Ok(vec)
     }
 
-    pub async fn has_role(
+    pub async fn has_role<'e, E>(
         &self,
         role: Role,
         tournament_id: Uuid,
-        pool: &Pool<Postgres>,
-    ) -> Result<bool, OmniError> {
-        let roles = self.get_roles(tournament_id, pool).await?;
+        executor: E,
+    ) -> Result<bool, OmniError>
+    where
+        E: Executor<'e, Database = Postgres>,
+    {
+        let roles = self.get_roles(tournament_id, executor).await?;
         Ok(roles.contains(&role))
     }
 
@@ -248,7 +257,13 @@ impl User {
         &self,
         pool: &Pool<Postgres>,
     ) -> Result<bool, OmniError> {
-        match query!("SELECT EXISTS(SELECT 1 FROM users u JOIN roles r ON u.id = r.user_id WHERE 'Organizer' = ANY(r.roles))").fetch_one(pool).await         {
+        match query!(
+            "SELECT EXISTS(SELECT 1 FROM roles WHERE user_id = $1 AND 'Organizer' = ANY(roles))",
+            self.id
+        )
+        .fetch_one(pool)
+        .await
+        {
             Ok(result) => Ok(result.exists.unwrap()),
             Err(_) => Err(OmniError::InternalServerError),
         }
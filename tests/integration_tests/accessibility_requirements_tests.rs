@@ -0,0 +1,344 @@
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    accessibility_requirements_utils::{
+        get_accessibility_requirements, put_accessibility_requirements,
+    },
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::create_debate,
+    get_response_json,
+    phases_utils::get_id_of_a_new_group_phase,
+    rooms_utils::{create_room, get_id_of_a_new_location},
+    rounds_utils::{allocate_rooms, get_id_of_a_new_round},
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_id_of_a_new_user, get_organizer_token},
+};
+
+struct Round {
+    phase_id: String,
+    round_id: String,
+    location_id: String,
+}
+
+async fn set_up_a_round(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<Round, OmniError> {
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    let round_id = get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?;
+    let location_id =
+        get_id_of_a_new_location(app, tournament_id, "Campus", token).await?;
+
+    Ok(Round {
+        phase_id,
+        round_id,
+        location_id,
+    })
+}
+
+async fn get_id_of_a_new_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    round_id: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let debate =
+        get_response_json(create_debate(app, tournament_id, round_id, token).await)
+            .await?;
+    Ok(debate["id"].as_str().unwrap().to_owned())
+}
+
+async fn get_id_of_a_new_room(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    mut room: Value,
+    token: &str,
+) -> Result<String, OmniError> {
+    room["location_id"] = json!(location_id);
+    let room = get_response_json(
+        create_room(app, tournament_id, location_id, &room, token).await,
+    )
+    .await?;
+    Ok(room["id"].as_str().unwrap().to_owned())
+}
+
+/// Returns IDs of rooms allocated to the given debates
+async fn allocate(
+    app: &TestApp,
+    tournament_id: &str,
+    round: &Round,
+    debate_ids: &[&str],
+    token: &str,
+) -> Result<Vec<Value>, OmniError> {
+    let debates = get_response_json(
+        allocate_rooms(app, tournament_id, &round.phase_id, &round.round_id, token).await,
+    )
+    .await?;
+    Ok(debate_ids
+        .iter()
+        .map(|id| {
+            debates
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|debate| debate["id"] == *id)
+                .unwrap()["room_id"]
+                .clone()
+        })
+        .collect())
+}
+
+#[tokio::test]
+async fn accessibility_requirements_should_be_set() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let requirements = json!({
+        "needs_step_free_access": true,
+        "needs_projector": false,
+        "needs_online_room": true,
+    });
+
+    // WHEN
+    let unset = get_response_json(
+        get_accessibility_requirements(
+            &app,
+            &tournament_id,
+            &format!("teams/{}", team_id),
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    for path in [format!("teams/{}", team_id), format!("judges/{}", judge_id)] {
+        put_accessibility_requirements(
+            &app,
+            &tournament_id,
+            &path,
+            &requirements,
+            &token,
+        )
+        .await;
+    }
+    let team_requirements = get_response_json(
+        get_accessibility_requirements(
+            &app,
+            &tournament_id,
+            &format!("teams/{}", team_id),
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let judge_requirements = get_response_json(
+        get_accessibility_requirements(
+            &app,
+            &tournament_id,
+            &format!("judges/{}", judge_id),
+            &token,
+        )
+        .await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(
+        unset,
+        json!({
+            "needs_step_free_access": false,
+            "needs_projector": false,
+            "needs_online_room": false,
+        })
+    );
+    assert_eq!(team_requirements, requirements);
+    assert_eq!(judge_requirements, requirements);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_judges_should_have_accessibility_requirements() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let user_id = get_id_of_a_new_user(&app, "not_a_judge", "some password").await;
+
+    // WHEN
+    let response = put_accessibility_requirements(
+        &app,
+        &tournament_id,
+        &format!("judges/{}", user_id),
+        &json!({ "needs_projector": true }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_allocated_to_teams_needing_them() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let round = set_up_a_round(&app, &tournament_id, &token).await?;
+    let step_free_room_id = get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 1", "is_step_free": true }),
+        &token,
+    )
+    .await?;
+    let other_room_id = get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 2", "floor": 2 }),
+        &token,
+    )
+    .await?;
+    let first_debate_id =
+        get_id_of_a_new_debate(&app, &tournament_id, &round.round_id, &token).await?;
+    let second_debate_id =
+        get_id_of_a_new_debate(&app, &tournament_id, &round.round_id, &token).await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &second_debate_id,
+        &team_id,
+        Some(true),
+        &token,
+    )
+    .await?;
+    put_accessibility_requirements(
+        &app,
+        &tournament_id,
+        &format!("teams/{}", team_id),
+        &json!({ "needs_step_free_access": true }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let room_ids = allocate(
+        &app,
+        &tournament_id,
+        &round,
+        &[&first_debate_id, &second_debate_id],
+        &token,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(
+        room_ids,
+        vec![json!(other_room_id), json!(step_free_room_id)]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_allocated_to_judges_needing_them() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let round = set_up_a_round(&app, &tournament_id, &token).await?;
+    get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 1" }),
+        &token,
+    )
+    .await?;
+    let projector_room_id = get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 2", "has_projector": true }),
+        &token,
+    )
+    .await?;
+    let debate_id =
+        get_id_of_a_new_debate(&app, &tournament_id, &round.round_id, &token).await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    put_accessibility_requirements(
+        &app,
+        &tournament_id,
+        &format!("judges/{}", judge_id),
+        &json!({ "needs_projector": true }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let room_ids = allocate(&app, &tournament_id, &round, &[&debate_id], &token).await?;
+
+    // THEN
+    assert_eq!(room_ids, vec![json!(projector_room_id)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_allocated_to_debates_they_can_hold() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let round = set_up_a_round(&app, &tournament_id, &token).await?;
+    get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 1", "capacity": 2 }),
+        &token,
+    )
+    .await?;
+    let debate_id =
+        get_id_of_a_new_debate(&app, &tournament_id, &round.round_id, &token).await?;
+    for _ in 0..3 {
+        let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    }
+
+    // WHEN
+    let too_small = allocate_rooms(
+        &app,
+        &tournament_id,
+        &round.phase_id,
+        &round.round_id,
+        &token,
+    )
+    .await;
+    let big_room_id = get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &round.location_id,
+        json!({ "name": "Room 2", "capacity": 3 }),
+        &token,
+    )
+    .await?;
+    let room_ids = allocate(&app, &tournament_id, &round, &[&debate_id], &token).await?;
+
+    // THEN
+    assert_eq!(too_small.status(), StatusCode::CONFLICT);
+    assert_eq!(room_ids, vec![json!(big_room_id)]);
+    Ok(())
+}
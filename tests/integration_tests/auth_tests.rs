@@ -12,5 +12,5 @@ async fn login_as_infraadmin_should_work_out_of_the_box() {
 
     // THEN
     assert_eq!(res.status(), StatusCode::OK);
-    assert_eq!(res.text().await.is_ok(), true);
+    assert!(res.text().await.is_ok());
 }
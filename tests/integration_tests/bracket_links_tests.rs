@@ -0,0 +1,223 @@
+use reqwest::StatusCode;
+use serde_json::Value;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debates_utils::{change_debate_status, get_debate},
+    draws_utils::{
+        create_teams, draw_phase, hold_debate, rule_drawn_debates, seed_phase,
+    },
+    get_response_json,
+    phases_utils::finish_phase,
+    plans_utils::get_ids_of_planned_phases,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_organizer_token},
+    verdicts_utils::{
+        delete_verdict, get_all_verdicts, get_id_of_a_new_verdict, patch_verdict,
+    },
+};
+
+/// Plays the group phase of a 6 team tournament and seeds a bracket of 4 teams
+async fn seed_a_bracket(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<Value, OmniError> {
+    let (group_phase_id, finals_phase_id) =
+        get_ids_of_planned_phases(app, tournament_id, 4, 3, 2, 6, token).await;
+    create_teams(app, tournament_id).await;
+    let group_draw = get_response_json(
+        draw_phase(app, tournament_id, &group_phase_id, false, token).await,
+    )
+    .await?;
+    rule_drawn_debates(app, tournament_id, &group_draw, token).await?;
+    finish_phase(app, tournament_id, &group_phase_id, token).await;
+
+    get_response_json(seed_phase(app, tournament_id, &finals_phase_id, None, token).await)
+        .await
+}
+
+async fn get_ladder(app: &TestApp, tournament_id: &str, token: &str) -> Value {
+    app.client
+        .get(app.url(&format!("/tournaments/{}/ladder", tournament_id)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .json::<Value>()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn ladder_should_show_bracket_links_of_a_seeded_bracket() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let seeding = seed_a_bracket(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let ladder = get_ladder(&app, &tournament_id, &token).await;
+
+    // THEN
+    let links = ladder["bracket_links"].as_array().unwrap();
+    assert_eq!(links.len(), 2);
+    for (drawn_debate, is_proposition) in seeding["debates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .zip([true, false])
+    {
+        let link = links
+            .iter()
+            .find(|link| link["source_debate_id"] == drawn_debate["debate_id"])
+            .unwrap();
+        assert_eq!(link["is_proposition"], is_proposition);
+        assert_eq!(link["target_debate_id"], links[0]["target_debate_id"]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn winners_should_advance_once_all_verdicts_are_in() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let seeding = seed_a_bracket(&app, &tournament_id, &token).await?;
+    let semifinal = &seeding["debates"][0];
+    let semifinal_id = semifinal["debate_id"].as_str().unwrap();
+    let ladder = get_ladder(&app, &tournament_id, &token).await;
+    let final_id = ladder["bracket_links"][0]["target_debate_id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+
+    let first_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let second_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    for judge_id in [&first_judge_id, &second_judge_id] {
+        get_id_of_a_new_debate_judge(&app, &tournament_id, semifinal_id, judge_id)
+            .await?;
+    }
+
+    // WHEN
+    get_id_of_a_new_verdict(
+        &app,
+        &tournament_id,
+        &first_judge_id,
+        semifinal_id,
+        &true,
+        &token,
+    )
+    .await?;
+    let final_after_one_verdict =
+        get_response_json(get_debate(&app, &final_id, &tournament_id, &token).await)
+            .await?;
+    let verdict_id = get_id_of_a_new_verdict(
+        &app,
+        &tournament_id,
+        &second_judge_id,
+        semifinal_id,
+        &true,
+        &token,
+    )
+    .await?;
+    let final_after_all_verdicts =
+        get_response_json(get_debate(&app, &final_id, &tournament_id, &token).await)
+            .await?;
+
+    // THEN
+    assert!(final_after_one_verdict["teams"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    let teams = final_after_all_verdicts["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0]["team_id"], semifinal["proposition_team_id"]);
+    assert_eq!(teams[0]["is_proposition"], true);
+
+    // WHEN
+    let response = patch_verdict(
+        &app,
+        &verdict_id,
+        &tournament_id,
+        &second_judge_id,
+        semifinal_id,
+        &false,
+        &token,
+    )
+    .await;
+    let final_after_split_verdicts =
+        get_response_json(get_debate(&app, &final_id, &tournament_id, &token).await)
+            .await?;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(final_after_split_verdicts["teams"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn advanced_winners_should_stay_once_the_next_debate_has_started(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let seeding = seed_a_bracket(&app, &tournament_id, &token).await?;
+    rule_drawn_debates(&app, &tournament_id, &seeding, &token).await?;
+    let ladder = get_ladder(&app, &tournament_id, &token).await;
+    let final_id = ladder["bracket_links"][0]["target_debate_id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    get_response_json(
+        change_debate_status(&app, &tournament_id, &final_id, "SidesDrawn", &token).await,
+    )
+    .await?;
+    hold_debate(&app, &tournament_id, &final_id, &token).await?;
+
+    let semifinal_id = seeding["debates"][0]["debate_id"].as_str().unwrap();
+    let verdicts = get_response_json(
+        get_all_verdicts(&app, &tournament_id, semifinal_id, &token).await,
+    )
+    .await?;
+    let verdict_id = verdicts[0]["id"].as_str().unwrap();
+    let judge_id = verdicts[0]["judge_user_id"].as_str().unwrap();
+
+    // WHEN
+    let patch = patch_verdict(
+        &app,
+        verdict_id,
+        &tournament_id,
+        judge_id,
+        semifinal_id,
+        &false,
+        &token,
+    )
+    .await;
+    let deletion =
+        delete_verdict(&app, verdict_id, &tournament_id, semifinal_id, &token).await;
+    let final_debate =
+        get_response_json(get_debate(&app, &final_id, &tournament_id, &token).await)
+            .await?;
+
+    // THEN
+    assert_eq!(patch.status(), StatusCode::CONFLICT);
+    assert_eq!(deletion.status(), StatusCode::CONFLICT);
+    let teams = final_debate["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 2);
+    assert!(teams
+        .iter()
+        .any(|team| team["team_id"] == seeding["debates"][0]["proposition_team_id"]));
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use reqwest::Response;
+
+use crate::common::test_app::TestApp;
+
+/// Puts accessibility requirements at the given path relative to the tournament,
+/// e.g. `teams/{id}` or `judges/{id}`
+pub async fn put_accessibility_requirements(
+    app: &TestApp,
+    tournament_id: &str,
+    path: &str,
+    requirements: &serde_json::Value,
+    token: &str,
+) -> Response {
+    app.client
+        .put(app.url(&format!(
+            "/tournaments/{}/{}/accessibility_requirements",
+            tournament_id, path
+        )))
+        .json(requirements)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_accessibility_requirements(
+    app: &TestApp,
+    tournament_id: &str,
+    path: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/{}/accessibility_requirements",
+            tournament_id, path
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
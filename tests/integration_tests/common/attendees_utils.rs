@@ -0,0 +1,52 @@
+use reqwest::{Response, StatusCode};
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::test_app::TestApp;
+
+pub async fn get_id_of_a_new_attendee(
+    app: &TestApp,
+    tournament_id: &str,
+    team_id: &str,
+    position: Option<i32>,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = create_attendee(app, tournament_id, team_id, position, token).await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error creating an attendee: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get an attendee".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_attendee(
+    app: &TestApp,
+    tournament_id: &str,
+    team_id: &str,
+    position: Option<i32>,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!("/tournaments/{}/attendee", tournament_id)))
+        .json(&json!({
+            "name": "Speaker",
+            "position": position,
+            "team_id": team_id,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,93 @@
+use reqwest::{Response, StatusCode};
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{debates_utils::get_debate, test_app::TestApp};
+
+pub async fn get_id_of_a_new_preset_debate_format(
+    app: &TestApp,
+    tournament_id: &str,
+    preset: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response =
+        create_debate_format_from_preset(app, tournament_id, preset, token).await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error creating a debate format: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a debate format".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_debate_format(
+    app: &TestApp,
+    tournament_id: &str,
+    format: Value,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!("/tournaments/{}/debate_formats", tournament_id)))
+        .json(&format)
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn create_debate_format_from_preset(
+    app: &TestApp,
+    tournament_id: &str,
+    preset: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debate_format_presets/{}",
+            tournament_id, preset
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Makes the debate use the format instead of the format of its tournament
+pub async fn override_debate_format(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    debate_format_id: &str,
+    token: &str,
+) -> Response {
+    let debate = get_debate(app, debate_id, tournament_id, token)
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+    app.client
+        .patch(app.url(&format!(
+            "/tournaments/{}/debates/{}",
+            tournament_id, debate_id
+        )))
+        .json(&json!({
+            "round_id": debate["round_id"],
+            "debate_format_id": debate_format_id,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::common::test_app::TestApp;
+
+use reqwest::{Response, StatusCode};
+use tau::omni_error::OmniError;
+
+use crate::common::auth_utils::get_session_token_for_infrastructure_admin;
+
+pub async fn get_id_of_a_new_debate_judge(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    judge_id: &str,
+) -> Result<String, OmniError> {
+    let token = get_session_token_for_infrastructure_admin(app).await;
+    let response =
+        create_debate_judge(app, tournament_id, debate_id, judge_id, &token).await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error assigning a Judge to a debate: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a Judge assignment".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_debate_judge(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    judge_id: &str,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("judge_user_id", judge_id);
+    request_body.insert("debate_id", debate_id);
+
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/judges",
+            tournament_id, debate_id
+        )))
+        .json(&request_body)
+        .header("accept", "text/plain")
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_debate_judges(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/debates/{}/judges",
+            tournament_id, debate_id
+        )))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn delete_debate_judge(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .delete(app.url(&format!(
+            "/tournaments/{}/debates/{}/judges/{}",
+            tournament_id, debate_id, id
+        )))
+        .header("accept", "text/plain")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
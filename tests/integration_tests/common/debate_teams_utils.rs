@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use crate::common::test_app::TestApp;
+
+use reqwest::{Response, StatusCode};
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+pub async fn get_id_of_a_new_debate_team(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    team_id: &str,
+    is_proposition: Option<bool>,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = create_debate_team(
+        app,
+        tournament_id,
+        debate_id,
+        team_id,
+        is_proposition,
+        token,
+    )
+    .await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error assigning a team to a debate: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a team assignment".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_debate_team(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    team_id: &str,
+    is_proposition: Option<bool>,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("team_id", Value::String(team_id.to_owned()));
+    request_body.insert("debate_id", Value::String(debate_id.to_owned()));
+    request_body.insert(
+        "is_proposition",
+        is_proposition.map_or(Value::Null, Value::Bool),
+    );
+
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/teams",
+            tournament_id, debate_id
+        )))
+        .json(&request_body)
+        .header("accept", "text/plain")
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Assigns a team to a position of the debate, e.g. "OpeningGovernment"
+pub async fn create_debate_team_on_side(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    team_id: &str,
+    side: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/teams",
+            tournament_id, debate_id
+        )))
+        .json(&json!({
+            "team_id": team_id,
+            "debate_id": debate_id,
+            "side": side,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_debate_teams(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/debates/{}/teams",
+            tournament_id, debate_id
+        )))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn patch_debate_team(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    id: &str,
+    is_proposition: bool,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("is_proposition", Value::Bool(is_proposition));
+
+    app.client
+        .patch(app.url(&format!(
+            "/tournaments/{}/debates/{}/teams/{}",
+            tournament_id, debate_id, id
+        )))
+        .json(&request_body)
+        .header("accept", "text/plain")
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn delete_debate_team(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .delete(app.url(&format!(
+            "/tournaments/{}/debates/{}/teams/{}",
+            tournament_id, debate_id, id
+        )))
+        .header("accept", "text/plain")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
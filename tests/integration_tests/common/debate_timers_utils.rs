@@ -0,0 +1,40 @@
+use reqwest::Response;
+use serde_json::json;
+
+use crate::common::test_app::TestApp;
+
+pub async fn get_debate_timer(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/debates/{}/timer",
+            tournament_id, debate_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn operate_debate_timer(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    action: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/timer",
+            tournament_id, debate_id
+        )))
+        .json(&json!({ "action": action }))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
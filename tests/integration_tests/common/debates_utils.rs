@@ -32,6 +32,31 @@ pub async fn get_id_of_a_new_debate(
     }
 }
 
+/// Creates a new group phase with a single round holding `count` debates
+pub async fn get_ids_of_new_debates_in_one_round(
+    app: &TestApp,
+    tournament_id: &str,
+    count: usize,
+) -> Result<Vec<String>, OmniError> {
+    let token = get_session_token_for_infrastructure_admin(app).await;
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(app, tournament_id, &phase_id, &token).await?;
+    let mut debate_ids = vec![];
+    for _ in 0..count {
+        let response = create_debate(app, tournament_id, &round_id, &token).await;
+        match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+            Some(id) => debate_ids.push(id.to_owned()),
+            None => {
+                return Err(OmniError::ExplicitError {
+                    status: StatusCode::INTERNAL_SERVER_ERROR,
+                    message: "Failed to get a debate".to_owned(),
+                })
+            }
+        }
+    }
+    Ok(debate_ids)
+}
+
 pub async fn create_debate(
     app: &TestApp,
     tournament_id: &str,
@@ -53,6 +78,49 @@ pub async fn create_debate(
         .unwrap()
 }
 
+pub async fn create_debate_with_motion(
+    app: &TestApp,
+    tournament_id: &str,
+    round_id: &str,
+    motion_id: &str,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("round_id", round_id);
+    request_body.insert("tournament_id", tournament_id);
+    request_body.insert("motion_id", motion_id);
+
+    app.client
+        .post(app.url(&format!("/tournaments/{}/debates", tournament_id)))
+        .json(&request_body)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn change_debate_status(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    status: &str,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("status", status);
+
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/status",
+            tournament_id, debate_id
+        )))
+        .json(&request_body)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
 pub async fn get_debate(
     app: &TestApp,
     id: &str,
@@ -68,6 +136,24 @@ pub async fn get_debate(
         .unwrap()
 }
 
+pub async fn get_debate_result(
+    app: &TestApp,
+    id: &str,
+    tournament_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/debates/{}/result",
+            tournament_id, id
+        )))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
 pub async fn get_all_debates(
     app: &TestApp,
     judge_id: &str,
@@ -86,6 +172,22 @@ pub async fn get_all_debates(
         .unwrap()
 }
 
+pub async fn patch_tournament_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    id: &str,
+    patch: &serde_json::Value,
+    token: &str,
+) -> Response {
+    app.client
+        .patch(app.url(&format!("/tournaments/{}/debates/{}", tournament_id, id)))
+        .json(patch)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
 pub async fn patch_debate(
     app: &TestApp,
     id: &str,
@@ -122,3 +224,24 @@ pub async fn delete_debate(
         .await
         .unwrap()
 }
+
+pub async fn create_debate_in_room(
+    app: &TestApp,
+    tournament_id: &str,
+    round_id: &str,
+    room_id: &str,
+    token: &str,
+) -> Response {
+    let mut request_body = HashMap::new();
+    request_body.insert("round_id", round_id);
+    request_body.insert("tournament_id", tournament_id);
+    request_body.insert("room_id", room_id);
+
+    app.client
+        .post(app.url(&format!("/tournaments/{}/debates", tournament_id)))
+        .json(&request_body)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
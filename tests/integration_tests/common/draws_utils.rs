@@ -0,0 +1,185 @@
+use reqwest::Response;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debates_utils::{change_debate_status, get_debate},
+    get_response_json,
+    motions_utils::get_id_of_a_new_motion,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::patch_tournament,
+    user_utils::get_id_of_a_new_judge,
+    verdicts_utils::get_id_of_a_new_verdict,
+};
+
+pub async fn draw_phase(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    dry_run: bool,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/draw",
+            tournament_id, phase_id
+        )))
+        .json(&json!({ "dry_run": dry_run }))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn seed_phase(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    advancing_teams: Option<i32>,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/seed",
+            tournament_id, phase_id
+        )))
+        .json(&json!({ "advancing_teams": advancing_teams }))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn seed_phase_keeping_groups_apart(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    advancing_teams: i32,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/seed",
+            tournament_id, phase_id
+        )))
+        .json(&json!({ "advancing_teams": advancing_teams, "keep_groups_apart": true }))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn draw_round(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    round_id: &str,
+    dry_run: bool,
+    seed: Option<u64>,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}/draw",
+            tournament_id, phase_id, round_id
+        )))
+        .json(&json!({ "dry_run": dry_run, "seed": seed }))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+const TEAM_NAMES: [&str; 6] = [
+    "Team Alpha",
+    "Team Bravo",
+    "Team Charlie",
+    "Team Delta",
+    "Team Echo",
+    "Team Foxtrot",
+];
+
+pub async fn create_teams(app: &TestApp, tournament_id: &str) -> Vec<String> {
+    let mut team_ids = vec![];
+    for name in TEAM_NAMES {
+        team_ids.push(get_id_of_a_new_team(app, tournament_id, name).await);
+    }
+    team_ids
+}
+
+/// Rules every drawn debate in favour of the Proposition
+pub async fn rule_drawn_debates(
+    app: &TestApp,
+    tournament_id: &str,
+    draw: &Value,
+    token: &str,
+) -> Result<(), OmniError> {
+    patch_tournament(
+        app,
+        tournament_id,
+        &json!({ "debate_preparation_time": 0 }),
+        token,
+    )
+    .await;
+    for drawn_debate in draw["debates"].as_array().unwrap() {
+        let debate_id = drawn_debate["debate_id"].as_str().unwrap();
+        let judge_id = get_id_of_a_new_judge(app, tournament_id).await?;
+        get_id_of_a_new_debate_judge(app, tournament_id, debate_id, &judge_id).await?;
+        hold_debate(app, tournament_id, debate_id, token).await?;
+        get_id_of_a_new_verdict(app, tournament_id, &judge_id, debate_id, &true, token)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Takes a drawn debate through preparation and speeches
+/// until it awaits verdicts of its panel
+pub async fn hold_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Result<(), OmniError> {
+    let motion_id = get_id_of_a_new_motion(
+        app,
+        tournament_id,
+        &format!("Motion of {debate_id}"),
+        token,
+    )
+    .await?;
+    let debate =
+        get_response_json(get_debate(app, debate_id, tournament_id, token).await).await?;
+    let patch = json!({
+        "motion_id": motion_id,
+        "marshal_user_id": debate["marshal_user_id"],
+        "round_id": debate["round_id"],
+        "debate_format_id": debate["debate_format_id"],
+        "room_id": debate["room_id"],
+    });
+    get_response_json(
+        app.client
+            .patch(app.url(&format!(
+                "/tournaments/{}/debates/{}",
+                tournament_id, debate_id
+            )))
+            .json(&patch)
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap(),
+    )
+    .await?;
+    for status in ["Preparation", "InProgress", "AwaitingVerdicts"] {
+        get_response_json(
+            change_debate_status(app, tournament_id, debate_id, status, token).await,
+        )
+        .await?;
+    }
+    Ok(())
+}
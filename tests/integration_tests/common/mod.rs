@@ -3,13 +3,26 @@ use reqwest::Response;
 use sqlx::{Pool, Postgres};
 use tokio::net::TcpListener;
 use tower_cookies::CookieManagerLayer;
+pub mod accessibility_requirements_utils;
 pub mod affiliations_utils;
+pub mod attendees_utils;
 pub mod auth_utils;
+pub mod debate_formats_utils;
+pub mod debate_judges_utils;
+pub mod debate_teams_utils;
+pub mod debate_timers_utils;
 pub mod debates_utils;
+pub mod draws_utils;
+pub mod motion_bank_utils;
+pub mod motions_utils;
 pub mod phases_utils;
 pub mod plans_utils;
 pub mod roles_utils;
+pub mod rooms_utils;
 pub mod rounds_utils;
+pub mod rule_overrides_utils;
+pub mod side_draws_utils;
+pub mod tab_utils;
 pub mod teams_utils;
 pub mod test_app;
 pub mod tournament_utils;
@@ -0,0 +1,91 @@
+use reqwest::{Response, StatusCode};
+use serde_json::Value;
+use tau::omni_error::OmniError;
+
+use crate::common::test_app::TestApp;
+
+pub async fn get_id_of_a_new_bank_motion(
+    app: &TestApp,
+    motion: Value,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = create_bank_motion(app, motion, token).await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error creating a bank motion: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a bank motion".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_bank_motion(app: &TestApp, motion: Value, token: &str) -> Response {
+    app.client
+        .post(app.url("/motion_bank"))
+        .json(&motion)
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn patch_bank_motion(
+    app: &TestApp,
+    id: &str,
+    patch: Value,
+    token: &str,
+) -> Response {
+    app.client
+        .patch(app.url(&format!("/motion_bank/{}", id)))
+        .json(&patch)
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn delete_bank_motion(app: &TestApp, id: &str, token: &str) -> Response {
+    app.client
+        .delete(app.url(&format!("/motion_bank/{}", id)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn search_motion_bank(app: &TestApp, query: &str, token: &str) -> Response {
+    app.client
+        .get(app.url(&format!("/motion_bank?{}", query)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn copy_bank_motion_to_tournament(
+    app: &TestApp,
+    tournament_id: &str,
+    bank_motion_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/motions/from_bank/{}",
+            tournament_id, bank_motion_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
@@ -0,0 +1,57 @@
+use reqwest::{Response, StatusCode};
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::test_app::TestApp;
+
+pub async fn get_id_of_a_new_motion(
+    app: &TestApp,
+    tournament_id: &str,
+    motion: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = create_motion(app, tournament_id, motion, token).await;
+    if response.status() != StatusCode::OK {
+        return Err(OmniError::ExplicitError {
+            status: response.status(),
+            message: format!(
+                "Error creating a motion: {}",
+                response.text().await.unwrap()
+            ),
+        });
+    }
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a motion".to_owned(),
+        }),
+    }
+}
+
+pub async fn create_motion(
+    app: &TestApp,
+    tournament_id: &str,
+    motion: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!("/tournaments/{}/motions", tournament_id)))
+        .json(&json!({
+            "motion": motion,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_motions(app: &TestApp, tournament_id: &str, token: &str) -> Response {
+    app.client
+        .get(app.url(&format!("/tournaments/{}/motions", tournament_id)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
@@ -1,9 +1,9 @@
 use std::collections::HashMap;
 
-use crate::common::test_app::TestApp;
+use crate::common::{rounds_utils::get_ids_of_rounds_of_phase, test_app::TestApp};
 
 use reqwest::{Response, StatusCode};
-use serde_json::Value;
+use serde_json::{json, Value};
 use tau::omni_error::OmniError;
 use uuid::Uuid;
 
@@ -12,7 +12,7 @@ pub async fn get_id_of_a_new_group_phase(
     tournament_id: &str,
     token: &str,
 ) -> Result<String, OmniError> {
-    match create_phase(&app, tournament_id, &false, &token)
+    match create_phase(app, tournament_id, &false, token)
         .await
         .json::<serde_json::Value>()
         .await
@@ -34,10 +34,7 @@ pub async fn create_phase(
     token: &str,
 ) -> Response {
     let mut request_body = HashMap::new();
-    request_body.insert(
-        "name",
-        Value::String(format!("phase_{}", Uuid::now_v7().to_string())),
-    );
+    request_body.insert("name", Value::String(format!("phase_{}", Uuid::now_v7())));
     request_body.insert("tournament_id", Value::String(tournament_id.to_owned()));
     request_body.insert("status", Value::String("Planned".to_owned()));
     request_body.insert("is_finals", Value::Bool(is_finals.to_owned()));
@@ -52,3 +49,36 @@ pub async fn create_phase(
         .await
         .unwrap()
 }
+
+/// Marks all rounds of a phase and the phase itself as finished
+pub async fn finish_phase(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    token: &str,
+) {
+    for round_id in get_ids_of_rounds_of_phase(app, tournament_id, phase_id, token).await
+    {
+        app.client
+            .patch(app.url(&format!(
+                "/tournaments/{}/phases/{}/rounds/{}",
+                tournament_id, phase_id, round_id
+            )))
+            .json(&json!({ "status": "Finished" }))
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    app.client
+        .patch(app.url(&format!(
+            "/tournaments/{}/phases/{}",
+            tournament_id, phase_id
+        )))
+        .json(&json!({ "status": "Finished" }))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap();
+}
@@ -92,7 +92,7 @@ pub async fn count_debates(pool: &Pool<Postgres>, tournament_id: &str) -> i64 {
 }
 
 pub fn calculate_final_phase_rounds(advancing_teams: i32) -> i32 {
-    let mut teams = advancing_teams.clone();
+    let mut teams = advancing_teams;
     let mut final_phase_rounds = 0;
     if teams != 0 {
         while (teams & 1) == 0 {
@@ -100,7 +100,7 @@ pub fn calculate_final_phase_rounds(advancing_teams: i32) -> i32 {
             teams >>= 1;
         }
     }
-    return final_phase_rounds;
+    final_phase_rounds
 }
 
 pub fn calculate_final_phase_debates(advancing_teams: i32) -> i32 {
@@ -136,3 +136,49 @@ mod test_rounds_calculation {
         assert_eq!(calculate_final_phase_rounds(4), 2);
     }
 }
+
+/// Creates a plan and returns IDs of its group phase and finals phase
+pub async fn get_ids_of_planned_phases(
+    app: &TestApp,
+    tournament_id: &str,
+    advancing_teams: i32,
+    group_phase_rounds: i32,
+    groups_count: i32,
+    total_teams: i32,
+    token: &str,
+) -> (String, String) {
+    create_plan(
+        app,
+        tournament_id,
+        advancing_teams,
+        group_phase_rounds,
+        groups_count,
+        total_teams,
+        token,
+    )
+    .await;
+
+    let phases = app
+        .client
+        .get(app.url(&format!("/tournaments/{}/phases", tournament_id)))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    let phase_id = |is_finals: bool| {
+        phases
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|phase| phase["is_finals"] == is_finals)
+            .unwrap()["id"]
+            .as_str()
+            .unwrap()
+            .to_owned()
+    };
+    (phase_id(false), phase_id(true))
+}
@@ -0,0 +1,162 @@
+use reqwest::{Response, StatusCode};
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::test_app::TestApp;
+
+pub async fn get_id_of_a_new_location(
+    app: &TestApp,
+    tournament_id: &str,
+    name: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = app
+        .client
+        .post(app.url(&format!("/tournaments/{}/locations", tournament_id)))
+        .json(&json!({ "name": name, "tournament_id": tournament_id }))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap();
+    get_id(response, "Failed to get a location").await
+}
+
+pub async fn get_id_of_a_new_room(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    name: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = create_room(
+        app,
+        tournament_id,
+        location_id,
+        &json!({ "name": name, "location_id": location_id }),
+        token,
+    )
+    .await;
+    get_id(response, "Failed to get a room").await
+}
+
+pub async fn create_room(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    room: &Value,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/locations/{}/rooms",
+            tournament_id, location_id
+        )))
+        .json(room)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_room_occupancy(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/locations/{}/occupancy",
+            tournament_id, location_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn close_room(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    room_id: &str,
+    closure: &Value,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/locations/{}/rooms/{}/closures",
+            tournament_id, location_id, room_id
+        )))
+        .json(closure)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn delete_room_closure(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    room_id: &str,
+    closure_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .delete(app.url(&format!(
+            "/tournaments/{}/locations/{}/rooms/{}/closures/{}",
+            tournament_id, location_id, room_id, closure_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn put_travel_time(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    other_location_id: &str,
+    minutes: i32,
+    token: &str,
+) -> Response {
+    app.client
+        .put(app.url(&format!(
+            "/tournaments/{}/locations/{}/travel_times/{}",
+            tournament_id, location_id, other_location_id
+        )))
+        .json(&json!({ "minutes": minutes }))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_travel_times(
+    app: &TestApp,
+    tournament_id: &str,
+    location_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/locations/{}/travel_times",
+            tournament_id, location_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+async fn get_id(response: Response, message: &str) -> Result<String, OmniError> {
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: message.to_owned(),
+        }),
+    }
+}
@@ -13,7 +13,7 @@ pub async fn get_id_of_a_new_round(
     phase_id: &str,
     token: &str,
 ) -> Result<String, OmniError> {
-    match create_round(app, tournament_id, phase_id, &token)
+    match create_round(app, tournament_id, phase_id, token)
         .await
         .json::<serde_json::Value>()
         .await
@@ -36,7 +36,7 @@ pub async fn create_round(
 ) -> Response {
     let _socket_address = get_local_socket_addr();
     let mut request_body = HashMap::new();
-    let phase_name = format!("phase_{}", Uuid::now_v7().to_string());
+    let phase_name = format!("phase_{}", Uuid::now_v7());
 
     request_body.insert("phase_id", Value::String(phase_id.to_owned()));
     request_body.insert("status", Value::String("Planned".to_owned()));
@@ -55,3 +55,157 @@ pub async fn create_round(
         .await
         .unwrap()
 }
+
+/// Returns IDs of all rounds of a phase in the order of creation
+pub async fn get_ids_of_rounds_of_phase(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    token: &str,
+) -> Vec<String> {
+    let rounds = app
+        .client
+        .get(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds",
+            tournament_id, phase_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .json::<serde_json::Value>()
+        .await
+        .unwrap();
+
+    let mut round_ids: Vec<String> = rounds
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|round| round["id"].as_str().unwrap().to_owned())
+        .collect();
+    round_ids.sort();
+    round_ids
+}
+
+pub async fn patch_round(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    round_id: &str,
+    patch: &Value,
+    token: &str,
+) -> Response {
+    app.client
+        .patch(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}",
+            tournament_id, phase_id, round_id
+        )))
+        .json(patch)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Creates a round following the given one, planned for the given time
+pub async fn get_id_of_a_new_round_after(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    previous_round_id: &str,
+    (planned_start_time, planned_end_time): (&str, &str),
+    token: &str,
+) -> Result<String, OmniError> {
+    let request_body = serde_json::json!({
+        "name": format!("round_{}", Uuid::now_v7()),
+        "phase_id": phase_id,
+        "status": "Planned",
+        "previous_round_id": previous_round_id,
+        "planned_start_time": planned_start_time,
+        "planned_end_time": planned_end_time,
+    });
+    let response = app
+        .client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds",
+            tournament_id, phase_id
+        )))
+        .json(&request_body)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap();
+    match response.json::<serde_json::Value>().await.unwrap()["id"].as_str() {
+        Some(id) => Ok(id.to_owned()),
+        None => Err(OmniError::ExplicitError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Failed to get a round".to_owned(),
+        }),
+    }
+}
+
+pub async fn allocate_rooms(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    round_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}/allocate_rooms",
+            tournament_id, phase_id, round_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn allocate_judges(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: &str,
+    round_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}/allocate_judges",
+            tournament_id, phase_id, round_id
+        )))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn generate_schedule(
+    app: &TestApp,
+    tournament_id: &str,
+    schedule: &Value,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!("/tournaments/{}/schedule", tournament_id)))
+        .json(schedule)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn shift_schedule(
+    app: &TestApp,
+    tournament_id: &str,
+    shift: &Value,
+    token: &str,
+) -> Response {
+    app.client
+        .post(app.url(&format!("/tournaments/{}/schedule/shift", tournament_id)))
+        .json(shift)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
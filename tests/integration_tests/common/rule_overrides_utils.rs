@@ -0,0 +1,42 @@
+use reqwest::Response;
+
+use crate::common::test_app::TestApp;
+
+/// Puts rule overrides at the given path relative to the tournament,
+/// e.g. `phases/{id}` or `debates/{id}`
+pub async fn put_rule_overrides(
+    app: &TestApp,
+    tournament_id: &str,
+    path: &str,
+    overrides: &serde_json::Value,
+    token: &str,
+) -> Response {
+    app.client
+        .put(app.url(&format!(
+            "/tournaments/{}/{}/rule_overrides",
+            tournament_id, path
+        )))
+        .json(overrides)
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_effective_rules(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Response {
+    app.client
+        .get(app.url(&format!(
+            "/tournaments/{}/debates/{}/rules",
+            tournament_id, debate_id
+        )))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
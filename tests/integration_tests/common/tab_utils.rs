@@ -0,0 +1,25 @@
+use reqwest::Response;
+
+use crate::common::test_app::TestApp;
+
+/// Gets the tab of a whole tournament, or of one of its phases if `phase_id` is given
+pub async fn get_tab(
+    app: &TestApp,
+    tournament_id: &str,
+    phase_id: Option<&str>,
+    token: &str,
+) -> Response {
+    let path = match phase_id {
+        Some(phase_id) => {
+            format!("/tournaments/{}/phases/{}/tab", tournament_id, phase_id)
+        }
+        None => format!("/tournaments/{}/tab", tournament_id),
+    };
+    app.client
+        .get(app.url(&path))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
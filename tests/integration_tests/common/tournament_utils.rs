@@ -15,7 +15,7 @@ pub async fn create_tournament(
     shortened_name: Option<std::string::String>,
     token: &str,
 ) -> Response {
-    if shortened_name == None {
+    if shortened_name.is_none() {
         return create_tournament_without_shortened_name(app, full_name, token).await;
     } else {
         return create_tournament_with_shortened_name(
@@ -35,7 +35,7 @@ pub async fn create_tournament_without_shortened_name(
 ) -> Response {
     let mut request_body = HashMap::new();
     request_body.insert("full_name", full_name);
-    let shortened = shorten(&full_name);
+    let shortened = shorten(full_name);
     request_body.insert("shortened_name", &shortened);
 
     app.client
@@ -93,6 +93,32 @@ pub async fn get_id_of_a_new_tournament(
     }
 }
 
+pub async fn patch_tournament(
+    app: &TestApp,
+    tournament_id: &str,
+    patch: &serde_json::Value,
+    token: &str,
+) -> Response {
+    app.client
+        .patch(app.url(&format!("/tournaments/{}", tournament_id)))
+        .json(patch)
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+pub async fn get_tournament(app: &TestApp, tournament_id: &str, token: &str) -> Response {
+    app.client
+        .get(app.url(&format!("/tournaments/{}", tournament_id)))
+        .header("accept", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
 #[cfg(test)]
 mod test_shortened_name {
     use tau::tournaments::shorten;
@@ -88,6 +88,20 @@ pub async fn get_id_of_a_new_judge(
     Ok(judge_id)
 }
 
+pub async fn get_id_and_token_of_a_new_judge(
+    app: &TestApp,
+    tournament_id: &str,
+) -> Result<(String, String), OmniError> {
+    let handle = Uuid::now_v7().to_string();
+    let password = "password";
+
+    let token = get_session_token_for_infrastructure_admin(app).await;
+    let judge_id = get_id_of_a_new_user(app, &handle, password).await;
+    create_roles(app, &judge_id, tournament_id, vec![Role::Judge], &token).await;
+    let judge_token = get_session_token_for(app, &handle, password).await?;
+    Ok((judge_id, judge_token))
+}
+
 pub async fn check_permission(
     app: &TestApp,
     user_id: &str,
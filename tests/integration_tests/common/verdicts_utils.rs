@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::common::test_app::TestApp;
 
 use reqwest::{Response, StatusCode};
-use serde_json::Value;
+use serde_json::{json, Value};
 use tau::omni_error::OmniError;
 
 pub async fn get_id_of_a_new_verdict(
@@ -20,7 +20,7 @@ pub async fn get_id_of_a_new_verdict(
         judge_id,
         debate_id,
         proposition_won,
-        &token,
+        token,
     )
     .await;
     if response.status() != StatusCode::OK {
@@ -68,6 +68,70 @@ pub async fn create_verdict(
         .unwrap()
 }
 
+/// Submits a verdict along with scores of the given speakers
+pub async fn create_verdict_with_speaker_scores(
+    app: &TestApp,
+    tournament_id: &str,
+    judge_id: &str,
+    debate_id: &str,
+    proposition_won: bool,
+    speaker_scores: &[(&str, f64)],
+    token: &str,
+) -> Response {
+    let speaker_scores: Vec<Value> = speaker_scores
+        .iter()
+        .map(|(attendee_id, score)| json!({ "attendee_id": attendee_id, "score": score }))
+        .collect();
+
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/verdicts",
+            tournament_id, debate_id
+        )))
+        .json(&json!({
+            "judge_user_id": judge_id,
+            "debate_id": debate_id,
+            "proposition_won": proposition_won,
+            "speaker_scores": speaker_scores,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
+/// Submits a ranking ballot of a four-team debate, placing each position
+pub async fn create_ranking_verdict(
+    app: &TestApp,
+    tournament_id: &str,
+    judge_id: &str,
+    debate_id: &str,
+    rankings: &[(&str, i32)],
+    token: &str,
+) -> Response {
+    let rankings: Vec<Value> = rankings
+        .iter()
+        .map(|(side, rank)| json!({ "side": side, "rank": rank }))
+        .collect();
+
+    app.client
+        .post(app.url(&format!(
+            "/tournaments/{}/debates/{}/verdicts",
+            tournament_id, debate_id
+        )))
+        .json(&json!({
+            "judge_user_id": judge_id,
+            "debate_id": debate_id,
+            "rankings": rankings,
+        }))
+        .header("Content-Type", "application/json")
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+}
+
 pub async fn get_verdict(
     app: &TestApp,
     id: &str,
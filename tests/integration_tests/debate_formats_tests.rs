@@ -0,0 +1,195 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    attendees_utils::create_attendee,
+    debate_formats_utils::{
+        create_debate_format, get_id_of_a_new_preset_debate_format,
+        override_debate_format,
+    },
+    debate_timers_utils::get_debate_timer,
+    debates_utils::{get_debate, get_id_of_a_new_debate, patch_tournament_debate},
+    get_response_json,
+    motions_utils::get_id_of_a_new_motion,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::get_organizer_token,
+};
+
+#[tokio::test]
+async fn attendee_positions_should_follow_the_debate_format() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+    let australs_id =
+        get_id_of_a_new_preset_debate_format(&app, &tournament_id, "Australs", &token)
+            .await?;
+
+    // WHEN
+    let oxford_fourth_speaker =
+        create_attendee(&app, &tournament_id, &team_id, Some(4), &token).await;
+    let patched_tournament = patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "debate_format_id": australs_id }),
+        &token,
+    )
+    .await;
+    let australs_fourth_speaker =
+        create_attendee(&app, &tournament_id, &team_id, Some(4), &token).await;
+    let australs_third_speaker =
+        create_attendee(&app, &tournament_id, &team_id, Some(3), &token).await;
+
+    // THEN
+    assert_eq!(oxford_fourth_speaker.status(), StatusCode::OK);
+    assert_eq!(patched_tournament.status(), StatusCode::OK);
+    assert_eq!(
+        australs_fourth_speaker.status(),
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+    assert_eq!(australs_third_speaker.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn debate_timer_should_follow_the_format_of_the_debate() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let wsdc_id =
+        get_id_of_a_new_preset_debate_format(&app, &tournament_id, "WSDC", &token)
+            .await?;
+
+    // WHEN
+    let overridden =
+        override_debate_format(&app, &tournament_id, &debate_id, &wsdc_id, &token).await;
+    let timer = get_debate_timer(&app, &tournament_id, &debate_id, &token).await;
+
+    // THEN
+    assert_eq!(overridden.status(), StatusCode::OK);
+    let timer = get_response_json(timer).await?;
+    let speeches = timer["speeches"].as_array().unwrap();
+    assert_eq!(speeches.len(), 8);
+    assert_eq!(timer["remaining_ms"], 480_000);
+    assert_eq!(speeches[6]["side"], "Opposition");
+    assert_eq!(speeches[6]["is_reply"], true);
+    assert_eq!(speeches[7]["duration"], 240);
+    Ok(())
+}
+
+#[tokio::test]
+async fn patching_a_debate_should_keep_its_format() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let wsdc_id =
+        get_id_of_a_new_preset_debate_format(&app, &tournament_id, "WSDC", &token)
+            .await?;
+    override_debate_format(&app, &tournament_id, &debate_id, &wsdc_id, &token).await;
+    let debate =
+        get_response_json(get_debate(&app, &debate_id, &tournament_id, &token).await)
+            .await?;
+    let motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id, "This House would debate", &token)
+            .await?;
+
+    // WHEN
+    let response = patch_tournament_debate(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &json!({ "round_id": debate["round_id"], "motion_id": motion_id }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let patched = get_response_json(response).await?;
+    assert_eq!(patched["motion_id"], motion_id);
+    assert_eq!(patched["debate_format_id"], wsdc_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn formats_of_other_tournaments_should_not_be_used() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "first").await?;
+    let other_tournament_id = get_id_of_a_new_tournament(&app, "second").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let other_token = get_organizer_token(&app, &other_tournament_id).await;
+    let wsdc_id =
+        get_id_of_a_new_preset_debate_format(&app, &tournament_id, "WSDC", &token)
+            .await?;
+
+    // WHEN
+    let response = patch_tournament(
+        &app,
+        &other_tournament_id,
+        &json!({ "debate_format_id": wsdc_id }),
+        &other_token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn speeches_should_match_the_speaker_count_of_the_format() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let speech = |side: &str, speaker_position: i32| {
+        json!({
+            "side": side,
+            "speaker_position": speaker_position,
+            "duration": 240,
+        })
+    };
+
+    // WHEN
+    let valid = create_debate_format(
+        &app,
+        &tournament_id,
+        json!({
+            "name": "Short Oxford",
+            "speakers_per_team": 2,
+            "speeches": [speech("Proposition", 1), speech("Opposition", 1),
+                speech("Proposition", 2), speech("Opposition", 2)],
+        }),
+        &token,
+    )
+    .await;
+    let invalid = create_debate_format(
+        &app,
+        &tournament_id,
+        json!({
+            "name": "Broken Oxford",
+            "speakers_per_team": 2,
+            "speeches": [speech("Proposition", 1), speech("Opposition", 3)],
+        }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(valid.status(), StatusCode::OK);
+    let valid = get_response_json(valid).await?;
+    assert_eq!(valid["speeches"][3]["side"], "Opposition");
+    assert_eq!(valid["ad_vocem_time"], 0);
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use reqwest::StatusCode;
+use tau::omni_error::OmniError;
+use uuid::Uuid;
+
+use crate::common::{
+    affiliations_utils::get_id_of_a_new_affiliation,
+    debate_judges_utils::{
+        create_debate_judge, delete_debate_judge, get_debate_judges,
+        get_id_of_a_new_debate_judge,
+    },
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{get_debate, get_ids_of_new_debates_in_one_round},
+    get_response_json,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_id_of_a_new_user, get_organizer_token},
+};
+
+#[tokio::test]
+async fn organizers_should_be_able_to_assign_judges_to_debates() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+
+    // WHEN
+    let response =
+        create_debate_judge(&app, &tournament_id, &debate_id, &judge_id, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let debate =
+        get_response_json(get_debate(&app, &debate_id, &tournament_id, &token).await)
+            .await?;
+    let judges = debate["judges"].as_array().unwrap();
+    assert_eq!(judges.len(), 1);
+    assert_eq!(judges[0]["judge_user_id"], judge_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn users_without_the_judge_role_should_not_be_assigned_to_debates(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let user_id = get_id_of_a_new_user(&app, &Uuid::now_v7().to_string(), "pass").await;
+
+    // WHEN
+    let response =
+        create_debate_judge(&app, &tournament_id, &debate_id, &user_id, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_should_not_be_assigned_to_debates_of_affiliated_teams(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Affiliated team").await;
+    get_id_of_a_new_debate_team(&app, &tournament_id, &debate_id, &team_id, None, &token)
+        .await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_affiliation(&app, &judge_id, &team_id).await?;
+
+    // WHEN
+    let response =
+        create_debate_judge(&app, &tournament_id, &debate_id, &judge_id, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_should_not_rule_two_debates_in_the_same_round() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_ids = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 2).await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_ids[0], &judge_id).await?;
+
+    // WHEN
+    let response =
+        create_debate_judge(&app, &tournament_id, &debate_ids[1], &judge_id, &token)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_assignments_should_not_place_a_judge_twice_in_a_round(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_ids = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 3).await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+
+    // WHEN
+    let assign = |index: usize| {
+        create_debate_judge(&app, &tournament_id, &debate_ids[index], &judge_id, &token)
+    };
+    let (first, second, third) = tokio::join!(assign(0), assign(1), assign(2));
+
+    // THEN
+    let statuses = [first.status(), second.status(), third.status()];
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == StatusCode::OK)
+            .count(),
+        1
+    );
+    assert!(statuses
+        .iter()
+        .all(|status| [StatusCode::OK, StatusCode::CONFLICT].contains(status)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn organizers_should_be_able_to_unassign_judges() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let assignment_id =
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+
+    // WHEN
+    let response =
+        delete_debate_judge(&app, &tournament_id, &debate_id, &assignment_id, &token)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let panel = get_debate_judges(&app, &tournament_id, &debate_id, &token).await;
+    assert_eq!(get_response_json(panel).await?.as_array().unwrap().len(), 0);
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::{
+        delete_debate_judge, get_debate_judges, get_id_of_a_new_debate_judge,
+    },
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{get_debate_result, get_id_of_a_new_debate},
+    get_response_json,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{get_id_of_a_new_judge, get_judge_token, get_organizer_token},
+    verdicts_utils::get_id_of_a_new_verdict,
+};
+
+struct RuledDebate {
+    debate_id: String,
+    proposition_team_id: String,
+    opposition_team_id: String,
+}
+
+/// Creates a debate between two teams with a panel of Judges
+/// voting for the Proposition as given by `votes`.
+/// Judges beyond the number of votes do not submit verdicts.
+async fn rule_a_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    panel_size: usize,
+    votes: &[bool],
+    token: &str,
+) -> Result<RuledDebate, OmniError> {
+    let debate_id = get_id_of_a_new_debate(app, tournament_id).await?;
+    let proposition_team_id =
+        get_id_of_a_new_team(app, tournament_id, "Proposition Team").await;
+    let opposition_team_id =
+        get_id_of_a_new_team(app, tournament_id, "Opposition Team").await;
+    for (team_id, is_proposition) in
+        [(&proposition_team_id, true), (&opposition_team_id, false)]
+    {
+        get_id_of_a_new_debate_team(
+            app,
+            tournament_id,
+            &debate_id,
+            team_id,
+            Some(is_proposition),
+            token,
+        )
+        .await?;
+    }
+
+    for judge_index in 0..panel_size {
+        let judge_id = get_id_of_a_new_judge(app, tournament_id).await?;
+        get_id_of_a_new_debate_judge(app, tournament_id, &debate_id, &judge_id).await?;
+        if let Some(proposition_won) = votes.get(judge_index) {
+            get_id_of_a_new_verdict(
+                app,
+                tournament_id,
+                &judge_id,
+                &debate_id,
+                proposition_won,
+                token,
+            )
+            .await?;
+        }
+    }
+
+    Ok(RuledDebate {
+        debate_id,
+        proposition_team_id,
+        opposition_team_id,
+    })
+}
+
+#[tokio::test]
+async fn result_should_be_incomplete_until_every_judge_submits_a_verdict(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = rule_a_debate(&app, &tournament_id, 3, &[true, true], &token).await?;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response =
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &judge_token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let result = get_response_json(response).await?;
+    assert_eq!(result["status"], "Incomplete");
+    assert_eq!(result["panel_size"], 3);
+    assert_eq!(result["proposition_ballots"], 2);
+    assert_eq!(result["winner_team_id"], Value::Null);
+    Ok(())
+}
+
+#[tokio::test]
+async fn verdicts_of_judges_removed_from_the_panel_should_not_count(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = rule_a_debate(&app, &tournament_id, 2, &[true, true], &token).await?;
+    let panel = get_response_json(
+        get_debate_judges(&app, &tournament_id, &debate.debate_id, &token).await,
+    )
+    .await?;
+    let removed_assignment_id = panel[0]["id"].as_str().unwrap();
+    delete_debate_judge(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        removed_assignment_id,
+        &token,
+    )
+    .await;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate.debate_id, &judge_id)
+        .await?;
+
+    // WHEN
+    let response =
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let result = get_response_json(response).await?;
+    assert_eq!(result["status"], "Incomplete");
+    assert_eq!(result["panel_size"], 2);
+    assert_eq!(result["proposition_ballots"], 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn split_decisions_should_be_won_by_the_majority() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate =
+        rule_a_debate(&app, &tournament_id, 3, &[false, true, false], &token).await?;
+
+    // WHEN
+    let response =
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await;
+
+    // THEN
+    let result = get_response_json(response).await?;
+    assert_eq!(result["status"], "Decided");
+    assert_eq!(result["proposition_won"], false);
+    assert_eq!(result["winner_team_id"], debate.opposition_team_id);
+    assert_eq!(result["opposition_ballots"], 2);
+    assert_eq!(result["is_unanimous"], false);
+    Ok(())
+}
+
+#[tokio::test]
+async fn unanimous_decisions_should_be_marked_as_such() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = rule_a_debate(&app, &tournament_id, 3, &[true; 3], &token).await?;
+
+    // WHEN
+    let response =
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await;
+
+    // THEN
+    let result = get_response_json(response).await?;
+    assert_eq!(result["status"], "Decided");
+    assert_eq!(result["winner_team_id"], debate.proposition_team_id);
+    assert_eq!(result["is_unanimous"], true);
+    Ok(())
+}
+
+#[tokio::test]
+async fn level_verdicts_should_follow_the_tie_policy_of_the_tournament(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = rule_a_debate(&app, &tournament_id, 2, &[true, false], &token).await?;
+    let tied_result = get_response_json(
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await,
+    )
+    .await?;
+
+    // WHEN
+    let response = patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "verdict_tie_policy": "Opposition" }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(tied_result["status"], "Tied");
+    assert_eq!(tied_result["winner_team_id"], Value::Null);
+
+    let result = get_response_json(
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await,
+    )
+    .await?;
+    assert_eq!(result["status"], "Decided");
+    assert_eq!(result["winner_team_id"], debate.opposition_team_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn results_should_not_be_available_through_other_tournaments(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    let debate =
+        rule_a_debate(&app, &tournament_id_alpha, 1, &[true], &token_alpha).await?;
+
+    // WHEN
+    let response =
+        get_debate_result(&app, &debate.debate_id, &tournament_id_bravo, &token_bravo)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ladder_should_include_results_of_debates() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = rule_a_debate(&app, &tournament_id, 1, &[true], &token).await?;
+
+    // WHEN
+    let response = app
+        .client
+        .get(app.url(&format!("/tournaments/{}/ladder", tournament_id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    let ladder = get_response_json(response).await?;
+    let debates = ladder["debates"].as_array().unwrap();
+    assert_eq!(debates.len(), 1);
+    assert_eq!(debates[0]["id"], debate.debate_id);
+    assert_eq!(debates[0]["result"]["debate_id"], debate.debate_id);
+    assert_eq!(
+        debates[0]["result"]["winner_team_id"],
+        debate.proposition_team_id
+    );
+    Ok(())
+}
@@ -0,0 +1,342 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{
+        change_debate_status, create_debate, create_debate_with_motion, get_debate,
+    },
+    get_response_json,
+    motions_utils::get_id_of_a_new_motion,
+    phases_utils::get_id_of_a_new_group_phase,
+    rounds_utils::{get_id_of_a_new_round, patch_round},
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{
+        get_id_of_a_new_judge, get_judge_token, get_marshal_token, get_organizer_token,
+    },
+    verdicts_utils::get_id_of_a_new_verdict,
+};
+
+struct RoundWithDebate {
+    phase_id: String,
+    round_id: String,
+    debate_id: String,
+}
+
+/// Creates a round holding a single debate on a motion,
+/// with both sides taken by teams
+async fn set_up_a_debate_with_drawn_sides(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<RoundWithDebate, OmniError> {
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    let round_id = get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?;
+    let motion_id =
+        get_id_of_a_new_motion(app, tournament_id, "This House would debate", token)
+            .await?;
+    let debate = get_response_json(
+        create_debate_with_motion(app, tournament_id, &round_id, &motion_id, token).await,
+    )
+    .await?;
+    let debate_id = debate["id"].as_str().unwrap().to_owned();
+    for (handle, is_proposition) in [("Team Alpha", true), ("Team Beta", false)] {
+        let team_id = get_id_of_a_new_team(app, tournament_id, handle).await;
+        get_id_of_a_new_debate_team(
+            app,
+            tournament_id,
+            &debate_id,
+            &team_id,
+            Some(is_proposition),
+            token,
+        )
+        .await?;
+    }
+
+    Ok(RoundWithDebate {
+        phase_id,
+        round_id,
+        debate_id,
+    })
+}
+
+#[tokio::test]
+async fn debates_should_be_concluded_once_the_panel_has_ruled() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "debate_preparation_time": 0 }),
+        &token,
+    )
+    .await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate.debate_id, &judge_id)
+        .await?;
+
+    // WHEN
+    let mut statuses = vec![];
+    for status in [
+        "SidesDrawn",
+        "Preparation",
+        "InProgress",
+        "AwaitingVerdicts",
+    ] {
+        let response =
+            change_debate_status(&app, &tournament_id, &debate.debate_id, status, &token)
+                .await;
+        statuses.push(get_response_json(response).await?["status"].clone());
+    }
+    get_id_of_a_new_verdict(
+        &app,
+        &tournament_id,
+        &judge_id,
+        &debate.debate_id,
+        &true,
+        &token,
+    )
+    .await?;
+    let concluded = get_response_json(
+        get_debate(&app, &debate.debate_id, &tournament_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(
+        statuses,
+        vec![
+            "SidesDrawn",
+            "Preparation",
+            "InProgress",
+            "AwaitingVerdicts"
+        ]
+    );
+    assert_eq!(concluded["status"], "Concluded");
+    Ok(())
+}
+
+#[tokio::test]
+async fn verdicts_should_not_conclude_debates_before_they_await_verdicts(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "debate_preparation_time": 0 }),
+        &token,
+    )
+    .await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate.debate_id, &judge_id)
+        .await?;
+
+    // WHEN
+    get_id_of_a_new_verdict(
+        &app,
+        &tournament_id,
+        &judge_id,
+        &debate.debate_id,
+        &true,
+        &token,
+    )
+    .await?;
+    let early = get_response_json(
+        get_debate(&app, &debate.debate_id, &tournament_id, &token).await,
+    )
+    .await?;
+    let mut awaiting = serde_json::Value::Null;
+    for status in [
+        "SidesDrawn",
+        "Preparation",
+        "InProgress",
+        "AwaitingVerdicts",
+    ] {
+        let response =
+            change_debate_status(&app, &tournament_id, &debate.debate_id, status, &token)
+                .await;
+        awaiting = get_response_json(response).await?;
+    }
+
+    // THEN
+    assert_ne!(early["status"], "Concluded");
+    assert_eq!(awaiting["status"], "Concluded");
+    Ok(())
+}
+
+#[tokio::test]
+async fn debates_should_not_skip_stages() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+    let empty_debate = get_response_json(
+        create_debate(&app, &tournament_id, &debate.round_id, &token).await,
+    )
+    .await?;
+    let empty_debate_id = empty_debate["id"].as_str().unwrap();
+
+    // WHEN
+    let skipped_stage = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "InProgress",
+        &token,
+    )
+    .await;
+    let manually_concluded = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "Concluded",
+        &token,
+    )
+    .await;
+    let without_teams =
+        change_debate_status(&app, &tournament_id, empty_debate_id, "SidesDrawn", &token)
+            .await;
+
+    // THEN
+    assert_eq!(skipped_stage.status(), StatusCode::CONFLICT);
+    assert_eq!(manually_concluded.status(), StatusCode::CONFLICT);
+    assert_eq!(without_teams.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn debates_should_not_start_before_the_preparation_time_runs_out(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+    change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "SidesDrawn",
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let preparation = get_response_json(
+        change_debate_status(
+            &app,
+            &tournament_id,
+            &debate.debate_id,
+            "Preparation",
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let started = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "InProgress",
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(preparation["status"], "Preparation");
+    assert!(preparation["preparation_end_time"].is_string());
+    assert_eq!(started.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rounds_should_only_finish_once_their_debates_are_concluded(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+    let finished = json!({ "status": "Finished" });
+
+    // WHEN
+    let finished_early = patch_round(
+        &app,
+        &tournament_id,
+        &debate.phase_id,
+        &debate.round_id,
+        &finished,
+        &token,
+    )
+    .await;
+    let cancelled = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "Cancelled",
+        &token,
+    )
+    .await;
+    let finished_after_cancelling = patch_round(
+        &app,
+        &tournament_id,
+        &debate.phase_id,
+        &debate.round_id,
+        &finished,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(finished_early.status(), StatusCode::CONFLICT);
+    assert_eq!(cancelled.status(), StatusCode::OK);
+    assert_eq!(finished_after_cancelling.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_the_debate_marshal_and_organizers_should_change_debate_status(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let marshal_token = get_marshal_token(&app, &tournament_id).await;
+    let debate = set_up_a_debate_with_drawn_sides(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let judge_response = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "SidesDrawn",
+        &judge_token,
+    )
+    .await;
+    let other_marshal_response = change_debate_status(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        "SidesDrawn",
+        &marshal_token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(judge_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(other_marshal_response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use reqwest::StatusCode;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_teams_utils::{
+        create_debate_team, delete_debate_team, get_debate_teams,
+        get_id_of_a_new_debate_team, patch_debate_team,
+    },
+    debates_utils::{get_debate, get_ids_of_new_debates_in_one_round},
+    get_response_json,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_judge_token, get_organizer_token},
+};
+
+#[tokio::test]
+async fn organizers_should_be_able_to_assign_teams_to_debates() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let proposition_id = get_id_of_a_new_team(&app, &tournament_id, "Proposition").await;
+    let opposition_id = get_id_of_a_new_team(&app, &tournament_id, "Opposition").await;
+
+    // WHEN
+    let proposition_response = create_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &proposition_id,
+        Some(true),
+        &token,
+    )
+    .await;
+    let opposition_response = create_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &opposition_id,
+        Some(false),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(proposition_response.status(), StatusCode::OK);
+    assert_eq!(opposition_response.status(), StatusCode::OK);
+
+    let debate =
+        get_response_json(get_debate(&app, &debate_id, &tournament_id, &token).await)
+            .await?;
+    let teams = debate["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 2);
+    assert!(teams
+        .iter()
+        .any(|t| t["team_id"] == proposition_id.as_str() && t["is_proposition"] == true));
+    assert!(teams
+        .iter()
+        .any(|t| t["team_id"] == opposition_id.as_str() && t["is_proposition"] == false));
+    Ok(())
+}
+
+#[tokio::test]
+async fn debates_should_not_be_attended_by_more_than_two_teams() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    for handle in ["First team", "Second team"] {
+        let team_id = get_id_of_a_new_team(&app, &tournament_id, handle).await;
+        get_id_of_a_new_debate_team(
+            &app,
+            &tournament_id,
+            &debate_id,
+            &team_id,
+            None,
+            &token,
+        )
+        .await?;
+    }
+    let third_team_id = get_id_of_a_new_team(&app, &tournament_id, "Third team").await;
+
+    // WHEN
+    let response = create_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &third_team_id,
+        None,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn debates_should_not_have_two_teams_on_the_same_side() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let first_team_id = get_id_of_a_new_team(&app, &tournament_id, "First team").await;
+    let second_team_id = get_id_of_a_new_team(&app, &tournament_id, "Second team").await;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &first_team_id,
+        Some(true),
+        &token,
+    )
+    .await?;
+    let second_assignment_id = get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &second_team_id,
+        Some(false),
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    let response = patch_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &second_assignment_id,
+        true,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_assignments_should_not_share_a_side() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let mut team_ids = vec![];
+    for handle in ["First team", "Second team", "Third team"] {
+        team_ids.push(get_id_of_a_new_team(&app, &tournament_id, handle).await);
+    }
+
+    // WHEN
+    let assign = |index: usize| {
+        create_debate_team(
+            &app,
+            &tournament_id,
+            &debate_id,
+            &team_ids[index],
+            Some(true),
+            &token,
+        )
+    };
+    let (first, second, third) = tokio::join!(assign(0), assign(1), assign(2));
+
+    // THEN
+    let statuses = [first.status(), second.status(), third.status()];
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == StatusCode::OK)
+            .count(),
+        1
+    );
+    assert!(statuses
+        .iter()
+        .all(|status| [StatusCode::OK, StatusCode::CONFLICT].contains(status)));
+    let teams = get_response_json(
+        get_debate_teams(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+    assert_eq!(teams.as_array().unwrap().len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn teams_should_not_debate_twice_in_the_same_round() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_ids = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 2).await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Busy team").await;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_ids[0],
+        &team_id,
+        None,
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    let response =
+        create_debate_team(&app, &tournament_id, &debate_ids[1], &team_id, None, &token)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn teams_from_other_tournaments_should_not_be_assigned_to_debates(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let other_tournament_id = get_id_of_a_new_tournament(&app, "other").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let team_id = get_id_of_a_new_team(&app, &other_tournament_id, "Outsiders").await;
+
+    // WHEN
+    let response =
+        create_debate_team(&app, &tournament_id, &debate_id, &team_id, None, &token)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_should_be_able_to_read_but_not_modify_debate_teams(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let organizer_token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let debate_id = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 1)
+        .await?
+        .remove(0);
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Some team").await;
+    let assignment_id = get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &team_id,
+        None,
+        &organizer_token,
+    )
+    .await?;
+
+    // WHEN
+    let read_response =
+        get_debate_teams(&app, &tournament_id, &debate_id, &judge_token).await;
+    let delete_response = delete_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &assignment_id,
+        &judge_token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(read_response.status(), StatusCode::OK);
+    assert_eq!(
+        get_response_json(read_response).await?[0]["id"],
+        assignment_id
+    );
+    assert_eq!(delete_response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debate_timers_utils::{get_debate_timer, operate_debate_timer},
+    debates_utils::{
+        change_debate_status, create_debate_with_motion, get_id_of_a_new_debate,
+    },
+    get_response_json,
+    motions_utils::get_id_of_a_new_motion,
+    phases_utils::get_id_of_a_new_group_phase,
+    rounds_utils::get_id_of_a_new_round,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{get_judge_token, get_marshal_token, get_organizer_token},
+};
+
+/// Creates a debate on a motion, with both sides taken by teams,
+/// and brings it to the stage in which speeches are given
+async fn set_up_a_debate_in_progress(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    patch_tournament(
+        app,
+        tournament_id,
+        &json!({ "debate_preparation_time": 0 }),
+        token,
+    )
+    .await;
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    let round_id = get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?;
+    let motion_id =
+        get_id_of_a_new_motion(app, tournament_id, "This House would debate", token)
+            .await?;
+    let debate = get_response_json(
+        create_debate_with_motion(app, tournament_id, &round_id, &motion_id, token).await,
+    )
+    .await?;
+    let debate_id = debate["id"].as_str().unwrap().to_owned();
+    for (handle, is_proposition) in [("Team Alpha", true), ("Team Beta", false)] {
+        let team_id = get_id_of_a_new_team(app, tournament_id, handle).await;
+        get_id_of_a_new_debate_team(
+            app,
+            tournament_id,
+            &debate_id,
+            &team_id,
+            Some(is_proposition),
+            token,
+        )
+        .await?;
+    }
+    for status in ["SidesDrawn", "Preparation", "InProgress"] {
+        get_response_json(
+            change_debate_status(app, tournament_id, &debate_id, status, token).await,
+        )
+        .await?;
+    }
+    Ok(debate_id)
+}
+
+#[tokio::test]
+async fn timer_should_follow_speech_rules_of_the_tournament() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_in_progress(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let initial = get_debate_timer(&app, &tournament_id, &debate_id, &token).await;
+    let started =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &token).await;
+    let paused =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Pause", &token).await;
+    let advanced =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "NextSpeech", &token)
+            .await;
+
+    // THEN
+    let initial = get_response_json(initial).await?;
+    assert_eq!(initial["status"], "Waiting");
+    assert_eq!(initial["speeches"].as_array().unwrap().len(), 8);
+    assert_eq!(initial["remaining_ms"], 300_000);
+    assert_eq!(
+        initial["protected_windows"],
+        json!([{ "start": 270, "end": 300 }])
+    );
+    assert_eq!(started.status(), StatusCode::OK);
+    assert_eq!(get_response_json(started).await?["status"], "Running");
+    assert_eq!(get_response_json(paused).await?["status"], "Paused");
+    let advanced = get_response_json(advanced).await?;
+    assert_eq!(advanced["status"], "Waiting");
+    assert_eq!(advanced["speech_index"], 1);
+    assert_eq!(advanced["elapsed_ms"], 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn timer_should_reject_actions_not_matching_its_state() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_in_progress(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let resumed =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Resume", &token).await;
+    let ad_vocem =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "AdVocem", &token).await;
+
+    // THEN
+    assert_eq!(resumed.status(), StatusCode::CONFLICT);
+    assert_eq!(ad_vocem.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn speeches_should_not_start_before_the_debate_is_in_progress(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+
+    // WHEN
+    let started =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &token).await;
+    let advanced =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "NextSpeech", &token)
+            .await;
+    let timer = get_debate_timer(&app, &tournament_id, &debate_id, &token).await;
+
+    // THEN
+    assert_eq!(started.status(), StatusCode::CONFLICT);
+    assert_eq!(advanced.status(), StatusCode::CONFLICT);
+    let timer = get_response_json(timer).await?;
+    assert_eq!(timer["status"], "Waiting");
+    assert_eq!(timer["speech_index"], 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_the_debate_marshal_and_organizers_should_operate_timers(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let marshal_token = get_marshal_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+
+    // WHEN
+    let judge_response =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &judge_token)
+            .await;
+    let other_marshal_response =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &marshal_token)
+            .await;
+    let judge_read =
+        get_debate_timer(&app, &tournament_id, &debate_id, &judge_token).await;
+
+    // THEN
+    assert_eq!(judge_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(other_marshal_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(judge_read.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn timers_should_be_dropped_once_debates_are_over() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_in_progress(&app, &tournament_id, &token).await?;
+    operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &token).await;
+
+    // WHEN
+    change_debate_status(&app, &tournament_id, &debate_id, "Cancelled", &token).await;
+    let timer = get_debate_timer(&app, &tournament_id, &debate_id, &token).await;
+    let restarted =
+        operate_debate_timer(&app, &tournament_id, &debate_id, "Start", &token).await;
+
+    // THEN
+    assert_eq!(get_response_json(timer).await?["status"], "Waiting");
+    assert_eq!(restarted.status(), StatusCode::CONFLICT);
+    Ok(())
+}
@@ -0,0 +1,492 @@
+use std::collections::HashSet;
+
+use reqwest::StatusCode;
+use serde_json::Value;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debates_utils::get_debate,
+    draws_utils::{
+        create_teams, draw_phase, draw_round, rule_drawn_debates, seed_phase,
+        seed_phase_keeping_groups_apart,
+    },
+    get_response_json,
+    phases_utils::finish_phase,
+    plans_utils::{count_debates, get_ids_of_planned_phases},
+    rounds_utils::get_ids_of_rounds_of_phase,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_judge_token, get_organizer_token},
+    verdicts_utils::get_id_of_a_new_verdict,
+};
+
+fn pairs_of(draw: &Value) -> Vec<(String, String)> {
+    draw["debates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|debate| {
+            let mut pair = [
+                debate["proposition_team_id"].as_str().unwrap().to_owned(),
+                debate["opposition_team_id"].as_str().unwrap().to_owned(),
+            ];
+            pair.sort();
+            (pair[0].clone(), pair[1].clone())
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn organizers_should_be_able_to_draw_a_round_robin() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    // 6 teams split into 2 groups of 3, which need exactly 3 rounds
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    let team_ids = create_teams(&app, &tournament_id).await;
+
+    // WHEN
+    let response = draw_phase(&app, &tournament_id, &phase_id, false, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let draw = get_response_json(response).await?;
+    assert_eq!(draw["dry_run"], false);
+
+    let pairs = pairs_of(&draw);
+    assert_eq!(pairs.len(), 6);
+    assert_eq!(pairs.iter().collect::<HashSet<_>>().len(), pairs.len());
+    assert_eq!(draw["byes"].as_array().unwrap().len(), 6);
+
+    for team_id in &team_ids {
+        let debates_count = pairs
+            .iter()
+            .filter(|(first, second)| first == team_id || second == team_id)
+            .count();
+        assert_eq!(debates_count, 2);
+    }
+
+    for drawn_debate in draw["debates"].as_array().unwrap() {
+        let debate_id = drawn_debate["debate_id"].as_str().unwrap();
+        let debate =
+            get_response_json(get_debate(&app, debate_id, &tournament_id, &token).await)
+                .await?;
+        let teams = debate["teams"].as_array().unwrap();
+        assert_eq!(teams.len(), 2);
+        assert!(teams.iter().any(|team| team["team_id"]
+            == drawn_debate["proposition_team_id"]
+            && team["is_proposition"] == true));
+        assert!(teams
+            .iter()
+            .any(|team| team["team_id"] == drawn_debate["opposition_team_id"]
+                && team["is_proposition"] == false));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_runs_should_not_change_the_draw() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    // 6 teams in a single group need more debates than the plan created
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 1, 6, &token).await;
+    create_teams(&app, &tournament_id).await;
+    let debates_count = count_debates(&app.pool, &tournament_id).await;
+
+    // WHEN
+    let response = draw_phase(&app, &tournament_id, &phase_id, true, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let draw = get_response_json(response).await?;
+    assert_eq!(draw["dry_run"], true);
+    assert_eq!(pairs_of(&draw).len(), 9);
+    assert!(draw["debates"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|debate| debate["debate_id"].is_null()));
+    assert_eq!(
+        count_debates(&app.pool, &tournament_id).await,
+        debates_count
+    );
+
+    for drawn_debate in draw["debates"].as_array().unwrap() {
+        if let Some(debate_id) = drawn_debate["debate_id"].as_str() {
+            let debate = get_response_json(
+                get_debate(&app, debate_id, &tournament_id, &token).await,
+            )
+            .await?;
+            assert!(debate["teams"].as_array().unwrap().is_empty());
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn finals_phases_should_not_be_drawn_as_round_robins() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (_, phase_id) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    create_teams(&app, &tournament_id).await;
+
+    // WHEN
+    let response = draw_phase(&app, &tournament_id, &phase_id, false, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_should_not_be_able_to_draw() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let organizer_token = get_organizer_token(&app, &tournament_id).await;
+    let token = get_judge_token(&app, &tournament_id).await;
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &organizer_token)
+            .await;
+    create_teams(&app, &tournament_id).await;
+
+    // WHEN
+    let response = draw_phase(&app, &tournament_id, &phase_id, false, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn swiss_draws_should_be_deterministic_for_a_seed() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    let round_id = get_ids_of_rounds_of_phase(&app, &tournament_id, &phase_id, &token)
+        .await
+        .remove(0);
+    create_teams(&app, &tournament_id).await;
+
+    // WHEN
+    let first_draw = get_response_json(
+        draw_round(
+            &app,
+            &tournament_id,
+            &phase_id,
+            &round_id,
+            true,
+            Some(42),
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let second_draw = get_response_json(
+        draw_round(
+            &app,
+            &tournament_id,
+            &phase_id,
+            &round_id,
+            true,
+            Some(42),
+            &token,
+        )
+        .await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(first_draw["seed"], 42);
+    assert_eq!(pairs_of(&first_draw).len(), 3);
+    assert_eq!(first_draw["debates"], second_draw["debates"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn swiss_draws_should_pair_teams_by_wins_without_rematches() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    let round_ids =
+        get_ids_of_rounds_of_phase(&app, &tournament_id, &phase_id, &token).await;
+    create_teams(&app, &tournament_id).await;
+
+    let first_draw = get_response_json(
+        draw_round(
+            &app,
+            &tournament_id,
+            &phase_id,
+            &round_ids[0],
+            false,
+            None,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let mut winners = HashSet::new();
+    for drawn_debate in first_draw["debates"].as_array().unwrap() {
+        let debate_id = drawn_debate["debate_id"].as_str().unwrap();
+        let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+        get_id_of_a_new_debate_judge(&app, &tournament_id, debate_id, &judge_id).await?;
+        get_id_of_a_new_verdict(
+            &app,
+            &tournament_id,
+            &judge_id,
+            debate_id,
+            &true,
+            &token,
+        )
+        .await?;
+        winners.insert(
+            drawn_debate["proposition_team_id"]
+                .as_str()
+                .unwrap()
+                .to_owned(),
+        );
+    }
+
+    // WHEN
+    let response = draw_round(
+        &app,
+        &tournament_id,
+        &phase_id,
+        &round_ids[1],
+        false,
+        None,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let second_draw = get_response_json(response).await?;
+    let first_pairs: HashSet<_> = pairs_of(&first_draw).into_iter().collect();
+    let second_pairs = pairs_of(&second_draw);
+    assert_eq!(second_pairs.len(), 3);
+    assert!(second_pairs.iter().all(|pair| !first_pairs.contains(pair)));
+
+    // 3 winners and 3 losers leave exactly one team to be pulled up
+    let pull_ups = second_draw["pull_ups"].as_array().unwrap();
+    assert_eq!(pull_ups.len(), 1);
+    assert!(!winners.contains(pull_ups[0]["team_id"].as_str().unwrap()));
+    let mixed_pairs = second_pairs
+        .iter()
+        .filter(|(first, second)| winners.contains(first) != winners.contains(second))
+        .count();
+    assert_eq!(mixed_pairs, 1);
+    assert!(second_draw["broken_constraints"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|broken| broken["constraint"] != "NoRepeatPairings"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn swiss_draws_should_not_count_debates_until_the_panel_has_ruled(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    let round_ids =
+        get_ids_of_rounds_of_phase(&app, &tournament_id, &phase_id, &token).await;
+    create_teams(&app, &tournament_id).await;
+
+    let first_draw = get_response_json(
+        draw_round(
+            &app,
+            &tournament_id,
+            &phase_id,
+            &round_ids[0],
+            false,
+            None,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    for drawn_debate in first_draw["debates"].as_array().unwrap() {
+        let debate_id = drawn_debate["debate_id"].as_str().unwrap();
+        let ruling_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+        let silent_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+        for judge_id in [&ruling_judge_id, &silent_judge_id] {
+            get_id_of_a_new_debate_judge(&app, &tournament_id, debate_id, judge_id)
+                .await?;
+        }
+        get_id_of_a_new_verdict(
+            &app,
+            &tournament_id,
+            &ruling_judge_id,
+            debate_id,
+            &true,
+            &token,
+        )
+        .await?;
+    }
+
+    // WHEN
+    let response = draw_round(
+        &app,
+        &tournament_id,
+        &phase_id,
+        &round_ids[1],
+        false,
+        None,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let second_draw = get_response_json(response).await?;
+    assert!(second_draw["pull_ups"].as_array().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn organizers_should_be_able_to_seed_a_bracket() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (group_phase_id, finals_phase_id) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    create_teams(&app, &tournament_id).await;
+    let group_draw = get_response_json(
+        draw_phase(&app, &tournament_id, &group_phase_id, false, &token).await,
+    )
+    .await?;
+    rule_drawn_debates(&app, &tournament_id, &group_draw, &token).await?;
+    finish_phase(&app, &tournament_id, &group_phase_id, &token).await;
+
+    // WHEN
+    let response =
+        seed_phase(&app, &tournament_id, &finals_phase_id, Some(4), &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let seeding = get_response_json(response).await?;
+    let seeds = seeding["seeds"].as_array().unwrap();
+    assert_eq!(seeds.len(), 4);
+    for (higher, lower) in seeds.iter().zip(seeds.iter().skip(1)) {
+        assert!(higher["wins"].as_i64() >= lower["wins"].as_i64());
+    }
+
+    let debates = seeding["debates"].as_array().unwrap();
+    assert_eq!(debates.len(), 2);
+    assert_eq!(debates[0]["proposition_team_id"], seeds[0]["team_id"]);
+    assert_eq!(debates[0]["opposition_team_id"], seeds[3]["team_id"]);
+    assert_eq!(debates[1]["proposition_team_id"], seeds[1]["team_id"]);
+    assert_eq!(debates[1]["opposition_team_id"], seeds[2]["team_id"]);
+
+    let debate_id = debates[0]["debate_id"].as_str().unwrap();
+    let debate =
+        get_response_json(get_debate(&app, debate_id, &tournament_id, &token).await)
+            .await?;
+    assert_eq!(debate["teams"].as_array().unwrap().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn brackets_should_keep_teams_from_the_same_group_apart() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (group_phase_id, finals_phase_id) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    create_teams(&app, &tournament_id).await;
+    let group_draw = get_response_json(
+        draw_phase(&app, &tournament_id, &group_phase_id, false, &token).await,
+    )
+    .await?;
+    rule_drawn_debates(&app, &tournament_id, &group_draw, &token).await?;
+    finish_phase(&app, &tournament_id, &group_phase_id, &token).await;
+
+    // WHEN
+    let response = seed_phase_keeping_groups_apart(
+        &app,
+        &tournament_id,
+        &finals_phase_id,
+        4,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let seeding = get_response_json(response).await?;
+    let seeds = seeding["seeds"].as_array().unwrap();
+    let group_of = |team_id: &Value| {
+        seeds
+            .iter()
+            .find(|seed| &seed["team_id"] == team_id)
+            .unwrap()["group"]
+            .clone()
+    };
+    assert!(seeds.iter().all(|seed| seed["group"].is_i64()));
+    let broken_constraints = seeding["broken_constraints"].as_array().unwrap();
+    for debate in seeding["debates"].as_array().unwrap() {
+        let (proposition, opposition) = (
+            &debate["proposition_team_id"],
+            &debate["opposition_team_id"],
+        );
+        if group_of(proposition) == group_of(opposition) {
+            assert!(broken_constraints.iter().any(|broken| {
+                broken["constraint"] == "SeparateGroups"
+                    && broken["team_ids"].as_array().unwrap().contains(proposition)
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn brackets_should_not_be_seeded_before_the_group_phase_ends(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (_, finals_phase_id) =
+        get_ids_of_planned_phases(&app, &tournament_id, 2, 3, 2, 6, &token).await;
+    create_teams(&app, &tournament_id).await;
+
+    // WHEN
+    let response = seed_phase(&app, &tournament_id, &finals_phase_id, None, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_formats_utils::get_id_of_a_new_preset_debate_format,
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::create_debate_team_on_side,
+    debates_utils::{get_debate_result, get_id_of_a_new_debate},
+    get_response_json,
+    plans_utils::{count_debates, create_plan},
+    tab_utils::get_tab,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{get_id_of_a_new_judge, get_organizer_token},
+    verdicts_utils::{create_ranking_verdict, create_verdict},
+};
+
+const POSITIONS: [&str; 4] = [
+    "OpeningGovernment",
+    "OpeningOpposition",
+    "ClosingGovernment",
+    "ClosingOpposition",
+];
+
+struct FourTeamDebate {
+    debate_id: String,
+    /// Teams in the order of POSITIONS
+    team_ids: Vec<String>,
+    judge_id: String,
+}
+
+async fn use_british_parliamentary(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<(), OmniError> {
+    let format_id =
+        get_id_of_a_new_preset_debate_format(app, tournament_id, "BP", token).await?;
+    let response = patch_tournament(
+        app,
+        tournament_id,
+        &json!({ "debate_format_id": format_id }),
+        token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+/// Creates a British Parliamentary debate with a team on every position
+/// and a single Judge on the panel
+async fn set_up_a_four_team_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<FourTeamDebate, OmniError> {
+    use_british_parliamentary(app, tournament_id, token).await?;
+    let debate_id = get_id_of_a_new_debate(app, tournament_id).await?;
+    let mut team_ids = vec![];
+    for side in POSITIONS {
+        let team_id = get_id_of_a_new_team(app, tournament_id, side).await;
+        let response = create_debate_team_on_side(
+            app,
+            tournament_id,
+            &debate_id,
+            &team_id,
+            side,
+            token,
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        team_ids.push(team_id);
+    }
+    let judge_id = get_id_of_a_new_judge(app, tournament_id).await?;
+    get_id_of_a_new_debate_judge(app, tournament_id, &debate_id, &judge_id).await?;
+
+    Ok(FourTeamDebate {
+        debate_id,
+        team_ids,
+        judge_id,
+    })
+}
+
+#[tokio::test]
+async fn four_team_debates_should_be_placed_by_ranking_ballots() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = set_up_a_four_team_debate(&app, &tournament_id, &token).await?;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "tab_tie_breaks": ["Points", "Random"] }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let verdict = create_ranking_verdict(
+        &app,
+        &tournament_id,
+        &debate.judge_id,
+        &debate.debate_id,
+        &[
+            ("ClosingGovernment", 1),
+            ("OpeningGovernment", 2),
+            ("OpeningOpposition", 3),
+            ("ClosingOpposition", 4),
+        ],
+        &token,
+    )
+    .await;
+    let result = get_response_json(
+        get_debate_result(&app, &debate.debate_id, &tournament_id, &token).await,
+    )
+    .await?;
+    let tab =
+        get_response_json(get_tab(&app, &tournament_id, None, &token).await).await?;
+
+    // THEN
+    assert_eq!(verdict.status(), StatusCode::OK);
+    assert_eq!(result["status"], "Decided");
+    assert_eq!(result["proposition_won"], serde_json::Value::Null);
+    assert_eq!(result["winner_team_id"], debate.team_ids[2]);
+    assert_eq!(result["is_unanimous"], true);
+    let placements = result["placements"].as_array().unwrap();
+    assert_eq!(placements[0]["side"], "ClosingGovernment");
+    assert_eq!(placements[0]["points"], 3);
+    assert_eq!(placements[3]["team_id"], debate.team_ids[3]);
+    assert_eq!(placements[3]["points"], 0);
+
+    let teams = tab["teams"].as_array().unwrap();
+    let order: Vec<&str> = teams
+        .iter()
+        .map(|entry| entry["team_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            &debate.team_ids[2],
+            &debate.team_ids[0],
+            &debate.team_ids[1],
+            &debate.team_ids[3]
+        ]
+    );
+    assert_eq!(teams[1]["points"], 2);
+    assert_eq!(teams[0]["wins"], 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn four_team_debates_should_only_accept_complete_rankings() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate = set_up_a_four_team_debate(&app, &tournament_id, &token).await?;
+    let fifth_team_id = get_id_of_a_new_team(&app, &tournament_id, "Fifth").await;
+
+    // WHEN
+    let winner_ballot = create_verdict(
+        &app,
+        &tournament_id,
+        &debate.judge_id,
+        &debate.debate_id,
+        &true,
+        &token,
+    )
+    .await;
+    let missing_team = create_ranking_verdict(
+        &app,
+        &tournament_id,
+        &debate.judge_id,
+        &debate.debate_id,
+        &[
+            ("OpeningGovernment", 1),
+            ("OpeningOpposition", 2),
+            ("ClosingGovernment", 3),
+        ],
+        &token,
+    )
+    .await;
+    let shared_rank = create_ranking_verdict(
+        &app,
+        &tournament_id,
+        &debate.judge_id,
+        &debate.debate_id,
+        &[
+            ("OpeningGovernment", 1),
+            ("OpeningOpposition", 1),
+            ("ClosingGovernment", 3),
+            ("ClosingOpposition", 4),
+        ],
+        &token,
+    )
+    .await;
+    let fifth_team = create_debate_team_on_side(
+        &app,
+        &tournament_id,
+        &debate.debate_id,
+        &fifth_team_id,
+        "ClosingOpposition",
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(winner_ballot.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(missing_team.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(shared_rank.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(fifth_team.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn two_team_sides_should_be_rejected_in_four_team_debates() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    use_british_parliamentary(&app, &tournament_id, &token).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+
+    // WHEN
+    let response = create_debate_team_on_side(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &team_id,
+        "Proposition",
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn plans_should_not_plan_finals_of_four_team_debates() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    use_british_parliamentary(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let plan = create_plan(&app, &tournament_id, 8, 2, 2, 16, &token).await;
+
+    // THEN
+    assert_eq!(plan.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(count_debates(&app.pool, &tournament_id).await, 0);
+    Ok(())
+}
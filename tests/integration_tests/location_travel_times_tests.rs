@@ -0,0 +1,441 @@
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{create_debate, create_debate_in_room},
+    get_response_json,
+    phases_utils::get_id_of_a_new_group_phase,
+    rooms_utils::{
+        get_id_of_a_new_location, get_id_of_a_new_room, get_travel_times, put_travel_time,
+    },
+    rounds_utils::{
+        allocate_judges, allocate_rooms, get_id_of_a_new_round,
+        get_id_of_a_new_round_after, patch_round,
+    },
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_organizer_token},
+};
+
+struct Campuses {
+    phase_id: String,
+    /// Starts at 10:00 and holds rooms until 12:00
+    first_round_id: String,
+    second_round_id: String,
+    north_location_id: String,
+    north_room_id: String,
+    south_location_id: String,
+    south_room_id: String,
+}
+
+/// Sets up two back to back rounds at two locations with a room each.
+/// The allocator picks rooms at North first, unless told otherwise.
+async fn set_up_two_campuses(
+    app: &TestApp,
+    tournament_id: &str,
+    second_round_start_time: &str,
+    token: &str,
+) -> Result<Campuses, OmniError> {
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    let first_round_id =
+        get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?;
+    patch_round(
+        app,
+        tournament_id,
+        &phase_id,
+        &first_round_id,
+        &json!({
+            "planned_start_time": "2026-05-16T10:00:00Z",
+            "planned_end_time": "2026-05-16T12:00:00Z",
+        }),
+        token,
+    )
+    .await;
+    let second_round_id = get_id_of_a_new_round_after(
+        app,
+        tournament_id,
+        &phase_id,
+        &first_round_id,
+        (second_round_start_time, "2026-05-16T16:00:00Z"),
+        token,
+    )
+    .await?;
+    let north_location_id =
+        get_id_of_a_new_location(app, tournament_id, "North", token).await?;
+    let north_room_id =
+        get_id_of_a_new_room(app, tournament_id, &north_location_id, "N1", token).await?;
+    let south_location_id =
+        get_id_of_a_new_location(app, tournament_id, "South", token).await?;
+    let south_room_id =
+        get_id_of_a_new_room(app, tournament_id, &south_location_id, "S1", token).await?;
+
+    Ok(Campuses {
+        phase_id,
+        first_round_id,
+        second_round_id,
+        north_location_id,
+        north_room_id,
+        south_location_id,
+        south_room_id,
+    })
+}
+
+async fn get_id_of_a_new_debate(
+    app: &TestApp,
+    tournament_id: &str,
+    round_id: &str,
+    room_id: Option<&str>,
+    token: &str,
+) -> Result<String, OmniError> {
+    let response = match room_id {
+        Some(room_id) => {
+            create_debate_in_room(app, tournament_id, round_id, room_id, token).await
+        }
+        None => create_debate(app, tournament_id, round_id, token).await,
+    };
+    let debate = get_response_json(response).await?;
+    Ok(debate["id"].as_str().unwrap().to_owned())
+}
+
+fn get_room_of_debate(debates: &Value, debate_id: &str) -> Value {
+    debates
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|debate| debate["id"] == debate_id)
+        .unwrap()["room_id"]
+        .clone()
+}
+
+#[tokio::test]
+async fn travel_times_should_apply_both_ways() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let north_id =
+        get_id_of_a_new_location(&app, &tournament_id, "North", &token).await?;
+    let south_id =
+        get_id_of_a_new_location(&app, &tournament_id, "South", &token).await?;
+
+    // WHEN
+    put_travel_time(&app, &tournament_id, &north_id, &south_id, 30, &token).await;
+    let from_south = get_response_json(
+        get_travel_times(&app, &tournament_id, &south_id, &token).await,
+    )
+    .await?;
+    put_travel_time(&app, &tournament_id, &south_id, &north_id, 20, &token).await;
+    let from_north = get_response_json(
+        get_travel_times(&app, &tournament_id, &north_id, &token).await,
+    )
+    .await?;
+    let within_location =
+        put_travel_time(&app, &tournament_id, &north_id, &north_id, 5, &token).await;
+    let negative =
+        put_travel_time(&app, &tournament_id, &north_id, &south_id, -5, &token).await;
+
+    // THEN
+    assert_eq!(
+        from_south,
+        json!([{ "location_id": north_id, "minutes": 30 }])
+    );
+    assert_eq!(
+        from_north,
+        json!([{ "location_id": south_id, "minutes": 20 }])
+    );
+    assert_eq!(within_location.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(negative.status(), StatusCode::BAD_REQUEST);
+    Ok(())
+}
+
+#[tokio::test]
+async fn teams_and_judges_should_stay_at_their_previous_location() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let campuses =
+        set_up_two_campuses(&app, &tournament_id, "2026-05-16T13:00:00Z", &token).await?;
+    let other_south_room_id = get_id_of_a_new_room(
+        &app,
+        &tournament_id,
+        &campuses.south_location_id,
+        "S2",
+        &token,
+    )
+    .await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let earlier_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.first_round_id,
+        Some(&campuses.south_room_id),
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &earlier_debate_id,
+        &team_id,
+        Some(true),
+        &token,
+    )
+    .await?;
+    let judged_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.first_round_id,
+        Some(&other_south_room_id),
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &judged_debate_id, &judge_id)
+        .await?;
+    let team_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        None,
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &team_debate_id,
+        &team_id,
+        Some(false),
+        &token,
+    )
+    .await?;
+    let judge_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        None,
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &judge_debate_id, &judge_id)
+        .await?;
+
+    // WHEN
+    let allocated = get_response_json(
+        allocate_rooms(
+            &app,
+            &tournament_id,
+            &campuses.phase_id,
+            &campuses.second_round_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+
+    // THEN
+    let mut room_ids = vec![
+        get_room_of_debate(&allocated, &team_debate_id),
+        get_room_of_debate(&allocated, &judge_debate_id),
+    ];
+    room_ids.sort_by_key(|id| id.to_string());
+    let mut south_room_ids =
+        vec![json!(campuses.south_room_id), json!(other_south_room_id)];
+    south_room_ids.sort_by_key(|id| id.to_string());
+    assert_eq!(room_ids, south_room_ids);
+    Ok(())
+}
+
+#[tokio::test]
+async fn teams_should_not_be_sent_beyond_reach() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let campuses =
+        set_up_two_campuses(&app, &tournament_id, "2026-05-16T12:10:00Z", &token).await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Team Alpha").await;
+    let earlier_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.first_round_id,
+        Some(&campuses.north_room_id),
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &earlier_debate_id,
+        &team_id,
+        Some(true),
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        Some(&campuses.north_room_id),
+        &token,
+    )
+    .await?;
+    let debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        None,
+        &token,
+    )
+    .await?;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &team_id,
+        Some(false),
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    put_travel_time(
+        &app,
+        &tournament_id,
+        &campuses.north_location_id,
+        &campuses.south_location_id,
+        30,
+        &token,
+    )
+    .await;
+    let too_far = allocate_rooms(
+        &app,
+        &tournament_id,
+        &campuses.phase_id,
+        &campuses.second_round_id,
+        &token,
+    )
+    .await;
+    put_travel_time(
+        &app,
+        &tournament_id,
+        &campuses.north_location_id,
+        &campuses.south_location_id,
+        10,
+        &token,
+    )
+    .await;
+    let allocated = get_response_json(
+        allocate_rooms(
+            &app,
+            &tournament_id,
+            &campuses.phase_id,
+            &campuses.second_round_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(too_far.status(), StatusCode::CONFLICT);
+    assert!(too_far.text().await.unwrap().contains("travel times"));
+    assert_eq!(
+        get_room_of_debate(&allocated, &debate_id),
+        campuses.south_room_id.as_str()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_should_be_allocated_at_their_previous_location() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let campuses =
+        set_up_two_campuses(&app, &tournament_id, "2026-05-16T13:00:00Z", &token).await?;
+    let north_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let south_judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    for (room_id, judge_id) in [
+        (&campuses.north_room_id, &north_judge_id),
+        (&campuses.south_room_id, &south_judge_id),
+    ] {
+        let debate_id = get_id_of_a_new_debate(
+            &app,
+            &tournament_id,
+            &campuses.first_round_id,
+            Some(room_id),
+            &token,
+        )
+        .await?;
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, judge_id).await?;
+    }
+    // Debates are served in order of creation, so the South debate comes first
+    let south_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        Some(&campuses.south_room_id),
+        &token,
+    )
+    .await?;
+    let north_debate_id = get_id_of_a_new_debate(
+        &app,
+        &tournament_id,
+        &campuses.second_round_id,
+        Some(&campuses.north_room_id),
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    let allocated = get_response_json(
+        allocate_judges(
+            &app,
+            &tournament_id,
+            &campuses.phase_id,
+            &campuses.second_round_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let repeated = allocate_judges(
+        &app,
+        &tournament_id,
+        &campuses.phase_id,
+        &campuses.second_round_id,
+        &token,
+    )
+    .await;
+
+    // THEN
+    let get_judge_of_debate = |debate_id: &str| {
+        allocated
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|assignment| assignment["debate_id"] == debate_id)
+            .unwrap()["judge_user_id"]
+            .clone()
+    };
+    assert_eq!(allocated.as_array().unwrap().len(), 2);
+    assert_eq!(
+        get_judge_of_debate(&north_debate_id),
+        north_judge_id.as_str()
+    );
+    assert_eq!(
+        get_judge_of_debate(&south_debate_id),
+        south_judge_id.as_str()
+    );
+    assert_eq!(get_response_json(repeated).await?, json!([]));
+    Ok(())
+}
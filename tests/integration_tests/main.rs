@@ -1,12 +1,32 @@
-﻿mod affiliations_tests;
+﻿mod accessibility_requirements_tests;
+mod affiliations_tests;
 mod auth_tests;
+mod bracket_links_tests;
 pub mod common;
+mod debate_formats_tests;
+mod debate_judges_tests;
+mod debate_results_tests;
+mod debate_statuses_tests;
+mod debate_teams_tests;
+mod debate_timers_tests;
 mod debates_tests;
+mod draws_tests;
+mod four_team_debates_tests;
 mod ladder_tests;
+mod location_travel_times_tests;
+mod motion_bank_tests;
+mod motions_tests;
 mod permissions_tests;
 mod plans_tests;
 mod roles_tests;
+mod room_bookings_tests;
+mod rule_overrides_tests;
+mod schedules_tests;
+mod side_draws_tests;
+mod speaker_scores_tests;
+mod tab_tests;
 mod teams_tests;
 mod teapot_tests;
 mod tournament_tests;
+mod users_tests;
 mod verdicts_tests;
@@ -0,0 +1,206 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    get_response_json,
+    motion_bank_utils::{
+        copy_bank_motion_to_tournament, create_bank_motion, delete_bank_motion,
+        get_id_of_a_new_bank_motion, patch_bank_motion, search_motion_bank,
+    },
+    motions_utils::get_motions,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_judge_token, get_organizer_token},
+};
+
+const MOTION: &str = "This House would abolish the UN Security Council.";
+
+#[tokio::test]
+async fn bank_motions_should_be_searchable_by_content_and_tags() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let security_council_id = get_id_of_a_new_bank_motion(
+        &app,
+        json!({
+            "motion": MOTION,
+            "tags": ["International Relations"],
+            "language": "en",
+            "difficulty": "Medium",
+        }),
+        &token,
+    )
+    .await?;
+    let basic_income_id = get_id_of_a_new_bank_motion(
+        &app,
+        json!({
+            "motion": "This House would introduce a universal basic income.",
+            "adinfo": "Universal basic income is paid to every citizen regardless of their employment.",
+            "tags": ["economics", "welfare"],
+            "difficulty": "Easy",
+        }),
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    let by_content = search_motion_bank(&app, "query=security%20council", &token).await;
+    let by_adinfo = search_motion_bank(&app, "query=employment", &token).await;
+    let by_tag = search_motion_bank(&app, "tags=international%20relations", &token).await;
+    let by_tags_and_difficulty =
+        search_motion_bank(&app, "tags=economics,welfare&difficulty=Hard", &token).await;
+
+    // THEN
+    let by_content = get_response_json(by_content).await?;
+    assert_eq!(by_content.as_array().unwrap().len(), 1);
+    assert_eq!(by_content[0]["id"], security_council_id);
+    let by_adinfo = get_response_json(by_adinfo).await?;
+    assert_eq!(by_adinfo.as_array().unwrap().len(), 1);
+    assert_eq!(by_adinfo[0]["id"], basic_income_id);
+    let by_tag = get_response_json(by_tag).await?;
+    assert_eq!(by_tag.as_array().unwrap().len(), 1);
+    assert_eq!(by_tag[0]["tags"], json!(["international relations"]));
+    let by_tags_and_difficulty = get_response_json(by_tags_and_difficulty).await?;
+    assert!(by_tags_and_difficulty.as_array().unwrap().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn bank_motion_should_be_copied_into_a_tournament() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let bank_motion_id = get_id_of_a_new_bank_motion(
+        &app,
+        json!({ "motion": MOTION, "adinfo": "The UN Security Council has 15 members." }),
+        &token,
+    )
+    .await?;
+
+    // WHEN
+    let response =
+        copy_bank_motion_to_tournament(&app, &tournament_id, &bank_motion_id, &token)
+            .await;
+    let repeated_response =
+        copy_bank_motion_to_tournament(&app, &tournament_id, &bank_motion_id, &token)
+            .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let motion = get_response_json(response).await?;
+    assert_ne!(motion["id"], bank_motion_id);
+    assert_eq!(motion["motion"], MOTION);
+    assert_eq!(motion["adinfo"], "The UN Security Council has 15 members.");
+    assert_eq!(motion["tournament_id"], tournament_id);
+    assert_eq!(repeated_response.status(), StatusCode::CONFLICT);
+    let motions =
+        get_response_json(get_motions(&app, &tournament_id, &token).await).await?;
+    assert_eq!(motions.as_array().unwrap().len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn motion_bank_should_not_be_available_to_non_organizers() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    get_organizer_token(&app, &tournament_id_alpha).await;
+    let judge_token = get_judge_token(&app, &tournament_id_bravo).await;
+
+    // WHEN
+    let search_response = search_motion_bank(&app, "", &judge_token).await;
+    let create_response =
+        create_bank_motion(&app, json!({ "motion": MOTION }), &judge_token).await;
+
+    // THEN
+    assert_eq!(search_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(create_response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[tokio::test]
+async fn bank_motions_should_be_changed_only_by_organizers_of_their_source_tournament(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let alpha_token = get_organizer_token(&app, &tournament_id_alpha).await;
+    let bravo_token = get_organizer_token(&app, &tournament_id_bravo).await;
+    let bank_motion_id = get_id_of_a_new_bank_motion(
+        &app,
+        json!({ "motion": MOTION, "source_tournament_id": tournament_id_alpha }),
+        &alpha_token,
+    )
+    .await?;
+    let unattributed_id = get_id_of_a_new_bank_motion(
+        &app,
+        json!({ "motion": "This House would introduce a universal basic income." }),
+        &alpha_token,
+    )
+    .await?;
+
+    // WHEN
+    let claimed = create_bank_motion(
+        &app,
+        json!({
+            "motion": "This House would ban zoos.",
+            "source_tournament_id": tournament_id_alpha,
+        }),
+        &bravo_token,
+    )
+    .await;
+    let foreign_patch = patch_bank_motion(
+        &app,
+        &bank_motion_id,
+        json!({ "difficulty": "Hard" }),
+        &bravo_token,
+    )
+    .await;
+    let foreign_delete = delete_bank_motion(&app, &bank_motion_id, &bravo_token).await;
+    let unattributed_delete =
+        delete_bank_motion(&app, &unattributed_id, &alpha_token).await;
+    let own_patch = patch_bank_motion(
+        &app,
+        &bank_motion_id,
+        json!({ "difficulty": "Hard" }),
+        &alpha_token,
+    )
+    .await;
+    let own_delete = delete_bank_motion(&app, &bank_motion_id, &alpha_token).await;
+
+    // THEN
+    assert_eq!(claimed.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(foreign_patch.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(foreign_delete.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(unattributed_delete.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(own_patch.status(), StatusCode::OK);
+    assert_eq!(own_delete.status(), StatusCode::NO_CONTENT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn bank_motions_should_be_unique() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    get_id_of_a_new_bank_motion(&app, json!({ "motion": MOTION }), &token).await?;
+
+    // WHEN
+    let response = create_bank_motion(&app, json!({ "motion": MOTION }), &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    assert_eq!(
+        response.text().await.unwrap(),
+        "Motion with such content already exists in the motion bank"
+    );
+    Ok(())
+}
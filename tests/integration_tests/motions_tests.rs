@@ -0,0 +1,341 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{create_debate, get_debate},
+    get_response_json,
+    motions_utils::{create_motion, get_id_of_a_new_motion, get_motions},
+    phases_utils::get_id_of_a_new_group_phase,
+    rounds_utils::get_id_of_a_new_round,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_id_of_a_new_judge, get_judge_token, get_organizer_token},
+    verdicts_utils::get_id_of_a_new_verdict,
+};
+
+const MOTION: &str = "This House would abolish the UN Security Council.";
+const UNKNOWN_ID: &str = "01968b3c-1f2e-7d4a-9b1c-2e3f4a5b6c7d";
+
+#[tokio::test]
+async fn motions_should_be_listed_only_within_their_tournament() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    let motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id_alpha, MOTION, &token_alpha).await?;
+    get_id_of_a_new_motion(&app, &tournament_id_bravo, "Another motion", &token_bravo)
+        .await?;
+
+    // WHEN
+    let response = get_motions(&app, &tournament_id_alpha, &token_alpha).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let motions = get_response_json(response).await?;
+    let motions = motions.as_array().unwrap();
+    assert_eq!(motions.len(), 1);
+    assert_eq!(motions[0]["id"], motion_id);
+    assert_eq!(motions[0]["tournament_id"], tournament_id_alpha);
+    Ok(())
+}
+
+#[tokio::test]
+async fn motions_should_not_be_available_through_other_tournaments(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    let motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id_alpha, MOTION, &token_alpha).await?;
+    let path = format!("/tournaments/{}/motions/{}", tournament_id_bravo, motion_id);
+
+    // WHEN
+    let get_response = app
+        .client
+        .get(app.url(&path))
+        .bearer_auth(&token_bravo)
+        .send()
+        .await
+        .unwrap();
+    let delete_response = app
+        .client
+        .delete(app.url(&path))
+        .bearer_auth(&token_bravo)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(get_response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(delete_response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn motions_should_be_unique_only_within_a_tournament() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    get_id_of_a_new_motion(&app, &tournament_id_alpha, MOTION, &token_alpha).await?;
+
+    // WHEN
+    let duplicate = create_motion(&app, &tournament_id_alpha, MOTION, &token_alpha).await;
+    let elsewhere = create_motion(&app, &tournament_id_bravo, MOTION, &token_bravo).await;
+
+    // THEN
+    assert_eq!(duplicate.status(), StatusCode::CONFLICT);
+    assert_eq!(elsewhere.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rounds_and_debates_should_not_use_motions_of_other_tournaments(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    let own_motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id_alpha, MOTION, &token_alpha).await?;
+    let foreign_motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id_bravo, MOTION, &token_bravo).await?;
+    let phase_id =
+        get_id_of_a_new_group_phase(&app, &tournament_id_alpha, &token_alpha).await?;
+    let round_id =
+        get_id_of_a_new_round(&app, &tournament_id_alpha, &phase_id, &token_alpha)
+            .await?;
+    let round_path = format!(
+        "/tournaments/{}/phases/{}/rounds/{}",
+        tournament_id_alpha, phase_id, round_id
+    );
+
+    // WHEN
+    let mut round_responses = vec![];
+    for motion_id in [&foreign_motion_id, &own_motion_id] {
+        round_responses.push(
+            app.client
+                .patch(app.url(&round_path))
+                .json(&json!({ "motion_id": motion_id }))
+                .bearer_auth(&token_alpha)
+                .send()
+                .await
+                .unwrap(),
+        );
+    }
+    let debate_response = app
+        .client
+        .post(app.url(&format!("/tournaments/{}/debates", tournament_id_alpha)))
+        .json(&json!({
+            "round_id": round_id,
+            "tournament_id": tournament_id_alpha,
+            "motion_id": foreign_motion_id,
+        }))
+        .bearer_auth(&token_alpha)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(round_responses[0].status(), StatusCode::CONFLICT);
+    assert_eq!(round_responses[1].status(), StatusCode::OK);
+    assert_eq!(debate_response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn motions_should_be_hidden_from_judges_until_released() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let motion_id = get_id_of_a_new_motion(&app, &tournament_id, MOTION, &token).await?;
+    let phase_id = get_id_of_a_new_group_phase(&app, &tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(&app, &tournament_id, &phase_id, &token).await?;
+    let debate_id =
+        get_response_json(create_debate(&app, &tournament_id, &round_id, &token).await)
+            .await?["id"]
+            .as_str()
+            .unwrap()
+            .to_owned();
+    let round_path = format!(
+        "/tournaments/{}/phases/{}/rounds/{}",
+        tournament_id, phase_id, round_id
+    );
+    app.client
+        .patch(app.url(&round_path))
+        .json(&json!({ "motion_id": motion_id }))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    // WHEN
+    let debate_before_release = get_response_json(
+        get_debate(&app, &debate_id, &tournament_id, &judge_token).await,
+    )
+    .await?;
+    let round_before_release = get_response_json(
+        app.client
+            .get(app.url(&round_path))
+            .bearer_auth(&judge_token)
+            .send()
+            .await
+            .unwrap(),
+    )
+    .await?;
+    let organizer_debate =
+        get_response_json(get_debate(&app, &debate_id, &tournament_id, &token).await)
+            .await?;
+    let release_response = app
+        .client
+        .post(app.url(&format!("{}/release_motion", round_path)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let debate_after_release = get_response_json(
+        get_debate(&app, &debate_id, &tournament_id, &judge_token).await,
+    )
+    .await?;
+
+    // THEN
+    assert!(debate_before_release["motion_id"].is_null());
+    assert!(round_before_release["motion_id"].is_null());
+    assert_eq!(organizer_debate["motion_id"], motion_id);
+    assert_eq!(release_response.status(), StatusCode::OK);
+    let released_round = get_response_json(release_response).await?;
+    assert!(!released_round["motion_release_time"].is_null());
+    assert!(!released_round["planned_start_time"].is_null());
+    assert_eq!(debate_after_release["motion_id"], motion_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_organizers_should_release_motions_of_rounds_with_motions(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let phase_id = get_id_of_a_new_group_phase(&app, &tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(&app, &tournament_id, &phase_id, &token).await?;
+    let release_url = app.url(&format!(
+        "/tournaments/{}/phases/{}/rounds/{}/release_motion",
+        tournament_id, phase_id, round_id
+    ));
+
+    // WHEN
+    let judge_response = app
+        .client
+        .post(release_url.clone())
+        .bearer_auth(&judge_token)
+        .send()
+        .await
+        .unwrap();
+    let organizer_response = app
+        .client
+        .post(release_url)
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let wrong_phase_response = app
+        .client
+        .post(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}/release_motion",
+            tournament_id, UNKNOWN_ID, round_id
+        )))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(judge_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(organizer_response.status(), StatusCode::CONFLICT);
+    assert_eq!(wrong_phase_response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
+#[tokio::test]
+async fn motion_balance_should_count_debates_on_round_motions() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let motion_id = get_id_of_a_new_motion(&app, &tournament_id, MOTION, &token).await?;
+    let phase_id = get_id_of_a_new_group_phase(&app, &tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(&app, &tournament_id, &phase_id, &token).await?;
+    app.client
+        .patch(app.url(&format!(
+            "/tournaments/{}/phases/{}/rounds/{}",
+            tournament_id, phase_id, round_id
+        )))
+        .json(&json!({ "motion_id": motion_id }))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+    let debate_id =
+        get_response_json(create_debate(&app, &tournament_id, &round_id, &token).await)
+            .await?["id"]
+            .as_str()
+            .unwrap()
+            .to_owned();
+    for (name, is_proposition) in [("Proposition Team", true), ("Opposition Team", false)]
+    {
+        let team_id = get_id_of_a_new_team(&app, &tournament_id, name).await;
+        get_id_of_a_new_debate_team(
+            &app,
+            &tournament_id,
+            &debate_id,
+            &team_id,
+            Some(is_proposition),
+            &token,
+        )
+        .await?;
+    }
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    get_id_of_a_new_verdict(&app, &tournament_id, &judge_id, &debate_id, &true, &token)
+        .await?;
+
+    // WHEN
+    let response = app
+        .client
+        .get(app.url(&format!("/tournaments/{}/motion_balance", tournament_id)))
+        .bearer_auth(&token)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let report = get_response_json(response).await?;
+    let balance = &report["motions"][0];
+    assert_eq!(balance["motion_id"], motion_id);
+    assert_eq!(balance["overall"]["proposition_wins"], 1);
+    assert_eq!(balance["phases"][0]["phase_id"], phase_id);
+    assert_eq!(balance["unanimous"]["debates"], 1);
+    assert_eq!(balance["split"]["debates"], 0);
+    assert_eq!(report["side_bias"]["proposition_win_rate"], 1.0);
+    Ok(())
+}
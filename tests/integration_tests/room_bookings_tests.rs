@@ -0,0 +1,588 @@
+use chrono::{TimeDelta, Utc};
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::{
+        change_debate_status, create_debate, create_debate_in_room, get_debate,
+        patch_tournament_debate,
+    },
+    get_response_json,
+    motions_utils::get_id_of_a_new_motion,
+    phases_utils::get_id_of_a_new_group_phase,
+    rooms_utils::{
+        close_room, delete_room_closure, get_id_of_a_new_location, get_id_of_a_new_room,
+        get_room_occupancy,
+    },
+    rounds_utils::{
+        allocate_rooms, get_id_of_a_new_round, get_id_of_a_new_round_after, patch_round,
+    },
+    rule_overrides_utils::put_rule_overrides,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::get_organizer_token,
+};
+
+struct PlannedRounds {
+    phase_id: String,
+    /// Starts at 10:00
+    first_round_id: String,
+    /// Starts at 13:00, once the first round is over
+    second_round_id: String,
+}
+
+async fn set_up_two_rounds(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<PlannedRounds, OmniError> {
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    let first_round_id =
+        get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?;
+    patch_round(
+        app,
+        tournament_id,
+        &phase_id,
+        &first_round_id,
+        &json!({
+            "planned_start_time": "2026-05-16T10:00:00Z",
+            "planned_end_time": "2026-05-16T12:00:00Z",
+        }),
+        token,
+    )
+    .await;
+    let second_round_id = get_id_of_a_new_round_after(
+        app,
+        tournament_id,
+        &phase_id,
+        &first_round_id,
+        ("2026-05-16T13:00:00Z", "2026-05-16T15:00:00Z"),
+        token,
+    )
+    .await?;
+
+    Ok(PlannedRounds {
+        phase_id,
+        first_round_id,
+        second_round_id,
+    })
+}
+
+#[tokio::test]
+async fn rooms_should_not_be_double_booked() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.first_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let same_round = create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.first_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+    let next_round = create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.second_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+    let overlapping_rounds = patch_round(
+        &app,
+        &tournament_id,
+        &rounds.phase_id,
+        &rounds.second_round_id,
+        &json!({ "planned_start_time": "2026-05-16T11:00:00Z" }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(same_round.status(), StatusCode::CONFLICT);
+    assert_eq!(next_round.status(), StatusCode::OK);
+    assert_eq!(overlapping_rounds.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_bookings_should_not_share_a_room() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+
+    // WHEN
+    let book = || {
+        create_debate_in_room(
+            &app,
+            &tournament_id,
+            &rounds.first_round_id,
+            &room_id,
+            &token,
+        )
+    };
+    let (first, second, third) = tokio::join!(book(), book(), book());
+
+    // THEN
+    let statuses = [first.status(), second.status(), third.status()];
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == StatusCode::OK)
+            .count(),
+        1
+    );
+    assert!(statuses
+        .iter()
+        .all(|status| [StatusCode::OK, StatusCode::CONFLICT].contains(status)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn patching_a_debate_should_keep_its_room() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    let debate = get_response_json(
+        create_debate_in_room(
+            &app,
+            &tournament_id,
+            &rounds.first_round_id,
+            &room_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id, "This House would debate", &token)
+            .await?;
+
+    // WHEN
+    let response = patch_tournament_debate(
+        &app,
+        &tournament_id,
+        debate["id"].as_str().unwrap(),
+        &json!({ "round_id": rounds.first_round_id, "motion_id": motion_id }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let patched = get_response_json(response).await?;
+    assert_eq!(patched["motion_id"], motion_id);
+    assert_eq!(patched["room_id"], room_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_booked_for_the_overridden_time_slot() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("phases/{}", rounds.phase_id),
+        &json!({ "debate_time_slot": 240 }),
+        &token,
+    )
+    .await;
+    create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.first_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let next_round = create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.second_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(next_round.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_allocated_to_every_debate_of_a_round() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    for name in ["Room 1", "Room 2"] {
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, name, &token).await?;
+    }
+    let mut debate_ids = vec![];
+    for _ in 0..3 {
+        let debate = get_response_json(
+            create_debate(&app, &tournament_id, &rounds.first_round_id, &token).await,
+        )
+        .await?;
+        debate_ids.push(debate["id"].as_str().unwrap().to_owned());
+    }
+
+    // WHEN
+    let too_few_rooms = allocate_rooms(
+        &app,
+        &tournament_id,
+        &rounds.phase_id,
+        &rounds.first_round_id,
+        &token,
+    )
+    .await;
+    let debate_after_failure =
+        get_response_json(get_debate(&app, &debate_ids[0], &tournament_id, &token).await)
+            .await?;
+    get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 3", &token).await?;
+    let allocated = get_response_json(
+        allocate_rooms(
+            &app,
+            &tournament_id,
+            &rounds.phase_id,
+            &rounds.first_round_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(too_few_rooms.status(), StatusCode::CONFLICT);
+    assert_eq!(debate_after_failure["room_id"], serde_json::Value::Null);
+    let mut room_ids: Vec<&str> = allocated
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|debate| debate["room_id"].as_str().unwrap())
+        .collect();
+    room_ids.sort();
+    room_ids.dedup();
+    assert_eq!(room_ids.len(), 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_of_other_tournaments_should_not_be_booked() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let other_tournament_id = get_id_of_a_new_tournament(&app, "other").await?;
+    let other_token = get_organizer_token(&app, &other_tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &other_tournament_id, "Campus", &other_token)
+            .await?;
+    let room_id = get_id_of_a_new_room(
+        &app,
+        &other_tournament_id,
+        &location_id,
+        "Room 1",
+        &other_token,
+    )
+    .await?;
+
+    // WHEN
+    let response = create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.first_round_id,
+        &room_id,
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rooms_should_be_occupied_while_debates_are_held() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    let current_debate = get_response_json(
+        create_debate_in_room(
+            &app,
+            &tournament_id,
+            &rounds.first_round_id,
+            &room_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let next_debate = get_response_json(
+        create_debate_in_room(
+            &app,
+            &tournament_id,
+            &rounds.second_round_id,
+            &room_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    for (handle, is_proposition) in [("Team Alpha", true), ("Team Beta", false)] {
+        let team_id = get_id_of_a_new_team(&app, &tournament_id, handle).await;
+        get_id_of_a_new_debate_team(
+            &app,
+            &tournament_id,
+            current_debate["id"].as_str().unwrap(),
+            &team_id,
+            Some(is_proposition),
+            &token,
+        )
+        .await?;
+    }
+    let motion_id =
+        get_id_of_a_new_motion(&app, &tournament_id, "This House would debate", &token)
+            .await?;
+    patch_round(
+        &app,
+        &tournament_id,
+        &rounds.phase_id,
+        &rounds.first_round_id,
+        &json!({ "motion_id": motion_id }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let before = get_response_json(
+        get_room_occupancy(&app, &tournament_id, &location_id, &token).await,
+    )
+    .await?;
+    for status in ["SidesDrawn", "Preparation"] {
+        change_debate_status(
+            &app,
+            &tournament_id,
+            current_debate["id"].as_str().unwrap(),
+            status,
+            &token,
+        )
+        .await;
+    }
+    let during = get_response_json(
+        get_room_occupancy(&app, &tournament_id, &location_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(before[0]["is_occupied"], false);
+    assert_eq!(before[0]["current_debate"], serde_json::Value::Null);
+    assert_eq!(before[0]["next_debate"]["id"], current_debate["id"]);
+    assert_eq!(during[0]["is_occupied"], true);
+    assert_eq!(during[0]["current_debate"]["id"], current_debate["id"]);
+    assert_eq!(during[0]["next_debate"]["id"], next_debate["id"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn closed_rooms_should_be_occupied() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    let closure = json!({
+        "reason": "Broken heating",
+        "start_time": Utc::now() - TimeDelta::hours(1),
+        "end_time": Utc::now() + TimeDelta::hours(1),
+    });
+
+    // WHEN
+    let closure = get_response_json(
+        close_room(
+            &app,
+            &tournament_id,
+            &location_id,
+            &room_id,
+            &closure,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let closed = get_response_json(
+        get_room_occupancy(&app, &tournament_id, &location_id, &token).await,
+    )
+    .await?;
+    let deletion = delete_room_closure(
+        &app,
+        &tournament_id,
+        &location_id,
+        &room_id,
+        closure["id"].as_str().unwrap(),
+        &token,
+    )
+    .await;
+    let reopened = get_response_json(
+        get_room_occupancy(&app, &tournament_id, &location_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(closed[0]["is_occupied"], true);
+    assert_eq!(closed[0]["current_closure"]["reason"], "Broken heating");
+    assert_eq!(deletion.status(), StatusCode::NO_CONTENT);
+    assert_eq!(reopened[0]["is_occupied"], false);
+    assert_eq!(reopened[0]["current_closure"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[tokio::test]
+async fn closed_rooms_should_not_be_booked() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_two_rounds(&app, &tournament_id, &token).await?;
+    let location_id =
+        get_id_of_a_new_location(&app, &tournament_id, "Campus", &token).await?;
+    let closed_room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 1", &token)
+            .await?;
+    let open_room_id =
+        get_id_of_a_new_room(&app, &tournament_id, &location_id, "Room 2", &token)
+            .await?;
+    close_room(
+        &app,
+        &tournament_id,
+        &location_id,
+        &closed_room_id,
+        &json!({
+            "reason": "Renovation",
+            "start_time": "2026-05-16T09:00:00Z",
+            "end_time": "2026-05-16T11:00:00Z",
+        }),
+        &token,
+    )
+    .await;
+    let debate = get_response_json(
+        create_debate(&app, &tournament_id, &rounds.first_round_id, &token).await,
+    )
+    .await?;
+
+    // WHEN
+    let booking = create_debate_in_room(
+        &app,
+        &tournament_id,
+        &rounds.first_round_id,
+        &closed_room_id,
+        &token,
+    )
+    .await;
+    let allocated = get_response_json(
+        allocate_rooms(
+            &app,
+            &tournament_id,
+            &rounds.phase_id,
+            &rounds.first_round_id,
+            &token,
+        )
+        .await,
+    )
+    .await?;
+    let closing_a_booked_room = close_room(
+        &app,
+        &tournament_id,
+        &location_id,
+        &open_room_id,
+        &json!({
+            "reason": "Renovation",
+            "start_time": "2026-05-16T11:00:00Z",
+            "end_time": "2026-05-16T12:00:00Z",
+        }),
+        &token,
+    )
+    .await;
+    let closing_backwards = close_room(
+        &app,
+        &tournament_id,
+        &location_id,
+        &open_room_id,
+        &json!({
+            "reason": "Renovation",
+            "start_time": "2026-05-16T18:00:00Z",
+            "end_time": "2026-05-16T17:00:00Z",
+        }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(booking.status(), StatusCode::CONFLICT);
+    assert_eq!(allocated[0]["id"], debate["id"]);
+    assert_eq!(allocated[0]["room_id"], open_room_id.as_str());
+    assert_eq!(closing_a_booked_room.status(), StatusCode::CONFLICT);
+    assert_eq!(closing_backwards.status(), StatusCode::BAD_REQUEST);
+    Ok(())
+}
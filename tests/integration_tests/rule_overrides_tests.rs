@@ -0,0 +1,274 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_formats_utils::{
+        get_id_of_a_new_preset_debate_format, override_debate_format,
+    },
+    debate_timers_utils::get_debate_timer,
+    debates_utils::{create_debate, get_id_of_a_new_debate},
+    get_response_json,
+    phases_utils::get_id_of_a_new_group_phase,
+    rounds_utils::get_id_of_a_new_round,
+    rule_overrides_utils::{get_effective_rules, put_rule_overrides},
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{get_judge_token, get_organizer_token},
+};
+
+#[tokio::test]
+async fn rules_should_be_overridden_from_tournament_down_to_debate(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let phase_id = get_id_of_a_new_group_phase(&app, &tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(&app, &tournament_id, &phase_id, &token).await?;
+    let debate =
+        get_response_json(create_debate(&app, &tournament_id, &round_id, &token).await)
+            .await?;
+    let debate_id = debate["id"].as_str().unwrap();
+
+    // WHEN
+    let phase = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("phases/{phase_id}"),
+        &json!({ "speech_time": 420, "debate_preparation_time": 30 }),
+        &token,
+    )
+    .await;
+    let round = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("phases/{phase_id}/rounds/{round_id}"),
+        &json!({ "speech_time": 450 }),
+        &token,
+    )
+    .await;
+    let debate = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("debates/{debate_id}"),
+        &json!({ "beep_on_speech_end": false }),
+        &token,
+    )
+    .await;
+    let rules = get_response_json(
+        get_effective_rules(&app, &tournament_id, debate_id, &token).await,
+    )
+    .await?;
+    let timer = get_response_json(
+        get_debate_timer(&app, &tournament_id, debate_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(phase.status(), StatusCode::OK);
+    assert_eq!(round.status(), StatusCode::OK);
+    assert_eq!(debate.status(), StatusCode::OK);
+    assert_eq!(rules["speech_time"], 450);
+    assert_eq!(rules["debate_preparation_time"], 30);
+    assert_eq!(rules["beep_on_speech_end"], false);
+    assert_eq!(rules["end_protected_time"], 30);
+    assert_eq!(rules["speeches"][0]["duration"], 450);
+    assert_eq!(timer["remaining_ms"], 450_000);
+    Ok(())
+}
+
+#[tokio::test]
+async fn overrides_should_be_replaced_as_a_whole() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let path = format!("debates/{debate_id}");
+    put_rule_overrides(
+        &app,
+        &tournament_id,
+        &path,
+        &json!({ "speech_time": 600 }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    put_rule_overrides(
+        &app,
+        &tournament_id,
+        &path,
+        &json!({ "ad_vocem_time": 90 }),
+        &token,
+    )
+    .await;
+    let rules = get_response_json(
+        get_effective_rules(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(rules["speech_time"], 300);
+    assert_eq!(rules["ad_vocem_time"], 90);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_organizers_should_override_rules() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+
+    // WHEN
+    let response = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("debates/{debate_id}"),
+        &json!({ "speech_time": 600 }),
+        &judge_token,
+    )
+    .await;
+    let rules = get_effective_rules(&app, &tournament_id, &debate_id, &judge_token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(rules.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn overrides_should_apply_to_speeches_of_the_debate_format() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let format_id =
+        get_id_of_a_new_preset_debate_format(&app, &tournament_id, "WSDC", &token)
+            .await?;
+    override_debate_format(&app, &tournament_id, &debate_id, &format_id, &token).await;
+
+    // WHEN
+    put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("debates/{debate_id}"),
+        &json!({ "speech_time": 420, "start_protected_time": 30 }),
+        &token,
+    )
+    .await;
+    let rules = get_response_json(
+        get_effective_rules(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+    let timer = get_response_json(
+        get_debate_timer(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    let speeches = rules["speeches"].as_array().unwrap();
+    assert_eq!(speeches[0]["duration"], 420);
+    assert_eq!(speeches[0]["start_protected_time"], 30);
+    assert_eq!(speeches.last().unwrap()["duration"], 240);
+    assert_eq!(timer["remaining_ms"], 420_000);
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_overrides_should_be_rejected() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    let path = format!("debates/{debate_id}");
+
+    // WHEN
+    let negative = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &path,
+        &json!({ "speech_time": -300 }),
+        &token,
+    )
+    .await;
+    let zero = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &path,
+        &json!({ "debate_time_slot": 0 }),
+        &token,
+    )
+    .await;
+    let rules = get_response_json(
+        get_effective_rules(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(negative.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(zero.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(rules["speech_time"], 300);
+    Ok(())
+}
+
+#[tokio::test]
+async fn protected_time_should_fit_within_the_inherited_speech() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let phase_id = get_id_of_a_new_group_phase(&app, &tournament_id, &token).await?;
+    let round_id = get_id_of_a_new_round(&app, &tournament_id, &phase_id, &token).await?;
+    let debate =
+        get_response_json(create_debate(&app, &tournament_id, &round_id, &token).await)
+            .await?;
+    let debate_id = debate["id"].as_str().unwrap();
+    put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("phases/{phase_id}"),
+        &json!({ "speech_time": 60 }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    let round = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("phases/{phase_id}/rounds/{round_id}"),
+        &json!({ "start_protected_time": 45 }),
+        &token,
+    )
+    .await;
+    let debate = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("debates/{debate_id}"),
+        &json!({ "end_protected_time": 61 }),
+        &token,
+    )
+    .await;
+    let fitting = put_rule_overrides(
+        &app,
+        &tournament_id,
+        &format!("debates/{debate_id}"),
+        &json!({ "start_protected_time": 30 }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(round.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(debate.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(fitting.status(), StatusCode::OK);
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    get_response_json,
+    phases_utils::get_id_of_a_new_group_phase,
+    rounds_utils::{
+        generate_schedule, get_id_of_a_new_round, get_id_of_a_new_round_after,
+        patch_round, shift_schedule,
+    },
+    rule_overrides_utils::put_rule_overrides,
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::get_organizer_token,
+};
+
+struct Rounds {
+    phase_id: String,
+    round_ids: Vec<String>,
+}
+
+/// Sets up four rounds following each other, each of them taking
+/// 15 minutes to prepare and 90 minutes to debate
+async fn set_up_rounds(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<Rounds, OmniError> {
+    let phase_id = get_id_of_a_new_group_phase(app, tournament_id, token).await?;
+    put_rule_overrides(
+        app,
+        tournament_id,
+        &format!("phases/{}", phase_id),
+        &json!({ "debate_time_slot": 90, "debate_preparation_time": 15 }),
+        token,
+    )
+    .await;
+    let mut round_ids =
+        vec![get_id_of_a_new_round(app, tournament_id, &phase_id, token).await?];
+    for _ in 0..3 {
+        let round_id = get_id_of_a_new_round_after(
+            app,
+            tournament_id,
+            &phase_id,
+            round_ids.last().unwrap(),
+            ("2026-05-01T10:00:00Z", "2026-05-01T11:00:00Z"),
+            token,
+        )
+        .await?;
+        round_ids.push(round_id);
+    }
+
+    Ok(Rounds {
+        phase_id,
+        round_ids,
+    })
+}
+
+fn get_schedule() -> Value {
+    json!({
+        "start_time": "2026-05-16T08:00:00Z",
+        "day_start": "09:00:00",
+        "day_end": "18:00:00",
+        "minutes_between_rounds": 15,
+        "breaks": [{ "start": "12:00:00", "end": "13:00:00" }],
+    })
+}
+
+/// Returns planned start and end times of the rounds
+async fn get_planned_times(
+    app: &TestApp,
+    tournament_id: &str,
+    rounds: &Rounds,
+    token: &str,
+) -> Result<Vec<(Value, Value)>, OmniError> {
+    let mut times = vec![];
+    for round_id in &rounds.round_ids {
+        let round = get_response_json(
+            app.client
+                .get(app.url(&format!(
+                    "/tournaments/{}/phases/{}/rounds/{}",
+                    tournament_id, rounds.phase_id, round_id
+                )))
+                .bearer_auth(token)
+                .send()
+                .await
+                .unwrap(),
+        )
+        .await?;
+        times.push((
+            round["planned_start_time"].clone(),
+            round["planned_end_time"].clone(),
+        ));
+    }
+    Ok(times)
+}
+
+fn get_times_of_response(rounds: &Value) -> Vec<(Value, Value)> {
+    rounds
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|round| {
+            (
+                round["planned_start_time"].clone(),
+                round["planned_end_time"].clone(),
+            )
+        })
+        .collect()
+}
+
+fn times(expected: &[(&str, &str)]) -> Vec<(Value, Value)> {
+    expected
+        .iter()
+        .map(|(start, end)| (json!(start), json!(end)))
+        .collect()
+}
+
+#[tokio::test]
+async fn rounds_should_be_scheduled_around_breaks() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_rounds(&app, &tournament_id, &token).await?;
+    let mut preview = get_schedule();
+    preview["preview"] = json!(true);
+
+    // WHEN
+    let previewed = get_response_json(
+        generate_schedule(&app, &tournament_id, &preview, &token).await,
+    )
+    .await?;
+    let times_after_preview =
+        get_planned_times(&app, &tournament_id, &rounds, &token).await?;
+    let scheduled = get_response_json(
+        generate_schedule(&app, &tournament_id, &get_schedule(), &token).await,
+    )
+    .await?;
+    let times_after_scheduling =
+        get_planned_times(&app, &tournament_id, &rounds, &token).await?;
+
+    // THEN
+    let expected_times = times(&[
+        ("2026-05-16T09:15:00Z", "2026-05-16T10:45:00Z"),
+        ("2026-05-16T13:15:00Z", "2026-05-16T14:45:00Z"),
+        ("2026-05-16T15:15:00Z", "2026-05-16T16:45:00Z"),
+        ("2026-05-17T09:15:00Z", "2026-05-17T10:45:00Z"),
+    ]);
+    assert_eq!(get_times_of_response(&previewed), expected_times);
+    assert_eq!(
+        times_after_preview[1],
+        (json!("2026-05-01T10:00:00Z"), json!("2026-05-01T11:00:00Z"))
+    );
+    assert_eq!(get_times_of_response(&scheduled), expected_times);
+    assert_eq!(times_after_scheduling, expected_times);
+    Ok(())
+}
+
+#[tokio::test]
+async fn rounds_should_be_shifted_after_a_delay() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_rounds(&app, &tournament_id, &token).await?;
+    generate_schedule(&app, &tournament_id, &get_schedule(), &token).await;
+    let shift = json!({ "after_round_id": rounds.round_ids[1], "minutes": 30 });
+
+    // WHEN
+    let mut preview = shift.clone();
+    preview["preview"] = json!(true);
+    let previewed =
+        get_response_json(shift_schedule(&app, &tournament_id, &preview, &token).await)
+            .await?;
+    let shifted =
+        get_response_json(shift_schedule(&app, &tournament_id, &shift, &token).await)
+            .await?;
+    let times_after_shifting =
+        get_planned_times(&app, &tournament_id, &rounds, &token).await?;
+
+    // THEN
+    let expected_times = times(&[
+        ("2026-05-16T09:15:00Z", "2026-05-16T10:45:00Z"),
+        ("2026-05-16T13:15:00Z", "2026-05-16T14:45:00Z"),
+        ("2026-05-16T15:45:00Z", "2026-05-16T17:15:00Z"),
+        ("2026-05-17T09:45:00Z", "2026-05-17T11:15:00Z"),
+    ]);
+    assert_eq!(get_times_of_response(&previewed), expected_times[2..]);
+    assert_eq!(get_times_of_response(&shifted), expected_times[2..]);
+    assert_eq!(times_after_shifting, expected_times);
+    Ok(())
+}
+
+#[tokio::test]
+async fn ongoing_rounds_should_not_be_shifted() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_rounds(&app, &tournament_id, &token).await?;
+    generate_schedule(&app, &tournament_id, &get_schedule(), &token).await;
+    patch_round(
+        &app,
+        &tournament_id,
+        &rounds.phase_id,
+        &rounds.round_ids[2],
+        &json!({ "status": "Ongoing" }),
+        &token,
+    )
+    .await;
+    let shift = json!({ "after_round_id": rounds.round_ids[1], "minutes": 30 });
+
+    // WHEN
+    let shifted =
+        get_response_json(shift_schedule(&app, &tournament_id, &shift, &token).await)
+            .await?;
+    let times_after_shifting =
+        get_planned_times(&app, &tournament_id, &rounds, &token).await?;
+
+    // THEN
+    let expected_times = times(&[
+        ("2026-05-16T09:15:00Z", "2026-05-16T10:45:00Z"),
+        ("2026-05-16T13:15:00Z", "2026-05-16T14:45:00Z"),
+        ("2026-05-16T15:15:00Z", "2026-05-16T16:45:00Z"),
+        ("2026-05-17T09:45:00Z", "2026-05-17T11:15:00Z"),
+    ]);
+    assert_eq!(get_times_of_response(&shifted), expected_times[3..]);
+    assert_eq!(times_after_shifting, expected_times);
+    Ok(())
+}
+
+#[tokio::test]
+async fn impossible_schedules_should_be_rejected() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let rounds = set_up_rounds(&app, &tournament_id, &token).await?;
+    let mut backwards_day = get_schedule();
+    backwards_day["day_end"] = json!("08:00:00");
+    let mut short_day = get_schedule();
+    short_day["day_end"] = json!("10:00:00");
+
+    // WHEN
+    let backwards_day =
+        generate_schedule(&app, &tournament_id, &backwards_day, &token).await;
+    let short_day = generate_schedule(&app, &tournament_id, &short_day, &token).await;
+    let shifting_an_unknown_round = shift_schedule(
+        &app,
+        &tournament_id,
+        &json!({
+            "after_round_id": "01968b3c-1f2e-7d4a-9b1c-2e3f4a5b6c7d",
+            "minutes": 30,
+        }),
+        &token,
+    )
+    .await;
+    let shifting_into_the_previous_round = shift_schedule(
+        &app,
+        &tournament_id,
+        &json!({ "after_round_id": rounds.round_ids[1], "minutes": -30 }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(backwards_day.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(short_day.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(shifting_an_unknown_round.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        shifting_into_the_previous_round.status(),
+        StatusCode::BAD_REQUEST
+    );
+    Ok(())
+}
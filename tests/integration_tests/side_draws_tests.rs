@@ -0,0 +1,249 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    debate_teams_utils::{get_debate_teams, get_id_of_a_new_debate_team},
+    debates_utils::{
+        change_debate_status, get_debate, get_id_of_a_new_debate,
+        get_ids_of_new_debates_in_one_round,
+    },
+    get_response_json,
+    side_draws_utils::{draw_sides, get_side_draws},
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, get_tournament, patch_tournament},
+    user_utils::{get_judge_token, get_marshal_token, get_organizer_token},
+};
+
+/// Assigns two teams to the debate, both yet to be given sides
+async fn assign_two_teams(
+    app: &TestApp,
+    tournament_id: &str,
+    debate_id: &str,
+    token: &str,
+) -> Result<(), OmniError> {
+    for handle in ["Team Alpha", "Team Beta"] {
+        let team_id = get_id_of_a_new_team(app, tournament_id, handle).await;
+        get_id_of_a_new_debate_team(app, tournament_id, debate_id, &team_id, None, token)
+            .await?;
+    }
+    Ok(())
+}
+
+async fn set_up_a_debate_with_two_teams(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<String, OmniError> {
+    let debate_id = get_id_of_a_new_debate(app, tournament_id).await?;
+    assign_two_teams(app, tournament_id, &debate_id, token).await?;
+    Ok(debate_id)
+}
+
+#[tokio::test]
+async fn drawing_sides_should_assign_every_team_a_side() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_with_two_teams(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let side_draw =
+        get_response_json(draw_sides(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+    let teams = get_response_json(
+        get_debate_teams(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+    let debate =
+        get_response_json(get_debate(&app, &debate_id, &tournament_id, &token).await)
+            .await?;
+    let audit_trail =
+        get_response_json(get_side_draws(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+
+    // THEN
+    let sides = side_draw["sides"].as_array().unwrap();
+    assert_eq!(sides[0]["side"], "Proposition");
+    assert_eq!(sides[1]["side"], "Opposition");
+    let proposition = teams
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|team| team["team_id"] == sides[0]["team_id"])
+        .unwrap();
+    assert_eq!(proposition["is_proposition"], true);
+    assert_eq!(debate["status"], "SidesDrawn");
+    assert_eq!(audit_trail.as_array().unwrap().len(), 1);
+    assert_eq!(audit_trail[0]["seed"], side_draw["seed"]);
+    assert!(audit_trail[0]["drawn_by_user_id"].is_string());
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_draws_should_leave_the_latest_draw_matching_the_sides(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_with_two_teams(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let draw = || draw_sides(&app, &tournament_id, &debate_id, &token);
+    let (first, second, third) = tokio::join!(draw(), draw(), draw());
+    let teams = get_response_json(
+        get_debate_teams(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+    let audit_trail =
+        get_response_json(get_side_draws(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+
+    // THEN
+    for response in [first, second, third] {
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+    let draws = audit_trail.as_array().unwrap();
+    assert_eq!(draws.len(), 3);
+    let latest = &draws[2]["sides"];
+    let proposition = teams
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|team| team["is_proposition"] == true)
+        .unwrap();
+    assert_eq!(latest[0]["team_id"], proposition["team_id"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn side_draws_should_be_reproducible_from_the_tournament_seed(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "side_draw_seed": 2024 }),
+        &token,
+    )
+    .await;
+    let debate_id = set_up_a_debate_with_two_teams(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let first =
+        get_response_json(draw_sides(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+    let second =
+        get_response_json(draw_sides(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+    let tournament_for_organizer =
+        get_response_json(get_tournament(&app, &tournament_id, &token).await).await?;
+    let tournament_for_judge =
+        get_response_json(get_tournament(&app, &tournament_id, &judge_token).await)
+            .await?;
+
+    // THEN
+    assert_eq!(first["seed"], second["seed"]);
+    assert_eq!(first["sides"], second["sides"]);
+    assert_eq!(tournament_for_organizer["side_draw_seed"], 2024);
+    assert_eq!(
+        tournament_for_judge["side_draw_seed"],
+        serde_json::Value::Null
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn side_draw_seed_should_be_cleared_with_null() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "side_draw_seed": 2024 }),
+        &token,
+    )
+    .await;
+
+    // WHEN
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "full_name": "Renamed" }),
+        &token,
+    )
+    .await;
+    let kept =
+        get_response_json(get_tournament(&app, &tournament_id, &token).await).await?;
+    patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "side_draw_seed": null }),
+        &token,
+    )
+    .await;
+    let cleared =
+        get_response_json(get_tournament(&app, &tournament_id, &token).await).await?;
+
+    // THEN
+    assert_eq!(kept["side_draw_seed"], 2024);
+    assert_eq!(cleared["side_draw_seed"], serde_json::Value::Null);
+    Ok(())
+}
+
+#[tokio::test]
+async fn sides_should_not_be_drawn_once_the_preparation_starts() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let debate_ids = get_ids_of_new_debates_in_one_round(&app, &tournament_id, 2).await?;
+    let (debate_id, empty_debate_id) = (&debate_ids[0], &debate_ids[1]);
+    assign_two_teams(&app, &tournament_id, debate_id, &token).await?;
+    change_debate_status(&app, &tournament_id, debate_id, "Cancelled", &token).await;
+
+    // WHEN
+    let cancelled = draw_sides(&app, &tournament_id, debate_id, &token).await;
+    let without_teams = draw_sides(&app, &tournament_id, empty_debate_id, &token).await;
+
+    // THEN
+    assert_eq!(cancelled.status(), StatusCode::CONFLICT);
+    assert_eq!(without_teams.status(), StatusCode::CONFLICT);
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_the_debate_marshal_and_organizers_should_draw_sides(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+    let marshal_token = get_marshal_token(&app, &tournament_id).await;
+    let debate_id = set_up_a_debate_with_two_teams(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let judge_response = draw_sides(&app, &tournament_id, &debate_id, &judge_token).await;
+    let other_marshal_response =
+        draw_sides(&app, &tournament_id, &debate_id, &marshal_token).await;
+    let audit_trail =
+        get_response_json(get_side_draws(&app, &tournament_id, &debate_id, &token).await)
+            .await?;
+
+    // THEN
+    assert_eq!(judge_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(other_marshal_response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(audit_trail.as_array().unwrap().len(), 0);
+    Ok(())
+}
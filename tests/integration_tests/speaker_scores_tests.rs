@@ -0,0 +1,259 @@
+use reqwest::StatusCode;
+use serde_json::json;
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    attendees_utils::get_id_of_a_new_attendee,
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
+    debates_utils::get_id_of_a_new_debate,
+    get_response_json,
+    tab_utils::get_tab,
+    teams_utils::get_id_of_a_new_team,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{get_id_of_a_new_judge, get_organizer_token},
+    verdicts_utils::{create_verdict_with_speaker_scores, get_verdict},
+};
+
+struct ScoredDebate {
+    tournament_id: String,
+    debate_id: String,
+    judge_id: String,
+    proposition_team_id: String,
+    /// Speakers of the Proposition, followed by speakers of the Opposition
+    speaker_ids: Vec<String>,
+    /// A member of the Proposition team without a speaking position
+    non_speaker_id: String,
+    token: String,
+}
+
+/// Creates a debate between two teams of two speakers with a single Judge on its panel
+async fn prepare_a_debate(app: &TestApp) -> Result<ScoredDebate, OmniError> {
+    let tournament_id = get_id_of_a_new_tournament(app, "test").await?;
+    let token = get_organizer_token(app, &tournament_id).await;
+    let debate_id = get_id_of_a_new_debate(app, &tournament_id).await?;
+    let judge_id = get_id_of_a_new_judge(app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(app, &tournament_id, &debate_id, &judge_id).await?;
+
+    let mut team_ids = vec![];
+    let mut speaker_ids = vec![];
+    for (handle, is_proposition) in
+        [("Proposition Team", true), ("Opposition Team", false)]
+    {
+        let team_id = get_id_of_a_new_team(app, &tournament_id, handle).await;
+        get_id_of_a_new_debate_team(
+            app,
+            &tournament_id,
+            &debate_id,
+            &team_id,
+            Some(is_proposition),
+            &token,
+        )
+        .await?;
+        for position in [1, 2] {
+            speaker_ids.push(
+                get_id_of_a_new_attendee(
+                    app,
+                    &tournament_id,
+                    &team_id,
+                    Some(position),
+                    &token,
+                )
+                .await?,
+            );
+        }
+        team_ids.push(team_id);
+    }
+    let non_speaker_id =
+        get_id_of_a_new_attendee(app, &tournament_id, &team_ids[0], None, &token).await?;
+
+    Ok(ScoredDebate {
+        tournament_id,
+        debate_id,
+        judge_id,
+        proposition_team_id: team_ids[0].clone(),
+        speaker_ids,
+        non_speaker_id,
+        token,
+    })
+}
+
+impl ScoredDebate {
+    async fn submit(&self, app: &TestApp, scores: &[f64]) -> reqwest::Response {
+        let speaker_scores: Vec<(&str, f64)> = self
+            .speaker_ids
+            .iter()
+            .map(|id| id.as_str())
+            .zip(scores.iter().copied())
+            .collect();
+        create_verdict_with_speaker_scores(
+            app,
+            &self.tournament_id,
+            &self.judge_id,
+            &self.debate_id,
+            true,
+            &speaker_scores,
+            &self.token,
+        )
+        .await
+    }
+}
+
+#[tokio::test]
+async fn judges_should_be_able_to_score_speakers() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let debate = prepare_a_debate(&app).await?;
+
+    // WHEN
+    let response = debate.submit(&app, &[71.0, 70.5, 70.0, 69.5]).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let verdict_id = get_response_json(response).await?["id"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    let verdict = get_response_json(
+        get_verdict(
+            &app,
+            &verdict_id,
+            &debate.tournament_id,
+            &debate.debate_id,
+            &debate.token,
+        )
+        .await,
+    )
+    .await?;
+    let scores = verdict["speaker_scores"].as_array().unwrap();
+    assert_eq!(scores.len(), 4);
+    assert_eq!(scores[1]["attendee_id"], debate.speaker_ids[1]);
+    assert_eq!(scores[1]["score"], 70.5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn scores_outside_of_the_range_should_be_rejected() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let debate = prepare_a_debate(&app).await?;
+
+    // WHEN
+    let too_high = debate.submit(&app, &[85.0, 70.0, 70.0, 70.0]).await;
+    let off_step = debate.submit(&app, &[71.25, 70.0, 70.0, 70.0]).await;
+
+    // THEN
+    assert_eq!(too_high.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(off_step.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    Ok(())
+}
+
+#[tokio::test]
+async fn every_speaker_and_only_speakers_should_be_scored() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let debate = prepare_a_debate(&app).await?;
+
+    // WHEN
+    let incomplete = debate.submit(&app, &[72.0, 70.0, 70.0]).await;
+    let non_speaker = create_verdict_with_speaker_scores(
+        &app,
+        &debate.tournament_id,
+        &debate.judge_id,
+        &debate.debate_id,
+        true,
+        &[
+            (&debate.speaker_ids[0], 72.0),
+            (&debate.speaker_ids[1], 70.0),
+            (&debate.speaker_ids[2], 70.0),
+            (&debate.speaker_ids[3], 70.0),
+            (&debate.non_speaker_id, 70.0),
+        ],
+        &debate.token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(incomplete.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(non_speaker.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    Ok(())
+}
+
+#[tokio::test]
+async fn low_point_wins_should_be_rejected_unless_allowed() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let debate = prepare_a_debate(&app).await?;
+    let low_point_win = [70.0, 70.0, 71.0, 70.0];
+
+    // WHEN
+    let rejected = debate.submit(&app, &low_point_win).await;
+    patch_tournament(
+        &app,
+        &debate.tournament_id,
+        &json!({ "allow_low_point_wins": true }),
+        &debate.token,
+    )
+    .await;
+    let accepted = debate.submit(&app, &low_point_win).await;
+
+    // THEN
+    assert_eq!(rejected.status(), StatusCode::CONFLICT);
+    assert_eq!(accepted.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn speaker_tab_should_rank_speakers_by_their_scores() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let debate = prepare_a_debate(&app).await?;
+    let response = debate.submit(&app, &[70.0, 74.0, 71.0, 69.0]).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // WHEN
+    let response = app
+        .client
+        .get(app.url(&format!(
+            "/tournaments/{}/speaker_tab",
+            debate.tournament_id
+        )))
+        .bearer_auth(&debate.token)
+        .send()
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let tab = get_response_json(response).await?;
+    let speakers = tab["speakers"].as_array().unwrap();
+    let order: Vec<&str> = speakers
+        .iter()
+        .map(|speaker| speaker["attendee_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            debate.speaker_ids[1].as_str(),
+            debate.speaker_ids[2].as_str(),
+            debate.speaker_ids[0].as_str(),
+            debate.speaker_ids[3].as_str(),
+        ]
+    );
+    assert_eq!(speakers[0]["total_score"], 74.0);
+    assert_eq!(speakers[0]["average_score"], 74.0);
+
+    let team_tab = get_response_json(
+        get_tab(&app, &debate.tournament_id, None, &debate.token).await,
+    )
+    .await?;
+    let proposition = team_tab["teams"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["team_id"] == debate.proposition_team_id)
+        .unwrap();
+    assert_eq!(proposition["speaker_points"], 144.0);
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use tau::omni_error::OmniError;
+
+use crate::common::{
+    draws_utils::{create_teams, draw_phase, rule_drawn_debates},
+    get_response_json,
+    plans_utils::get_ids_of_planned_phases,
+    tab_utils::get_tab,
+    test_app::TestApp,
+    tournament_utils::{get_id_of_a_new_tournament, patch_tournament},
+    user_utils::{get_judge_token, get_organizer_token},
+};
+
+/// Draws the group phase of a 6 team tournament and rules all of its debates,
+/// with the Proposition winning every debate
+async fn play_the_group_phase(
+    app: &TestApp,
+    tournament_id: &str,
+    token: &str,
+) -> Result<(String, String), OmniError> {
+    let (group_phase_id, finals_phase_id) =
+        get_ids_of_planned_phases(app, tournament_id, 4, 3, 2, 6, token).await;
+    create_teams(app, tournament_id).await;
+    let draw = get_response_json(
+        draw_phase(app, tournament_id, &group_phase_id, false, token).await,
+    )
+    .await?;
+    rule_drawn_debates(app, tournament_id, &draw, token).await?;
+    Ok((group_phase_id, finals_phase_id))
+}
+
+fn wins_of(tab: &Value) -> Vec<i64> {
+    tab["teams"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["wins"].as_i64().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn tab_should_rank_teams_by_results_of_their_debates() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    play_the_group_phase(&app, &tournament_id, &token).await?;
+    let judge_token = get_judge_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response = get_tab(&app, &tournament_id, None, &judge_token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    let tab = get_response_json(response).await?;
+    assert_eq!(
+        tab["tie_breaks"],
+        json!(["Wins", "Ballots", "HeadToHead", "Random"])
+    );
+    let teams = tab["teams"].as_array().unwrap();
+    assert_eq!(teams.len(), 6);
+    assert_eq!(teams[0]["rank"], 1);
+    assert!(teams.iter().all(|entry| entry["debates"] == 2));
+
+    let wins = wins_of(&tab);
+    assert_eq!(wins.iter().sum::<i64>(), 6);
+    assert!(wins.windows(2).all(|pair| pair[0] >= pair[1]));
+    Ok(())
+}
+
+#[tokio::test]
+async fn phase_tab_should_only_list_teams_debating_in_the_phase() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    let (group_phase_id, finals_phase_id) =
+        play_the_group_phase(&app, &tournament_id, &token).await?;
+
+    // WHEN
+    let group_tab = get_response_json(
+        get_tab(&app, &tournament_id, Some(&group_phase_id), &token).await,
+    )
+    .await?;
+    let finals_tab = get_response_json(
+        get_tab(&app, &tournament_id, Some(&finals_phase_id), &token).await,
+    )
+    .await?;
+
+    // THEN
+    assert_eq!(group_tab["teams"].as_array().unwrap().len(), 6);
+    assert_eq!(finals_tab["teams"].as_array().unwrap().len(), 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tab_should_follow_tie_breaks_configured_by_organizers() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+    play_the_group_phase(&app, &tournament_id, &token).await?;
+    let response = patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "tab_tie_breaks": ["Ballots", "OppositionStrength"] }),
+        &token,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // WHEN
+    let tab =
+        get_response_json(get_tab(&app, &tournament_id, None, &token).await).await?;
+
+    // THEN
+    assert_eq!(tab["tie_breaks"], json!(["Ballots", "OppositionStrength"]));
+    let teams = tab["teams"].as_array().unwrap();
+    for pair in teams.windows(2) {
+        let key = |entry: &Value| {
+            (
+                entry["ballots_won"].as_i64().unwrap(),
+                entry["opposition_strength"].as_i64().unwrap(),
+            )
+        };
+        assert!(key(&pair[0]) >= key(&pair[1]));
+        if key(&pair[0]) == key(&pair[1]) {
+            assert_eq!(pair[0]["rank"], pair[1]["rank"]);
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn tie_breaks_should_not_be_repeated() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response = patch_tournament(
+        &app,
+        &tournament_id,
+        &json!({ "tab_tie_breaks": ["Wins", "Ballots", "Wins"] }),
+        &token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tabs_of_phases_should_not_be_available_through_other_tournaments(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id_alpha = get_id_of_a_new_tournament(&app, "alpha").await?;
+    let tournament_id_bravo = get_id_of_a_new_tournament(&app, "bravo").await?;
+    let token_alpha = get_organizer_token(&app, &tournament_id_alpha).await;
+    let token_bravo = get_organizer_token(&app, &tournament_id_bravo).await;
+    let (group_phase_id, _) =
+        get_ids_of_planned_phases(&app, &tournament_id_alpha, 4, 3, 2, 6, &token_alpha)
+            .await;
+
+    // WHEN
+    let response = get_tab(
+        &app,
+        &tournament_id_bravo,
+        Some(&group_phase_id),
+        &token_bravo,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
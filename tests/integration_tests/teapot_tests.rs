@@ -10,7 +10,7 @@ async fn test_teapot() {
     // WHEN
     let res = app
         .client
-        .get(app.url(&format!("/brew-coffee")))
+        .get(app.url("/brew-coffee"))
         .send()
         .await
         .unwrap();
@@ -19,7 +19,7 @@ async fn tournament_creation_should_require_login() {
     // WHEN
     let res = app
         .client
-        .post(app.url(&format!("/tournaments")))
+        .post(app.url("/tournaments"))
         .json(&request_body)
         .header("accept", "text/plain")
         .header("Content-Type", "application/json")
@@ -0,0 +1,43 @@
+use reqwest::StatusCode;
+use tau::omni_error::OmniError;
+use uuid::Uuid;
+
+use crate::common::{
+    test_app::TestApp,
+    tournament_utils::get_id_of_a_new_tournament,
+    user_utils::{create_user, get_judge_token, get_organizer_token},
+};
+
+#[tokio::test]
+async fn organizers_should_be_able_to_create_users() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "some tournament").await?;
+    let token = get_organizer_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response =
+        create_user(&app, &Uuid::now_v7().to_string(), "some password", &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+    Ok(())
+}
+
+#[tokio::test]
+async fn users_who_do_not_organize_any_tournament_should_not_create_users(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+    let tournament_id = get_id_of_a_new_tournament(&app, "some tournament").await?;
+    get_organizer_token(&app, &tournament_id).await;
+    let token = get_judge_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response =
+        create_user(&app, &Uuid::now_v7().to_string(), "some password", &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
@@ -5,15 +5,19 @@ use tau::{omni_error::OmniError, tournaments::roles::Role};
 use uuid::Uuid;
 
 use crate::common::{
+    affiliations_utils::get_id_of_a_new_affiliation,
     auth_utils::{get_session_token_for, get_session_token_for_infrastructure_admin},
+    debate_judges_utils::get_id_of_a_new_debate_judge,
+    debate_teams_utils::get_id_of_a_new_debate_team,
     debates_utils::get_id_of_a_new_debate,
     get_response_json,
     roles_utils::create_roles,
+    teams_utils::get_id_of_a_new_team,
     test_app::TestApp,
     tournament_utils::get_id_of_a_new_tournament,
     user_utils::{
-        create_user, get_id_of_a_new_judge, get_id_of_a_new_user, get_judge_token,
-        get_marshal_token, get_organizer_token,
+        create_user, get_id_and_token_of_a_new_judge, get_id_of_a_new_judge,
+        get_id_of_a_new_user, get_judge_token, get_marshal_token, get_organizer_token,
     },
     verdicts_utils::{
         create_verdict, delete_verdict, get_all_verdicts, get_id_of_a_new_verdict,
@@ -28,9 +32,9 @@ async fn judges_should_be_able_to_make_verdicts_on_debates_within_their_tourname
     let app = TestApp::spawn().await;
 
     let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
-    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let (judge_id, token) = get_id_and_token_of_a_new_judge(&app, &tournament_id).await?;
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
-    let token = get_judge_token(&app, &tournament_id).await;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
     let proposition_won = true;
 
     // WHEN
@@ -53,6 +57,43 @@ async fn judges_should_be_able_to_make_verdicts_on_debates_within_their_tourname
     Ok(())
 }
 
+#[tokio::test]
+async fn judges_should_make_a_single_verdict_on_a_debate() -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let (judge_id, token) = get_id_and_token_of_a_new_judge(&app, &tournament_id).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    let marshal_token = get_marshal_token(&app, &tournament_id).await;
+    get_id_of_a_new_verdict(&app, &tournament_id, &judge_id, &debate_id, &true, &token)
+        .await?;
+
+    // WHEN
+    let own_response =
+        create_verdict(&app, &tournament_id, &judge_id, &debate_id, &false, &token).await;
+    let on_behalf_response = create_verdict(
+        &app,
+        &tournament_id,
+        &judge_id,
+        &debate_id,
+        &false,
+        &marshal_token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(own_response.status(), StatusCode::CONFLICT);
+    assert_eq!(on_behalf_response.status(), StatusCode::CONFLICT);
+    let verdicts = get_response_json(
+        get_all_verdicts(&app, &tournament_id, &debate_id, &token).await,
+    )
+    .await?;
+    assert_eq!(verdicts.as_array().unwrap().len(), 1);
+    Ok(())
+}
+
 #[tokio::test]
 async fn making_verdicts_should_be_only_allowed_on_existing_debates(
 ) -> Result<(), OmniError> {
@@ -124,6 +165,7 @@ async fn organizers_should_be_able_to_get_verdicts() -> Result<(), OmniError> {
     let judge_id = get_id_of_a_new_user(&app, judge_username, judge_password).await;
     create_roles(&app, &judge_id, &tournament_id, vec![Role::Judge], &token).await;
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
     create_user(&app, judge_username, judge_password, &token).await;
     let token = get_session_token_for(&app, judge_username, judge_password).await?;
 
@@ -186,6 +228,10 @@ async fn anyone_should_be_able_to_list_verdicts() -> Result<(), OmniError> {
     let token_bravo =
         get_session_token_for(&app, judge_username_bravo, judge_password_bravo).await?;
 
+    for judge_id in [&judge_id_alpha, &judge_id_bravo] {
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, judge_id).await?;
+    }
+
     let tokens_to_test = vec![
         get_marshal_token(&app, &tournament_id).await,
         token_alpha.clone(),
@@ -240,6 +286,7 @@ async fn judges_should_be_able_to_patch_verdicts() -> Result<(), OmniError> {
     let judge_id = get_id_of_a_new_user(&app, judge_username, judge_password).await;
     create_roles(&app, &judge_id, &tournament_id, vec![Role::Judge], &token).await;
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
     create_user(&app, judge_username, judge_password, &token).await;
     let token = get_session_token_for(&app, judge_username, judge_password).await?;
 
@@ -309,6 +356,9 @@ async fn patching_verdict_into_existing_judge_debate_returns_conflict(
     .await;
 
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    for judge_id in [&judge_id_alpha, &judge_id_bravo] {
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, judge_id).await?;
+    }
     let token_alpha =
         get_session_token_for(&app, judge_username_alpha, judge_password_alpha).await?;
     let token_bravo =
@@ -365,6 +415,7 @@ async fn judges_should_be_able_to_delete_verdicts() -> Result<(), OmniError> {
     let judge_id = get_id_of_a_new_user(&app, judge_username, judge_password).await;
     create_roles(&app, &judge_id, &tournament_id, vec![Role::Judge], &token).await;
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
     create_user(&app, judge_username, judge_password, &token).await;
     let token = get_session_token_for(&app, judge_username, judge_password).await?;
 
@@ -425,6 +476,7 @@ async fn judges_should_not_be_able_to_change_verdict_judge_without_submit_verdic
     .await;
 
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
 
     let verdict_id = get_id_of_a_new_verdict(
         &app,
@@ -479,6 +531,7 @@ async fn admin_should_be_able_to_update_verdict() -> Result<(), OmniError> {
     let judge_token = get_session_token_for(&app, judge_username, judge_password).await?;
 
     let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
     let initial_verdict = true;
 
     let verdict_id = get_id_of_a_new_verdict(
@@ -512,3 +565,113 @@ async fn admin_should_be_able_to_update_verdict() -> Result<(), OmniError> {
     assert_eq!(response_body["proposition_won"], !initial_verdict);
     Ok(())
 }
+
+#[tokio::test]
+async fn judges_should_not_be_able_to_submit_verdicts_on_behalf_of_other_judges(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let (other_judge_id, other_judge_token) =
+        get_id_and_token_of_a_new_judge(&app, &tournament_id).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    for judge_id in [&judge_id, &other_judge_id] {
+        get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, judge_id).await?;
+    }
+
+    // WHEN
+    let response = create_verdict(
+        &app,
+        &tournament_id,
+        &judge_id,
+        &debate_id,
+        &true,
+        &other_judge_token,
+    )
+    .await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    Ok(())
+}
+
+#[tokio::test]
+async fn marshals_should_be_able_to_submit_verdicts_on_behalf_of_judges(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let judge_id = get_id_of_a_new_judge(&app, &tournament_id).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    let token = get_marshal_token(&app, &tournament_id).await;
+
+    // WHEN
+    let response =
+        create_verdict(&app, &tournament_id, &judge_id, &debate_id, &false, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response_body = get_response_json(response).await?;
+    assert_eq!(response_body["judge_user_id"], judge_id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn judges_outside_of_the_panel_should_not_be_able_to_submit_verdicts(
+) -> Result<(), OmniError> {
+    // GIVEN
+    let app = TestApp::spawn().await;
+
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let (judge_id, token) = get_id_and_token_of_a_new_judge(&app, &tournament_id).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+
+    // WHEN
+    let response =
+        create_verdict(&app, &tournament_id, &judge_id, &debate_id, &true, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response_body = response.text().await.unwrap_or_default();
+    assert!(response_body.contains("not on the panel"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn affiliated_judges_should_not_be_able_to_submit_verdicts() -> Result<(), OmniError>
+{
+    // GIVEN
+    let app = TestApp::spawn().await;
+
+    let tournament_id = get_id_of_a_new_tournament(&app, "test").await?;
+    let organizer_token = get_organizer_token(&app, &tournament_id).await;
+    let (judge_id, token) = get_id_and_token_of_a_new_judge(&app, &tournament_id).await?;
+    let debate_id = get_id_of_a_new_debate(&app, &tournament_id).await?;
+    get_id_of_a_new_debate_judge(&app, &tournament_id, &debate_id, &judge_id).await?;
+    let team_id = get_id_of_a_new_team(&app, &tournament_id, "Affiliated team").await;
+    get_id_of_a_new_debate_team(
+        &app,
+        &tournament_id,
+        &debate_id,
+        &team_id,
+        None,
+        &organizer_token,
+    )
+    .await?;
+    get_id_of_a_new_affiliation(&app, &judge_id, &team_id).await?;
+
+    // WHEN
+    let response =
+        create_verdict(&app, &tournament_id, &judge_id, &debate_id, &true, &token).await;
+
+    // THEN
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let response_body = response.text().await.unwrap_or_default();
+    assert!(response_body.contains("affiliated"));
+    Ok(())
+}